@@ -15,6 +15,8 @@ use bevy_utils::tracing::info_span;
 use bevy_utils::{tracing::debug, HashMap};
 use std::{
     fmt::Debug,
+    fs, io,
+    path::Path,
     process::{ExitCode, Termination},
 };
 use std::{
@@ -124,6 +126,7 @@ impl App {
             sub_apps: SubApps {
                 main: SubApp::new(),
                 sub_apps: HashMap::new(),
+                threads: HashMap::new(),
             },
             runner: Box::new(run_once),
         }
@@ -317,6 +320,68 @@ impl App {
         self
     }
 
+    /// Removes every system in `set` from the provided `schedule`, including the systems of nested
+    /// sets. Passing a system function removes every instance of that function.
+    ///
+    /// The removed systems are dropped along with their state, and the schedule is rebuilt the
+    /// next time it runs. A schedule can't be edited while it is running, so this is meant to be
+    /// called between updates or from systems in another schedule.
+    ///
+    /// See [`Schedule::remove_systems`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// # fn system_a() {}
+    /// # fn system_b() {}
+    /// #
+    /// app.add_systems(Update, (system_a, system_b));
+    /// app.remove_systems(Update, system_a);
+    /// ```
+    pub fn remove_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M>,
+    ) -> &mut Self {
+        self.main_mut().remove_systems(schedule, set);
+        self
+    }
+
+    /// Removes `set` from the provided `schedule`, along with every system in it and the set's
+    /// own run conditions and ordering constraints.
+    ///
+    /// See [`Schedule::remove_set`] for details.
+    pub fn remove_set<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M>,
+    ) -> &mut Self {
+        self.main_mut().remove_set(schedule, set);
+        self
+    }
+
+    /// Replaces the only system in `set` in the provided `schedule` with `system`, keeping its
+    /// run conditions, sets and ordering constraints.
+    ///
+    /// See [`Schedule::replace_system`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schedule doesn't exist or if `set` doesn't contain exactly one system.
+    #[track_caller]
+    pub fn replace_system<M1, M2>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M1>,
+        system: impl IntoSystem<(), (), M2>,
+    ) -> &mut Self {
+        self.main_mut().replace_system(schedule, set, system);
+        self
+    }
+
     /// Initializes `T` event handling by inserting an event queue resource ([`Events::<T>`])
     /// and scheduling an [`event_update_system`] in [`First`].
     ///
@@ -599,6 +664,67 @@ impl App {
         self
     }
 
+    /// Registers the given function under the given name in the [`TypeRegistry`](bevy_reflect::TypeRegistry) resource,
+    /// so that it can be looked up and called by name.
+    ///
+    /// Registering several functions under the same name with different argument types
+    /// adds them as overloads of that name.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_app::App;
+    /// use bevy_ecs::reflect::AppTypeRegistry;
+    /// use bevy_reflect::func::ArgList;
+    ///
+    /// fn add(a: i32, b: i32) -> i32 {
+    ///     a + b
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.register_function("add", add);
+    ///
+    /// let registry = app.world().resource::<AppTypeRegistry>().read();
+    /// let args = ArgList::new().push_owned(1_i32).push_owned(2_i32);
+    /// let value = registry.functions().call("add", args).unwrap().unwrap_owned();
+    /// assert_eq!(value.take::<i32>().unwrap(), 3);
+    /// ```
+    ///
+    /// See [`bevy_reflect::TypeRegistry::register_function`].
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_function<F, Marker>(
+        &mut self,
+        name: impl Into<std::borrow::Cow<'static, str>>,
+        func: F,
+    ) -> &mut Self
+    where
+        F: bevy_reflect::func::IntoFunction<'static, Marker> + Clone + Send + Sync + 'static,
+    {
+        self.main_mut().register_function(name, func);
+        self
+    }
+
+    /// Registers the given method of type `T` under the given name in the
+    /// [`TypeRegistry`](bevy_reflect::TypeRegistry) resource, so that it can be looked up by path
+    /// (e.g. `"Transform::looking_at"`) with [`TypeRegistry::get_method`](bevy_reflect::TypeRegistry::get_method).
+    ///
+    /// `T` must have been registered with [`register_type`](Self::register_type) first.
+    ///
+    /// See [`bevy_reflect::TypeRegistry::register_method`].
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_method<T, F, Marker>(
+        &mut self,
+        name: impl Into<std::borrow::Cow<'static, str>>,
+        method: F,
+    ) -> &mut Self
+    where
+        T: bevy_reflect::Reflect + bevy_reflect::TypePath,
+        F: bevy_reflect::func::IntoFunction<'static, Marker> + Clone + Send + Sync + 'static,
+    {
+        self.main_mut()
+            .register_method::<T, F, Marker>(name, method);
+        self
+    }
+
     /// Returns a reference to the [`World`].
     pub fn world(&self) -> &World {
         self.main().world()
@@ -714,6 +840,23 @@ impl App {
         self
     }
 
+    /// Builds every schedule of the main app and its sub-apps and writes each one to `dir` as
+    /// Graphviz DOT (`<label>.dot`) and JSON (`<label>.json`).
+    ///
+    /// Schedules of sub-apps are written to a subdirectory named after the sub-app's label. The
+    /// output is stable, so the files can be checked in to review scheduling changes.
+    ///
+    /// See [`ScheduleExport`](bevy_ecs::schedule::ScheduleExport) for what is exported.
+    pub fn dump_schedules(&mut self, dir: impl AsRef<Path>) -> io::Result<()> {
+        let dir = dir.as_ref();
+        write_schedule_exports(self.world_mut(), dir)?;
+        for (label, sub_app) in self.sub_apps.sub_apps.iter_mut() {
+            let sub_dir = dir.join(file_name(&format!("{label:?}")));
+            write_schedule_exports(sub_app.world_mut(), &sub_dir)?;
+        }
+        Ok(())
+    }
+
     /// When doing [ambiguity checking](ScheduleBuildSettings) this
     /// ignores systems that are ambiguous on [`Component`] T.
     ///
@@ -847,6 +990,28 @@ impl App {
 
 type RunnerFn = Box<dyn FnOnce(App) -> AppExit>;
 
+fn write_schedule_exports(world: &mut World, dir: &Path) -> io::Result<()> {
+    let exports = world.export_schedules().map_err(io::Error::other)?;
+    fs::create_dir_all(dir)?;
+    for export in exports {
+        let name = file_name(&export.label);
+        fs::write(dir.join(format!("{name}.dot")), export.to_dot())?;
+        fs::write(dir.join(format!("{name}.json")), export.to_json())?;
+    }
+    Ok(())
+}
+
+/// Replaces characters that may not be valid in file names, e.g. in labels of generic types.
+fn file_name(label: &str) -> String {
+    label
+        .chars()
+        .map(|c| match c {
+            c if c.is_ascii_alphanumeric() || c == '-' || c == '_' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
 fn run_once(mut app: App) -> AppExit {
     while app.plugins_state() == PluginsState::Adding {
         #[cfg(not(target_arch = "wasm32"))]
@@ -945,7 +1110,11 @@ mod tests {
         world::{FromWorld, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use bevy_utils::Duration;
+
+    use crate::{
+        self as bevy_app, App, AppExit, AppLabel, Main, Plugin, SubApp, SubAppRunMode, Update,
+    };
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1036,6 +1205,26 @@ mod tests {
         assert_eq!(app.world().entities().len(), 2);
     }
 
+    #[test]
+    fn remove_and_replace_systems() {
+        fn baz(mut commands: Commands) {
+            commands.spawn_batch([(), (), ()]);
+        }
+
+        let mut app = App::new();
+        app.add_systems(EnterMainMenu, (foo, bar));
+        app.world_mut().run_schedule(EnterMainMenu);
+        assert_eq!(app.world().entities().len(), 2);
+
+        app.remove_systems(EnterMainMenu, foo);
+        app.world_mut().run_schedule(EnterMainMenu);
+        assert_eq!(app.world().entities().len(), 3);
+
+        app.replace_system(EnterMainMenu, bar, baz);
+        app.world_mut().run_schedule(EnterMainMenu);
+        assert_eq!(app.world().entities().len(), 6);
+    }
+
     #[test]
     #[should_panic]
     fn test_is_plugin_added_works_during_finish() {
@@ -1204,6 +1393,96 @@ mod tests {
         app.update();
     }
 
+    #[derive(AppLabel, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    struct ThreadedSubApp;
+
+    #[derive(Resource, Default)]
+    struct Updates(usize);
+
+    fn threaded_sub_app(run_mode: SubAppRunMode) -> App {
+        let mut app = App::new();
+        app.init_resource::<Updates>();
+
+        let mut sub_app = SubApp::new();
+        sub_app.update_schedule = Some(Main.intern());
+        sub_app
+            .init_resource::<Updates>()
+            .add_systems(Main, |mut updates: ResMut<Updates>| updates.0 += 1)
+            .set_extract(|main_world, sub_world| {
+                main_world.resource_mut::<Updates>().0 = sub_world.resource::<Updates>().0;
+            })
+            .set_run_mode(run_mode);
+        app.insert_sub_app(ThreadedSubApp, sub_app);
+        app
+    }
+
+    #[test]
+    fn pipelined_sub_app() {
+        let mut app = threaded_sub_app(SubAppRunMode::Pipelined);
+        for expected in 0..5 {
+            app.update();
+            // The sub-app is extracted before it is updated.
+            assert_eq!(app.world().resource::<Updates>().0, expected);
+        }
+        assert!(app.get_sub_app(ThreadedSubApp).is_none());
+    }
+
+    #[test]
+    fn free_running_sub_app() {
+        let mut app = threaded_sub_app(SubAppRunMode::FreeRunning {
+            timestep: Duration::from_millis(1),
+        });
+        let (sender, receiver) = std::sync::mpsc::channel();
+        app.sub_app_mut(ThreadedSubApp)
+            .add_systems(Main, move |updates: super::Res<Updates>| {
+                let _ = sender.send(updates.0);
+            });
+
+        app.update();
+        // Wait for the sub-app to update a few times on its own, without updating the main app.
+        while receiver.recv_timeout(Duration::from_secs(10)).unwrap() < 3 {}
+        app.update();
+        assert!(app.world().resource::<Updates>().0 >= 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn free_running_sub_app_needs_a_timestep() {
+        threaded_sub_app(SubAppRunMode::FreeRunning {
+            timestep: Duration::ZERO,
+        });
+    }
+
+    #[cfg(feature = "multi_threaded")]
+    #[test]
+    fn pipelined_sub_app_runs_non_send_systems_on_the_main_thread() {
+        use bevy_ecs::schedule::MainThreadExecutor;
+        use std::thread::ThreadId;
+
+        struct NonSendMarker;
+
+        let mut app = threaded_sub_app(SubAppRunMode::Pipelined);
+        let executor = MainThreadExecutor::new();
+        app.insert_resource(executor.clone());
+        let (sender, receiver) = std::sync::mpsc::channel::<ThreadId>();
+        let sender = Mutex::new(sender);
+        let sub_app = app.sub_app_mut(ThreadedSubApp);
+        sub_app.insert_resource(executor);
+        sub_app.world_mut().insert_non_send_resource(NonSendMarker);
+        sub_app.add_systems(Main, move |_: super::NonSend<NonSendMarker>| {
+            sender
+                .lock()
+                .unwrap()
+                .send(std::thread::current().id())
+                .unwrap();
+        });
+
+        // The second update waits for the sub-app update started by the first one.
+        app.update();
+        app.update();
+        assert_eq!(receiver.try_recv(), Ok(std::thread::current().id()));
+    }
+
     #[test]
     fn runner_returns_correct_exit_code() {
         fn raise_exits(mut exits: EventWriter<AppExit>) {
@@ -1338,4 +1617,29 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[test]
+    fn dump_schedules_writes_every_schedule() {
+        use super::AppLabel;
+        use crate::{self as bevy_app};
+
+        fn system(_commands: Commands) {}
+
+        #[derive(AppLabel, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        struct TestSubApp;
+
+        let dir =
+            std::env::temp_dir().join(format!("bevy_app_dump_schedules_{}", std::process::id()));
+        let mut app = App::new();
+        app.add_systems(Update, system);
+        app.insert_sub_app(TestSubApp, SubApp::new());
+        app.dump_schedules(&dir).unwrap();
+
+        let update = std::fs::read_to_string(dir.join("Update.json")).unwrap();
+        assert!(update.contains("\"name\": \"system\""));
+        assert!(dir.join("Update.dot").exists());
+        assert!(dir.join("Main.json").exists());
+        assert!(dir.join("TestSubApp").is_dir());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
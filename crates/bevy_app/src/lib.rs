@@ -14,6 +14,8 @@ mod plugin;
 mod plugin_group;
 mod schedule_runner;
 mod sub_app;
+mod sub_app_thread;
+mod system_requests;
 #[cfg(not(target_arch = "wasm32"))]
 mod terminal_ctrl_c_handler;
 
@@ -25,6 +27,9 @@ pub use plugin::*;
 pub use plugin_group::*;
 pub use schedule_runner::*;
 pub use sub_app::*;
+pub use sub_app_thread::SubAppRunMode;
+pub(crate) use sub_app_thread::SubAppThread;
+pub use system_requests::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use terminal_ctrl_c_handler::*;
 
@@ -38,6 +43,7 @@ pub mod prelude {
             PostStartup, PostUpdate, PreStartup, PreUpdate, SpawnScene, Startup, Update,
         },
         sub_app::SubApp,
+        sub_app_thread::SubAppRunMode,
         DynamicPlugin, Plugin, PluginGroup,
     };
 }
@@ -1,4 +1,6 @@
-use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
+use crate::{
+    App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState, SubAppRunMode, SubAppThread,
+};
 use bevy_ecs::{
     event::EventRegistry,
     prelude::*,
@@ -72,6 +74,8 @@ pub struct SubApp {
     /// A function that gives mutable access to two app worlds. This is primarily
     /// intended for copying data from the main world to secondary worlds.
     extract: Option<ExtractFn>,
+    /// Where and how often this sub-app is updated.
+    run_mode: SubAppRunMode,
 }
 
 impl Debug for SubApp {
@@ -92,6 +96,7 @@ impl Default for SubApp {
             plugins_state: PluginsState::Adding,
             update_schedule: None,
             extract: None,
+            run_mode: SubAppRunMode::Inline,
         }
     }
 }
@@ -164,6 +169,30 @@ impl SubApp {
         self
     }
 
+    /// Returns where and how often this sub-app is updated.
+    pub fn run_mode(&self) -> SubAppRunMode {
+        self.run_mode
+    }
+
+    /// Sets where and how often this sub-app is updated, see [`SubAppRunMode`].
+    ///
+    /// This has no effect on the main sub-app of an [`App`], or once the sub-app was moved to its
+    /// own thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the run mode is [`FreeRunning`](SubAppRunMode::FreeRunning) with a zero timestep.
+    pub fn set_run_mode(&mut self, run_mode: SubAppRunMode) -> &mut Self {
+        if let SubAppRunMode::FreeRunning { timestep } = run_mode {
+            assert!(
+                !timestep.is_zero(),
+                "Free-running sub-apps need a non-zero timestep."
+            );
+        }
+        self.run_mode = run_mode;
+        self
+    }
+
     /// See [`App::insert_resource`].
     pub fn insert_resource<R: Resource>(&mut self, resource: R) -> &mut Self {
         self.world.insert_resource(resource);
@@ -208,6 +237,43 @@ impl SubApp {
         self
     }
 
+    /// See [`App::remove_systems`].
+    pub fn remove_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M>,
+    ) -> &mut Self {
+        let mut schedules = self.world.resource_mut::<Schedules>();
+        schedules.remove_systems(schedule, set);
+        self
+    }
+
+    /// See [`App::remove_set`].
+    pub fn remove_set<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M>,
+    ) -> &mut Self {
+        let mut schedules = self.world.resource_mut::<Schedules>();
+        schedules.remove_set(schedule, set);
+        self
+    }
+
+    /// See [`App::replace_system`].
+    #[track_caller]
+    pub fn replace_system<M1, M2>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M1>,
+        system: impl IntoSystem<(), (), M2>,
+    ) -> &mut Self {
+        let mut schedules = self.world.resource_mut::<Schedules>();
+        if let Err(error) = schedules.replace_system(schedule, set, system) {
+            panic!("Could not replace system: {error}");
+        }
+        self
+    }
+
     /// See [`App::add_schedule`].
     pub fn add_schedule(&mut self, schedule: Schedule) -> &mut Self {
         let mut schedules = self.world.resource_mut::<Schedules>();
@@ -408,6 +474,39 @@ impl SubApp {
         registry.write().register_type_data::<T, D>();
         self
     }
+
+    /// See [`App::register_function`].
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_function<F, Marker>(
+        &mut self,
+        name: impl Into<std::borrow::Cow<'static, str>>,
+        func: F,
+    ) -> &mut Self
+    where
+        F: bevy_reflect::func::IntoFunction<'static, Marker> + Clone + Send + Sync + 'static,
+    {
+        let registry = self.world.resource_mut::<AppTypeRegistry>();
+        registry.write().register_function(name, func);
+        self
+    }
+
+    /// See [`App::register_method`].
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_method<T, F, Marker>(
+        &mut self,
+        name: impl Into<std::borrow::Cow<'static, str>>,
+        method: F,
+    ) -> &mut Self
+    where
+        T: bevy_reflect::Reflect + bevy_reflect::TypePath,
+        F: bevy_reflect::func::IntoFunction<'static, Marker> + Clone + Send + Sync + 'static,
+    {
+        let registry = self.world.resource_mut::<AppTypeRegistry>();
+        registry
+            .write()
+            .register_method::<T, F, Marker>(name, method);
+        self
+    }
 }
 
 /// The collection of sub-apps that belong to an [`App`].
@@ -417,11 +516,17 @@ pub struct SubApps {
     pub main: SubApp,
     /// Other, labeled sub-apps.
     pub sub_apps: HashMap<InternedAppLabel, SubApp>,
+    /// Labeled sub-apps that were moved to their own thread.
+    pub(crate) threads: HashMap<InternedAppLabel, SubAppThread>,
 }
 
 impl SubApps {
     /// Calls [`update`](SubApp::update) for the main sub-app, and then calls
     /// [`extract`](SubApp::extract) and [`update`](SubApp::update) for the rest.
+    ///
+    /// Sub-apps that don't run [inline](SubAppRunMode::Inline) are moved to their own thread on the
+    /// first call, after which they are only extracted here, before the inline sub-apps are
+    /// updated.
     pub fn update(&mut self) {
         #[cfg(feature = "trace")]
         let _bevy_update_span = info_span!("update").entered();
@@ -430,6 +535,13 @@ impl SubApps {
             let _bevy_frame_update_span = info_span!("main app").entered();
             self.main.run_default_schedule();
         }
+        #[cfg(not(target_arch = "wasm32"))]
+        self.spawn_threads();
+        for (_label, thread) in self.threads.iter_mut() {
+            #[cfg(feature = "trace")]
+            let _sub_app_span = info_span!("sub app extract", name = ?_label).entered();
+            thread.extract(&mut self.main.world);
+        }
         for (_label, sub_app) in self.sub_apps.iter_mut() {
             #[cfg(feature = "trace")]
             let _sub_app_span = info_span!("sub app", name = ?_label).entered();
@@ -440,6 +552,27 @@ impl SubApps {
         self.main.world.clear_trackers();
     }
 
+    /// Moves the sub-apps that don't run inline to their own thread.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn spawn_threads(&mut self) {
+        let labels = self
+            .sub_apps
+            .iter()
+            .filter(|(_, sub_app)| sub_app.run_mode() != SubAppRunMode::Inline)
+            .map(|(label, _)| *label)
+            .collect::<Vec<_>>();
+        for label in labels {
+            let sub_app = self.sub_apps.remove(&label).unwrap();
+            let thread = SubAppThread::spawn(format!("{label:?}"), sub_app);
+            self.threads.insert(label, thread);
+        }
+    }
+
+    /// Returns `true` if the sub-app with the given label was moved to its own thread.
+    pub fn is_threaded(&self, label: impl AppLabel) -> bool {
+        self.threads.contains_key(&label.intern())
+    }
+
     /// Returns an iterator over the sub-apps (starting with the main one).
     pub fn iter(&self) -> impl Iterator<Item = &SubApp> + '_ {
         std::iter::once(&self.main).chain(self.sub_apps.values())
@@ -451,10 +584,15 @@ impl SubApps {
     }
 
     /// Extract data from the main world into the [`SubApp`] with the given label and perform an update if it exists.
+    ///
+    /// If the sub-app was moved to its own thread, it is only extracted.
     pub fn update_subapp_by_label(&mut self, label: impl AppLabel) {
-        if let Some(sub_app) = self.sub_apps.get_mut(&label.intern()) {
+        let label = label.intern();
+        if let Some(sub_app) = self.sub_apps.get_mut(&label) {
             sub_app.extract(&mut self.main.world);
             sub_app.update();
+        } else if let Some(thread) = self.threads.get_mut(&label) {
+            thread.extract(&mut self.main.world);
         }
     }
 }
@@ -0,0 +1,267 @@
+use crate::{AppExit, SubApp};
+use async_channel::{Receiver, Sender};
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::MainThreadExecutor;
+#[cfg(feature = "multi_threaded")]
+use bevy_tasks::{ComputeTaskPool, TaskPool};
+use bevy_utils::{tracing::error, Duration, Instant};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::JoinHandle,
+};
+
+/// Controls on which thread, and how often, a [`SubApp`] is updated.
+///
+/// Set with [`SubApp::set_run_mode`]. Sub-apps that are not [`Inline`](Self::Inline) are moved to
+/// their own thread the first time the [`App`](crate::App) is updated, after which they can no
+/// longer be accessed with [`App::get_sub_app`](crate::App::get_sub_app). Data is exchanged with
+/// the main world only through the sub-app's [extract function](SubApp::set_extract), which still
+/// runs on the main thread, right after the main schedule.
+///
+/// On targets without threads (`wasm32`), every sub-app runs inline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubAppRunMode {
+    /// The sub-app is extracted and updated on the main thread, right after the main schedule.
+    #[default]
+    Inline,
+    /// The sub-app is extracted on the main thread, then updated on its own thread while the main
+    /// app runs its next update.
+    ///
+    /// The main app waits for the previous sub-app update to finish before extracting again, so
+    /// the sub-app is updated exactly once per main app update, one update behind. With the
+    /// `multi_threaded` feature, the main thread runs the tasks of the sub-app's
+    /// [`MainThreadExecutor`] while it waits, so that the sub-app can still run systems on the
+    /// main thread.
+    ///
+    /// This is how the render sub-app runs with `PipelinedRenderingPlugin`.
+    Pipelined,
+    /// The sub-app is updated on its own thread in a loop, at most once every `timestep`,
+    /// independently of the main app.
+    ///
+    /// Every main app update extracts into the sub-app once, between two of its updates. If a
+    /// sub-app update takes longer than `timestep`, the next one starts immediately instead of
+    /// trying to catch up.
+    FreeRunning {
+        /// The minimum time between the start of two updates of the sub-app. Must not be zero.
+        timestep: Duration,
+    },
+}
+
+/// A [`SubApp`] that was moved to its own thread.
+pub(crate) enum SubAppThread {
+    Pipelined {
+        /// The sub-app, while it is not being updated.
+        sub_app: Option<Box<SubApp>>,
+        /// Sends the extracted sub-app to the thread. Dropped to stop the thread.
+        sender: Option<Sender<Box<SubApp>>>,
+        /// Receives the sub-app back after an update.
+        receiver: Receiver<Box<SubApp>>,
+        /// The sub-app's executor for tasks on the main thread, run while waiting for the sub-app.
+        executor: Option<MainThreadExecutor>,
+        handle: Option<JoinHandle<()>>,
+    },
+    FreeRunning {
+        /// The sub-app, locked by the thread for the duration of each update.
+        sub_app: Arc<Mutex<SubApp>>,
+        /// Set while the main thread waits for the lock, so that the thread lets it through before
+        /// starting its next update.
+        extract_pending: Arc<AtomicBool>,
+        stop: Arc<AtomicBool>,
+        handle: Option<JoinHandle<()>>,
+    },
+}
+
+impl SubAppThread {
+    /// Spawns a thread updating `sub_app` according to its [`SubAppRunMode`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sub-app runs inline.
+    pub(crate) fn spawn(name: String, sub_app: SubApp) -> Self {
+        let builder = std::thread::Builder::new().name(name);
+        match sub_app.run_mode() {
+            SubAppRunMode::Inline => panic!("Inline sub-apps are not moved to a thread."),
+            SubAppRunMode::Pipelined => {
+                let executor = sub_app
+                    .world()
+                    .get_resource::<MainThreadExecutor>()
+                    .cloned();
+                let (sender, thread_receiver) = async_channel::bounded::<Box<SubApp>>(1);
+                let (thread_sender, receiver) = async_channel::bounded(1);
+                let handle = builder
+                    .spawn(move || {
+                        while let Some(mut sub_app) = receive_on_thread(&thread_receiver) {
+                            sub_app.update();
+                            if thread_sender.send_blocking(sub_app).is_err() {
+                                break;
+                            }
+                        }
+                    })
+                    .expect("failed to spawn sub-app thread");
+                Self::Pipelined {
+                    sub_app: Some(Box::new(sub_app)),
+                    sender: Some(sender),
+                    receiver,
+                    executor,
+                    handle: Some(handle),
+                }
+            }
+            SubAppRunMode::FreeRunning { timestep } => {
+                let sub_app = Arc::new(Mutex::new(sub_app));
+                let extract_pending = Arc::new(AtomicBool::new(false));
+                let stop = Arc::new(AtomicBool::new(false));
+                let handle = builder
+                    .spawn({
+                        let sub_app = sub_app.clone();
+                        let extract_pending = extract_pending.clone();
+                        let stop = stop.clone();
+                        move || {
+                            let mut next_update = Instant::now();
+                            while !stop.load(Ordering::Acquire) {
+                                // The lock is released at the end of the update, and not taken
+                                // again until a waiting extract went through.
+                                sub_app.lock().unwrap().update();
+                                while extract_pending.load(Ordering::Acquire) {
+                                    std::thread::yield_now();
+                                }
+                                next_update += timestep;
+                                let now = Instant::now();
+                                if next_update > now {
+                                    std::thread::sleep(next_update - now);
+                                } else {
+                                    next_update = now;
+                                }
+                            }
+                        }
+                    })
+                    .expect("failed to spawn sub-app thread");
+                Self::FreeRunning {
+                    sub_app,
+                    extract_pending,
+                    stop,
+                    handle: Some(handle),
+                }
+            }
+        }
+    }
+
+    /// Runs the sub-app's extract function with the main world, waiting for the sub-app if it is
+    /// being updated.
+    ///
+    /// If the sub-app thread panicked, sends [`AppExit::error`] to the main world instead.
+    pub(crate) fn extract(&mut self, main_world: &mut World) {
+        match self {
+            Self::Pipelined {
+                sub_app,
+                sender,
+                receiver,
+                executor,
+                ..
+            } => {
+                let Some(mut extracted) = sub_app
+                    .take()
+                    .or_else(|| receive_on_main_thread(receiver, executor.as_ref()))
+                else {
+                    error!("A sub-app thread panicked, exiting the app.");
+                    main_world.send_event(AppExit::error());
+                    return;
+                };
+                extracted.extract(main_world);
+                if let Some(sender) = sender {
+                    if let Err(err) = sender.send_blocking(extracted) {
+                        *sub_app = Some(err.0);
+                    }
+                }
+            }
+            Self::FreeRunning {
+                sub_app,
+                extract_pending,
+                ..
+            } => {
+                extract_pending.store(true, Ordering::Release);
+                let locked = sub_app.lock();
+                extract_pending.store(false, Ordering::Release);
+                match locked {
+                    Ok(mut sub_app) => sub_app.extract(main_world),
+                    Err(_) => {
+                        error!("A sub-app thread panicked, exiting the app.");
+                        main_world.send_event(AppExit::error());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Waits on the sub-app thread for the next extracted sub-app, running compute tasks meanwhile.
+#[cfg(feature = "multi_threaded")]
+fn receive_on_thread(receiver: &Receiver<Box<SubApp>>) -> Option<Box<SubApp>> {
+    ComputeTaskPool::get_or_init(TaskPool::default)
+        .scope(|s| {
+            s.spawn(async { receiver.recv().await.ok() });
+        })
+        .pop()
+        .flatten()
+}
+
+/// Waits on the sub-app thread for the next extracted sub-app.
+#[cfg(not(feature = "multi_threaded"))]
+fn receive_on_thread(receiver: &Receiver<Box<SubApp>>) -> Option<Box<SubApp>> {
+    receiver.recv_blocking().ok()
+}
+
+/// Waits on the main thread for the sub-app to finish its update, running the tasks of its
+/// [`MainThreadExecutor`] meanwhile.
+#[cfg(feature = "multi_threaded")]
+fn receive_on_main_thread(
+    receiver: &Receiver<Box<SubApp>>,
+    executor: Option<&MainThreadExecutor>,
+) -> Option<Box<SubApp>> {
+    ComputeTaskPool::get_or_init(TaskPool::default)
+        .scope_with_executor(true, executor.map(|executor| &*executor.0), |s| {
+            s.spawn(async { receiver.recv().await.ok() });
+        })
+        .pop()
+        .flatten()
+}
+
+/// Waits on the main thread for the sub-app to finish its update.
+#[cfg(not(feature = "multi_threaded"))]
+fn receive_on_main_thread(
+    receiver: &Receiver<Box<SubApp>>,
+    _executor: Option<&MainThreadExecutor>,
+) -> Option<Box<SubApp>> {
+    receiver.recv_blocking().ok()
+}
+
+impl Drop for SubAppThread {
+    fn drop(&mut self) {
+        // Join the thread so that the sub-app is dropped on the main thread, like inline ones.
+        match self {
+            Self::Pipelined {
+                sub_app,
+                sender,
+                receiver,
+                executor,
+                handle,
+            } => {
+                if sub_app.is_none() {
+                    *sub_app = receive_on_main_thread(receiver, executor.as_ref());
+                }
+                sender.take();
+                if let Some(handle) = handle.take() {
+                    let _ = handle.join();
+                }
+            }
+            Self::FreeRunning { stop, handle, .. } => {
+                stop.store(true, Ordering::Release);
+                if let Some(handle) = handle.take() {
+                    let _ = handle.join();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use bevy_ecs::{
+    event::EventUpdates,
+    schedule::IntoSystemConfigs,
+    system::{apply_system_requests, SystemRequests},
+};
+
+use crate::{App, First, Plugin};
+
+/// Runs the one-shot systems requested through [`SystemRequestSender`](bevy_ecs::system::SystemRequestSender)s
+/// once per frame, at the start of the [`First`] schedule.
+///
+/// Without this plugin, requested systems only run when [`apply_system_requests`] is called.
+///
+/// ```
+/// # use bevy_app::{App, SystemRequestsPlugin};
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Resource)]
+/// struct Score(u32);
+///
+/// let mut app = App::new();
+/// app.add_plugins(SystemRequestsPlugin).insert_resource(Score(5));
+///
+/// let get_score = app.world_mut().register_system(|score: Res<Score>| score.0);
+/// let ticket = app.world_mut().system_request_sender().run_system(get_score);
+///
+/// app.update();
+/// assert_eq!(ticket.try_take().unwrap().unwrap(), 5);
+/// ```
+#[derive(Default)]
+pub struct SystemRequestsPlugin;
+
+impl Plugin for SystemRequestsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SystemRequests>()
+            .add_systems(First, apply_system_requests.after(EventUpdates));
+    }
+}
@@ -0,0 +1,298 @@
+use std::sync::Arc;
+
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, SystemTimingKind, SystemTimings},
+};
+use bevy_utils::{HashMap, Instant};
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, DEFAULT_MAX_HISTORY_LENGTH,
+};
+
+/// Adds a "system time" diagnostic for every system and named system set that runs, in
+/// milliseconds of wall-clock time per frame.
+///
+/// Measurements are recorded by the schedule executors while [`SystemTimings`] is present, so
+/// this works without the `trace` feature or an external profiler. Diagnostics are created the
+/// first time a system runs and are named
+/// `system_time/<schedule>/systems/<system>` and `system_time/<schedule>/sets/<set>`.
+/// Frames in which a system doesn't run are recorded as `0`. Exclusive systems that run other
+/// schedules, like [`Main::run_main`], include the time spent in those schedules.
+///
+/// Use [`SystemTimeDiagnosticsPlugin::slowest_systems`] to find the systems that take up the most
+/// of the frame budget.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct SystemTimeDiagnosticsPlugin {
+    /// The history length of the created diagnostics, which bounds how many frames
+    /// [`SystemTimeDiagnosticsPlugin::slowest_systems`] can look back.
+    pub max_history_length: usize,
+}
+
+impl Default for SystemTimeDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            max_history_length: DEFAULT_MAX_HISTORY_LENGTH,
+        }
+    }
+}
+
+impl Plugin for SystemTimeDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsStore>()
+            .init_resource::<SystemTimings>()
+            .insert_resource(SystemTimeDiagnosticsState {
+                max_history_length: self.max_history_length,
+                paths: HashMap::new(),
+                frame: HashMap::new(),
+            })
+            .add_systems(Last, Self::diagnostic_system);
+    }
+}
+
+/// The time spent in a system or system set over the last frames, in milliseconds.
+#[derive(Debug, Clone)]
+pub struct SystemTimeSummary {
+    /// The path of the diagnostic.
+    pub path: DiagnosticPath,
+    /// The average time per frame.
+    pub average: f64,
+    /// The longest time in a single frame.
+    pub max: f64,
+}
+
+#[derive(Resource)]
+struct SystemTimeDiagnosticsState {
+    max_history_length: usize,
+    /// Diagnostic paths of every system and set that has been measured so far.
+    paths: HashMap<(InternedScheduleLabel, SystemTimingKind, Arc<str>), DiagnosticPath>,
+    /// Time spent per diagnostic during the current frame.
+    frame: HashMap<DiagnosticPath, f64>,
+}
+
+impl SystemTimeDiagnosticsPlugin {
+    /// The first component of the path of every system time diagnostic.
+    pub const PATH_PREFIX: &'static str = "system_time";
+
+    /// Returns the path of the diagnostic measuring `name` in the given `schedule`.
+    pub fn path(
+        schedule: InternedScheduleLabel,
+        kind: SystemTimingKind,
+        name: &str,
+    ) -> DiagnosticPath {
+        let kind = match kind {
+            SystemTimingKind::System => "systems",
+            SystemTimingKind::Set => "sets",
+        };
+        DiagnosticPath::from_components([
+            Self::PATH_PREFIX,
+            &path_component(&format!("{schedule:?}")),
+            kind,
+            &path_component(name),
+        ])
+    }
+
+    /// Returns the `n` systems that took the most time on average over the last `frames` frames,
+    /// slowest first.
+    pub fn slowest_systems(
+        store: &DiagnosticsStore,
+        n: usize,
+        frames: usize,
+    ) -> Vec<SystemTimeSummary> {
+        Self::slowest(store, "systems", n, frames)
+    }
+
+    /// Returns the `n` system sets that took the most time on average over the last `frames`
+    /// frames, slowest first.
+    pub fn slowest_sets(
+        store: &DiagnosticsStore,
+        n: usize,
+        frames: usize,
+    ) -> Vec<SystemTimeSummary> {
+        Self::slowest(store, "sets", n, frames)
+    }
+
+    fn slowest(
+        store: &DiagnosticsStore,
+        kind: &str,
+        n: usize,
+        frames: usize,
+    ) -> Vec<SystemTimeSummary> {
+        let mut summaries = store
+            .iter()
+            .filter(|diagnostic| diagnostic.is_enabled)
+            .filter(|diagnostic| {
+                let mut components = diagnostic.path().components();
+                components.next() == Some(Self::PATH_PREFIX) && components.nth(1) == Some(kind)
+            })
+            .filter_map(|diagnostic| {
+                let skip = diagnostic.history_len().saturating_sub(frames);
+                let values = diagnostic.values().skip(skip);
+                let (count, sum, max) = values
+                    .fold((0, 0.0, 0.0_f64), |(count, sum, max), &value| {
+                        (count + 1, sum + value, max.max(value))
+                    });
+                (count > 0).then(|| SystemTimeSummary {
+                    path: diagnostic.path().clone(),
+                    average: sum / count as f64,
+                    max,
+                })
+            })
+            .collect::<Vec<_>>();
+        summaries.sort_by(|a, b| b.average.total_cmp(&a.average));
+        summaries.truncate(n);
+        summaries
+    }
+
+    /// Moves the measurements recorded by the executors into the [`DiagnosticsStore`].
+    ///
+    /// Schedules that run after this system are measured in the next frame.
+    fn diagnostic_system(
+        mut timings: ResMut<SystemTimings>,
+        mut store: ResMut<DiagnosticsStore>,
+        mut state: ResMut<SystemTimeDiagnosticsState>,
+    ) {
+        let SystemTimeDiagnosticsState {
+            max_history_length,
+            paths,
+            frame,
+        } = &mut *state;
+
+        for timing in timings.drain() {
+            let path = paths
+                .entry((timing.schedule, timing.kind, timing.name))
+                .or_insert_with_key(|(schedule, kind, name)| {
+                    let path = Self::path(*schedule, *kind, name);
+                    store.add(
+                        Diagnostic::new(path.clone())
+                            .with_suffix("ms")
+                            .with_max_history_length(*max_history_length),
+                    );
+                    path
+                });
+            *frame.entry(path.clone()).or_default() += timing.duration.as_secs_f64() * 1000.0;
+        }
+
+        let time = Instant::now();
+        for path in paths.values() {
+            if let Some(diagnostic) = store.get_mut(path).filter(|d| d.is_enabled) {
+                let value = frame.get(path).copied().unwrap_or_default();
+                diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+            }
+        }
+        frame.clear();
+    }
+}
+
+/// Escapes `name` so that it forms a single, non-empty component of a [`DiagnosticPath`].
+fn path_component(name: &str) -> String {
+    if name.is_empty() {
+        "<unnamed>".to_string()
+    } else {
+        name.replace('/', "_")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::schedule::ScheduleLabel;
+
+    use super::*;
+
+    fn add_diagnostic(
+        store: &mut DiagnosticsStore,
+        kind: SystemTimingKind,
+        name: &str,
+        values: &[f64],
+    ) {
+        let path = SystemTimeDiagnosticsPlugin::path(Update.intern(), kind, name);
+        store.add(Diagnostic::new(path.clone()));
+        let diagnostic = store.get_mut(&path).unwrap();
+        for &value in values {
+            diagnostic.add_measurement(DiagnosticMeasurement {
+                time: Instant::now(),
+                value,
+            });
+        }
+    }
+
+    fn names(summaries: &[SystemTimeSummary]) -> Vec<&str> {
+        summaries
+            .iter()
+            .map(|summary| summary.path.components().last().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn slowest_systems_are_ranked_over_the_frame_window() {
+        let mut store = DiagnosticsStore::default();
+        add_diagnostic(
+            &mut store,
+            SystemTimingKind::System,
+            "spiky",
+            &[12.0, 0.0, 0.0],
+        );
+        add_diagnostic(
+            &mut store,
+            SystemTimingKind::System,
+            "steady",
+            &[1.0, 5.0, 5.0],
+        );
+        add_diagnostic(
+            &mut store,
+            SystemTimingKind::System,
+            "idle",
+            &[0.0, 0.0, 0.0],
+        );
+        add_diagnostic(
+            &mut store,
+            SystemTimingKind::Set,
+            "set",
+            &[100.0, 100.0, 100.0],
+        );
+
+        let slowest = SystemTimeDiagnosticsPlugin::slowest_systems(&store, 3, 3);
+        assert_eq!(names(&slowest), ["spiky", "steady", "idle"]);
+        assert_eq!(slowest[0].average, 4.0);
+        assert_eq!(slowest[0].max, 12.0);
+
+        let slowest = SystemTimeDiagnosticsPlugin::slowest_systems(&store, 2, 2);
+        assert_eq!(names(&slowest), ["steady", "spiky"]);
+        assert_eq!(slowest[0].average, 5.0);
+        assert_eq!(slowest[1].max, 0.0);
+
+        let slowest = SystemTimeDiagnosticsPlugin::slowest_sets(&store, 3, 3);
+        assert_eq!(names(&slowest), ["set"]);
+    }
+
+    #[test]
+    fn slowest_systems_skip_disabled_and_unmeasured_diagnostics() {
+        let mut store = DiagnosticsStore::default();
+        add_diagnostic(&mut store, SystemTimingKind::System, "disabled", &[10.0]);
+        add_diagnostic(&mut store, SystemTimingKind::System, "unmeasured", &[]);
+        add_diagnostic(&mut store, SystemTimingKind::System, "measured", &[1.0]);
+        let path = SystemTimeDiagnosticsPlugin::path(
+            Update.intern(),
+            SystemTimingKind::System,
+            "disabled",
+        );
+        store.get_mut(&path).unwrap().is_enabled = false;
+
+        let slowest = SystemTimeDiagnosticsPlugin::slowest_systems(&store, 3, 3);
+        assert_eq!(names(&slowest), ["measured"]);
+    }
+
+    #[test]
+    fn empty_names_are_escaped() {
+        let path = SystemTimeDiagnosticsPlugin::path(Update.intern(), SystemTimingKind::Set, "");
+        assert_eq!(path.as_str(), "system_time/Update/sets/<unnamed>");
+
+        let path =
+            SystemTimeDiagnosticsPlugin::path(Update.intern(), SystemTimingKind::System, "a/b");
+        assert_eq!(path.as_str(), "system_time/Update/systems/a_b");
+    }
+}
@@ -0,0 +1,165 @@
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    component::{ComponentId, StorageType},
+    prelude::*,
+    storage::MemoryUsage,
+    world::WorldMemoryUsage,
+};
+use bevy_utils::{HashMap, Instant};
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, RegisterDiagnostic,
+    DEFAULT_MAX_HISTORY_LENGTH,
+};
+
+/// Adds diagnostics for the memory used by the main [`World`], in KiB.
+///
+/// The numbers come from [`World::memory_usage`] and are measured once per frame, so they only
+/// include memory owned by the ECS storages, not heap allocations owned by components or
+/// resources themselves. The totals are published under [`Self::TOTAL`] and the other associated
+/// paths. If [`per_component`](Self::per_component) is set, a diagnostic named
+/// `world_memory/components/<component>` is also created for every component type the first time
+/// it is stored.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct WorldMemoryDiagnosticsPlugin {
+    /// Whether to create a diagnostic for every component type, in addition to the totals.
+    pub per_component: bool,
+    /// The history length of the created diagnostics.
+    pub max_history_length: usize,
+}
+
+impl Default for WorldMemoryDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            per_component: true,
+            max_history_length: DEFAULT_MAX_HISTORY_LENGTH,
+        }
+    }
+}
+
+impl Plugin for WorldMemoryDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        for path in [
+            Self::TOTAL,
+            Self::WASTED,
+            Self::TABLES,
+            Self::SPARSE_SETS,
+            Self::RESOURCES,
+            Self::ENTITIES,
+        ] {
+            app.register_diagnostic(
+                Diagnostic::new(path)
+                    .with_suffix("KiB")
+                    .with_max_history_length(self.max_history_length),
+            );
+        }
+        app.insert_resource(WorldMemoryDiagnosticsState {
+            per_component: self.per_component,
+            max_history_length: self.max_history_length,
+            paths: HashMap::new(),
+        })
+        .add_systems(Last, Self::diagnostic_system);
+    }
+}
+
+#[derive(Resource)]
+struct WorldMemoryDiagnosticsState {
+    per_component: bool,
+    max_history_length: usize,
+    /// Diagnostic paths of every component that has been measured so far.
+    paths: HashMap<ComponentId, DiagnosticPath>,
+}
+
+impl WorldMemoryDiagnosticsPlugin {
+    /// The first component of the path of every world memory diagnostic.
+    pub const PATH_PREFIX: &'static str = "world_memory";
+
+    /// Memory allocated by all storages of the world.
+    pub const TOTAL: DiagnosticPath = DiagnosticPath::const_new("world_memory/total");
+    /// Memory allocated by all storages of the world but not used.
+    pub const WASTED: DiagnosticPath = DiagnosticPath::const_new("world_memory/wasted");
+    /// Memory allocated by all tables.
+    pub const TABLES: DiagnosticPath = DiagnosticPath::const_new("world_memory/tables");
+    /// Memory allocated by all sparse sets.
+    pub const SPARSE_SETS: DiagnosticPath = DiagnosticPath::const_new("world_memory/sparse_sets");
+    /// Memory allocated by all resources.
+    pub const RESOURCES: DiagnosticPath = DiagnosticPath::const_new("world_memory/resources");
+    /// Memory allocated for the metadata of entities.
+    pub const ENTITIES: DiagnosticPath = DiagnosticPath::const_new("world_memory/entities");
+
+    /// Returns the path of the diagnostic measuring the component called `name`.
+    pub fn component_path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components([Self::PATH_PREFIX, "components", &name.replace('/', "_")])
+    }
+
+    fn diagnostic_system(world: &mut World) {
+        let usage = world.memory_usage();
+        world.resource_scope(|world, mut store: Mut<DiagnosticsStore>| {
+            let mut state = world.resource_mut::<WorldMemoryDiagnosticsState>();
+            Self::record(&usage, &mut store, &mut state);
+        });
+    }
+
+    fn record(
+        usage: &WorldMemoryUsage,
+        store: &mut DiagnosticsStore,
+        state: &mut WorldMemoryDiagnosticsState,
+    ) {
+        let time = Instant::now();
+        let mut add = |path: &DiagnosticPath, value: f64| {
+            if let Some(diagnostic) = store.get_mut(path).filter(|d| d.is_enabled) {
+                diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+            }
+        };
+
+        let total = usage.total();
+        add(&Self::TOTAL, kib(total.allocated));
+        add(&Self::WASTED, kib(total.wasted()));
+        add(
+            &Self::TABLES,
+            kib(usage.storage(StorageType::Table).allocated),
+        );
+        add(
+            &Self::SPARSE_SETS,
+            kib(usage.storage(StorageType::SparseSet).allocated),
+        );
+        add(&Self::RESOURCES, kib(usage.resources().allocated));
+        add(&Self::ENTITIES, kib(usage.entities.allocated));
+
+        if !state.per_component {
+            return;
+        }
+        let mut components: HashMap<ComponentId, MemoryUsage> = usage
+            .components
+            .iter()
+            .map(|component| (component.id, component.usage))
+            .collect();
+        for component in &usage.components {
+            state.paths.entry(component.id).or_insert_with(|| {
+                let path = Self::component_path(&component.name);
+                store.add(
+                    Diagnostic::new(path.clone())
+                        .with_suffix("KiB")
+                        .with_max_history_length(state.max_history_length),
+                );
+                path
+            });
+        }
+        for (id, path) in &state.paths {
+            if let Some(diagnostic) = store.get_mut(path).filter(|d| d.is_enabled) {
+                let value = components.remove(id).unwrap_or_default().allocated;
+                diagnostic.add_measurement(DiagnosticMeasurement {
+                    time,
+                    value: kib(value),
+                });
+            }
+        }
+    }
+}
+
+fn kib(bytes: usize) -> f64 {
+    bytes as f64 / 1024.0
+}
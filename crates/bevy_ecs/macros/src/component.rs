@@ -1,7 +1,10 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{parse_macro_input, parse_quote, DeriveInput, ExprPath, Ident, LitStr, Path, Result};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, ExprPath, Fields, Ident,
+    LitStr, Member, Path, Result, Type,
+};
 
 pub fn derive_event(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
@@ -38,22 +41,55 @@ pub fn derive_resource(input: TokenStream) -> TokenStream {
 
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+    let clone_fn = clone_fn_specialization(&bevy_ecs_path);
 
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::system::Resource for #struct_name #type_generics #where_clause {
+            fn clone_fn() -> Option<#bevy_ecs_path::component::ComponentCloneFn> {
+                #clone_fn
+            }
         }
     })
 }
 
+fn clone_handler_fn(bevy_ecs_path: &Path, clone_handler: Option<CloneHandlerTy>) -> TokenStream2 {
+    let handler = match clone_handler {
+        None => return TokenStream2::new(),
+        Some(CloneHandlerTy::Default) => {
+            quote!(#bevy_ecs_path::component::ComponentCloneHandler::Default)
+        }
+        Some(CloneHandlerTy::Ignore) => {
+            quote!(#bevy_ecs_path::component::ComponentCloneHandler::Ignore)
+        }
+        Some(CloneHandlerTy::Custom(handler)) => {
+            quote!(#bevy_ecs_path::component::ComponentCloneHandler::Custom(#handler))
+        }
+    };
+    quote! {
+        fn get_component_clone_handler() -> #bevy_ecs_path::component::ComponentCloneHandler {
+            #handler
+        }
+    }
+}
+
 pub fn derive_component(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
     let bevy_ecs_path: Path = crate::bevy_ecs_path();
 
-    let attrs = match parse_component_attr(&ast) {
+    let attrs = match parse_component_attr(&ast, &bevy_ecs_path) {
         Ok(attrs) => attrs,
         Err(e) => return e.into_compile_error().into(),
     };
 
+    let relationship = match derive_relationship(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let relationship_target = match derive_relationship_target(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
 
     let on_add = hook_register_function_call(quote! {on_add}, attrs.on_add);
@@ -68,8 +104,14 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+    let clone_fn = clone_fn_specialization(&bevy_ecs_path);
+    let clone_handler = clone_handler_fn(&bevy_ecs_path, attrs.clone_handler);
 
     TokenStream::from(quote! {
+        #relationship
+
+        #relationship_target
+
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             const STORAGE_TYPE: #bevy_ecs_path::component::StorageType = #storage;
 
@@ -80,16 +122,40 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
                 #on_replace
                 #on_remove
             }
+
+            fn clone_fn() -> Option<#bevy_ecs_path::component::ComponentCloneFn> {
+                #clone_fn
+            }
+
+            #clone_handler
+
+            fn map_entities_fn() -> Option<#bevy_ecs_path::component::ComponentMapEntitiesFn> {
+                use #bevy_ecs_path::component::{ComponentMapEntitiesBase, ComponentMapEntitiesViaMapEntities};
+                (&&#bevy_ecs_path::component::ComponentCloneSpecialization::<Self>::default()).map_entities_fn()
+            }
         }
     })
 }
 
+/// Picks `component_clone_via_clone` if `Self: Clone`, and `None` otherwise.
+fn clone_fn_specialization(bevy_ecs_path: &Path) -> TokenStream2 {
+    quote! {
+        use #bevy_ecs_path::component::{ComponentCloneBase, ComponentCloneViaClone};
+        (&&#bevy_ecs_path::component::ComponentCloneSpecialization::<Self>::default()).clone_fn()
+    }
+}
+
 pub const COMPONENT: &str = "component";
 pub const STORAGE: &str = "storage";
 pub const ON_ADD: &str = "on_add";
 pub const ON_INSERT: &str = "on_insert";
 pub const ON_REPLACE: &str = "on_replace";
 pub const ON_REMOVE: &str = "on_remove";
+pub const CLONE_HANDLER: &str = "clone_handler";
+
+pub const RELATIONSHIP: &str = "relationship";
+pub const RELATIONSHIP_TARGET: &str = "relationship_target";
+pub const LINKED_SPAWN: &str = "linked_spawn";
 
 struct Attrs {
     storage: StorageTy,
@@ -97,6 +163,18 @@ struct Attrs {
     on_insert: Option<ExprPath>,
     on_replace: Option<ExprPath>,
     on_remove: Option<ExprPath>,
+    clone_handler: Option<CloneHandlerTy>,
+    relationship: Option<Relationship>,
+    relationship_target: Option<RelationshipTarget>,
+}
+
+struct Relationship {
+    relationship_target: Type,
+}
+
+struct RelationshipTarget {
+    relationship: Type,
+    linked_spawn: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -109,13 +187,26 @@ enum StorageTy {
 const TABLE: &str = "Table";
 const SPARSE_SET: &str = "SparseSet";
 
-fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
+enum CloneHandlerTy {
+    Default,
+    Ignore,
+    Custom(ExprPath),
+}
+
+// values for `clone_handler` attribute, besides a path to a handler function
+const DEFAULT: &str = "Default";
+const IGNORE: &str = "Ignore";
+
+fn parse_component_attr(ast: &DeriveInput, bevy_ecs_path: &Path) -> Result<Attrs> {
     let mut attrs = Attrs {
         storage: StorageTy::Table,
         on_add: None,
         on_insert: None,
         on_replace: None,
         on_remove: None,
+        clone_handler: None,
+        relationship: None,
+        relationship_target: None,
     };
 
     for meta in ast.attrs.iter().filter(|a| a.path().is_ident(COMPONENT)) {
@@ -143,15 +234,213 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
             } else if nested.path.is_ident(ON_REMOVE) {
                 attrs.on_remove = Some(nested.value()?.parse::<ExprPath>()?);
                 Ok(())
+            } else if nested.path.is_ident(CLONE_HANDLER) {
+                let value = nested.value()?;
+                attrs.clone_handler = Some(if value.peek(LitStr) {
+                    match value.parse::<LitStr>()?.value() {
+                        s if s == DEFAULT => CloneHandlerTy::Default,
+                        s if s == IGNORE => CloneHandlerTy::Ignore,
+                        s => {
+                            return Err(nested.error(format!(
+                                "Invalid clone handler `{s}`, expected '{DEFAULT}', '{IGNORE}' or a path to a handler function.",
+                            )));
+                        }
+                    }
+                } else {
+                    CloneHandlerTy::Custom(value.parse::<ExprPath>()?)
+                });
+                Ok(())
             } else {
                 Err(nested.error("Unsupported attribute"))
             }
         })?;
     }
 
+    for meta in ast.attrs.iter().filter(|a| a.path().is_ident(RELATIONSHIP)) {
+        let mut relationship_target = None;
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident(RELATIONSHIP_TARGET) {
+                relationship_target = Some(nested.value()?.parse::<Type>()?);
+                Ok(())
+            } else {
+                Err(nested.error("Unsupported attribute"))
+            }
+        })?;
+        let Some(relationship_target) = relationship_target else {
+            return Err(syn::Error::new(
+                meta.span(),
+                "Missing `relationship_target = ...` argument",
+            ));
+        };
+        attrs.relationship = Some(Relationship {
+            relationship_target,
+        });
+    }
+
+    for meta in ast
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident(RELATIONSHIP_TARGET))
+    {
+        let mut relationship = None;
+        let mut linked_spawn = false;
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident(RELATIONSHIP) {
+                relationship = Some(nested.value()?.parse::<Type>()?);
+                Ok(())
+            } else if nested.path.is_ident(LINKED_SPAWN) {
+                linked_spawn = true;
+                Ok(())
+            } else {
+                Err(nested.error("Unsupported attribute"))
+            }
+        })?;
+        let Some(relationship) = relationship else {
+            return Err(syn::Error::new(
+                meta.span(),
+                "Missing `relationship = ...` argument",
+            ));
+        };
+        attrs.relationship_target = Some(RelationshipTarget {
+            relationship,
+            linked_spawn,
+        });
+    }
+
+    if attrs.relationship.is_some() && attrs.relationship_target.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "A component cannot be both a `relationship` and a `relationship_target`",
+        ));
+    }
+    if attrs.relationship.is_some() && (attrs.on_insert.is_some() || attrs.on_replace.is_some()) {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Relationship components use the `on_insert` and `on_replace` hooks internally and cannot define their own",
+        ));
+    }
+    if attrs.relationship_target.is_some() && attrs.on_replace.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "RelationshipTarget components use the `on_replace` hook internally and cannot define their own",
+        ));
+    }
+    if attrs.relationship.is_some() {
+        attrs.on_insert = Some(parse_quote!(
+            <Self as #bevy_ecs_path::relationship::Relationship>::on_insert
+        ));
+        attrs.on_replace = Some(parse_quote!(
+            <Self as #bevy_ecs_path::relationship::Relationship>::on_replace
+        ));
+    }
+    if attrs.relationship_target.is_some() {
+        attrs.on_replace = Some(parse_quote!(
+            <Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace
+        ));
+        // The related entities are tracked by the cloned `Relationship` components instead.
+        attrs.clone_handler.get_or_insert(CloneHandlerTy::Ignore);
+    }
+
     Ok(attrs)
 }
 
+/// Returns the single field of a relationship struct, which stores the related entity or entities.
+fn relationship_field<'a>(ast: &'a DeriveInput, attribute: &str) -> Result<(Member, &'a Type)> {
+    let Data::Struct(data) = &ast.data else {
+        return Err(syn::Error::new(
+            ast.span(),
+            format!("`{attribute}` can only be derived for structs"),
+        ));
+    };
+    let field = match &data.fields {
+        Fields::Named(fields) if fields.named.len() == 1 => fields.named.first(),
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first(),
+        _ => None,
+    };
+    let Some(field) = field else {
+        return Err(syn::Error::new(
+            ast.span(),
+            format!("`{attribute}` components must have exactly one field"),
+        ));
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+    Ok((member, &field.ty))
+}
+
+fn derive_relationship(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship) = &attrs.relationship else {
+        return Ok(None);
+    };
+    let (member, _) = relationship_field(ast, RELATIONSHIP)?;
+    let relationship_target = &relationship.relationship_target;
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
+            type RelationshipTarget = #relationship_target;
+
+            #[inline(always)]
+            fn get(&self) -> #bevy_ecs_path::entity::Entity {
+                self.#member
+            }
+
+            #[inline]
+            fn from(entity: #bevy_ecs_path::entity::Entity) -> Self {
+                Self {
+                    #member: entity
+                }
+            }
+        }
+    }))
+}
+
+fn derive_relationship_target(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship_target) = &attrs.relationship_target else {
+        return Ok(None);
+    };
+    let (member, _) = relationship_field(ast, RELATIONSHIP_TARGET)?;
+    let relationship = &relationship_target.relationship;
+    let linked_spawn = relationship_target.linked_spawn;
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
+            const LINKED_SPAWN: bool = #linked_spawn;
+            type Relationship = #relationship;
+
+            #[inline]
+            fn collection(&self) -> &Vec<#bevy_ecs_path::entity::Entity> {
+                &self.#member
+            }
+
+            #[inline]
+            fn collection_mut_risky(&mut self) -> &mut Vec<#bevy_ecs_path::entity::Entity> {
+                &mut self.#member
+            }
+
+            #[inline]
+            fn from_collection_risky(collection: Vec<#bevy_ecs_path::entity::Entity>) -> Self {
+                Self {
+                    #member: collection
+                }
+            }
+        }
+    }))
+}
+
 fn storage_path(bevy_ecs_path: &Path, ty: StorageTy) -> TokenStream2 {
     let storage_type = match ty {
         StorageTy::Table => Ident::new("Table", Span::call_site()),
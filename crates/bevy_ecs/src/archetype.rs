@@ -24,7 +24,9 @@ use crate::{
     component::{ComponentId, Components, StorageType},
     entity::{Entity, EntityLocation},
     observer::Observers,
-    storage::{ImmutableSparseSet, SparseArray, SparseSet, SparseSetIndex, TableId, TableRow},
+    storage::{
+        ImmutableSparseSet, MemoryUsage, SparseArray, SparseSet, SparseSetIndex, TableId, TableRow,
+    },
 };
 use std::{
     hash::Hash,
@@ -274,6 +276,7 @@ impl Edges {
 }
 
 /// Metadata about an [`Entity`] in a [`Archetype`].
+#[derive(Clone)]
 pub struct ArchetypeEntity {
     entity: Entity,
     table_row: TableRow,
@@ -547,6 +550,15 @@ impl Archetype {
         }
     }
 
+    /// Returns the number of bytes used and allocated by the archetype's list of entities.
+    ///
+    /// This does not include the component data of those entities, which is stored in
+    /// [`Table`](crate::storage::Table)s and [`ComponentSparseSet`](crate::storage::ComponentSparseSet)s.
+    #[inline]
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.entities)
+    }
+
     /// Gets the total number of entities that belong to the archetype.
     #[inline]
     pub fn len(&self) -> usize {
@@ -593,6 +605,11 @@ impl Archetype {
         self.entities.clear();
     }
 
+    /// Replaces the entities stored in the archetype.
+    pub(crate) fn set_entities(&mut self, entities: Vec<ArchetypeEntity>) {
+        self.entities = entities;
+    }
+
     /// Returns true if any of the components in this archetype have `on_add` hooks
     #[inline]
     pub fn has_add_hook(&self) -> bool {
@@ -4,13 +4,13 @@ use crate::{
     self as bevy_ecs,
     archetype::ArchetypeFlags,
     change_detection::MAX_CHANGE_AGE,
-    entity::Entity,
+    entity::{ComponentCloneHandlerFn, DynEntityMapper, Entity, MapEntities},
     storage::{SparseSetIndex, Storages},
     system::{Local, Resource, SystemParam},
     world::{DeferredWorld, FromWorld, World},
 };
 pub use bevy_ecs_macros::Component;
-use bevy_ptr::{OwningPtr, UnsafeCellDeref};
+use bevy_ptr::{OwningPtr, Ptr, PtrMut, UnsafeCellDeref};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
 use bevy_utils::TypeIdMap;
@@ -198,6 +198,142 @@ pub trait Component: Send + Sync + 'static {
 
     /// Called when registering this component, allowing mutable access to its [`ComponentHooks`].
     fn register_component_hooks(_hooks: &mut ComponentHooks) {}
+
+    /// Returns the [`ComponentCloneFn`] used to clone values of this component, if it can be cloned.
+    ///
+    /// `#[derive(Component)]` returns [`component_clone_via_clone`] for types that implement [`Clone`],
+    /// and `None` otherwise.
+    fn clone_fn() -> Option<ComponentCloneFn> {
+        None
+    }
+
+    /// Returns the [`ComponentCloneHandler`] used for this component when an entity is cloned
+    /// with an [`EntityCloneBuilder`](crate::entity::EntityCloneBuilder).
+    ///
+    /// `#[derive(Component)]` sets it with `#[component(clone_handler = "Ignore")]`, or with the path to a
+    /// [`ComponentCloneHandlerFn`] such as `#[component(clone_handler = my_clone_handler)]`.
+    fn get_component_clone_handler() -> ComponentCloneHandler {
+        ComponentCloneHandler::Default
+    }
+
+    /// Returns the [`ComponentMapEntitiesFn`] used to update the [`Entity`] references stored in this component.
+    ///
+    /// `#[derive(Component)]` returns [`component_map_entities`] for types that implement [`MapEntities`],
+    /// and `None` otherwise.
+    fn map_entities_fn() -> Option<ComponentMapEntitiesFn> {
+        None
+    }
+}
+
+/// A function that clones the component or resource value behind `source`
+/// and passes ownership of the clone to `target`.
+///
+/// # Safety
+///
+/// `source` must point to a valid value of the type the function was registered for.
+pub type ComponentCloneFn = unsafe fn(source: Ptr<'_>, target: &mut dyn FnMut(OwningPtr<'_>));
+
+/// A [`ComponentCloneFn`] that clones values of `T` using its [`Clone`] implementation.
+///
+/// # Safety
+///
+/// `source` must point to a valid value of type `T`.
+pub unsafe fn component_clone_via_clone<T: Clone>(
+    source: Ptr<'_>,
+    target: &mut dyn FnMut(OwningPtr<'_>),
+) {
+    // SAFETY: Caller ensures `source` points to a valid `T`.
+    let value = unsafe { source.deref::<T>() }.clone();
+    OwningPtr::make(value, target);
+}
+
+/// A function that updates the [`Entity`] references stored in the component value behind `component`.
+///
+/// # Safety
+///
+/// `component` must point to a valid value of the type the function was registered for.
+pub type ComponentMapEntitiesFn =
+    unsafe fn(component: PtrMut<'_>, entity_mapper: &mut dyn DynEntityMapper);
+
+/// A [`ComponentMapEntitiesFn`] that updates values of `T` using its [`MapEntities`] implementation.
+///
+/// # Safety
+///
+/// `component` must point to a valid value of type `T`.
+pub unsafe fn component_map_entities<T: MapEntities>(
+    component: PtrMut<'_>,
+    mut entity_mapper: &mut dyn DynEntityMapper,
+) {
+    // SAFETY: Caller ensures `component` points to a valid `T`.
+    unsafe { component.deref_mut::<T>() }.map_entities(&mut entity_mapper);
+}
+
+/// How a component is handled when an entity is cloned with an [`EntityCloneBuilder`](crate::entity::EntityCloneBuilder).
+#[derive(Clone, Copy, Debug, Default)]
+pub enum ComponentCloneHandler {
+    /// Clone the component with its [`ComponentCloneFn`]. Components that cannot be cloned are skipped.
+    #[default]
+    Default,
+    /// Do not clone the component.
+    Ignore,
+    /// Clone the component with a custom function.
+    Custom(ComponentCloneHandlerFn),
+}
+
+/// Used by `#[derive(Component)]` and `#[derive(Resource)]` to pick [`component_clone_via_clone`]
+/// for types that implement [`Clone`], and [`component_map_entities`] for types that implement
+/// [`MapEntities`], through autoderef specialization.
+#[doc(hidden)]
+pub struct ComponentCloneSpecialization<T>(PhantomData<T>);
+
+impl<T> Default for ComponentCloneSpecialization<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait ComponentCloneBase {
+    fn clone_fn(&self) -> Option<ComponentCloneFn>;
+}
+
+impl<T> ComponentCloneBase for ComponentCloneSpecialization<T> {
+    fn clone_fn(&self) -> Option<ComponentCloneFn> {
+        None
+    }
+}
+
+#[doc(hidden)]
+pub trait ComponentCloneViaClone {
+    fn clone_fn(&self) -> Option<ComponentCloneFn>;
+}
+
+impl<T: Clone> ComponentCloneViaClone for &ComponentCloneSpecialization<T> {
+    fn clone_fn(&self) -> Option<ComponentCloneFn> {
+        Some(component_clone_via_clone::<T>)
+    }
+}
+
+#[doc(hidden)]
+pub trait ComponentMapEntitiesBase {
+    fn map_entities_fn(&self) -> Option<ComponentMapEntitiesFn>;
+}
+
+impl<T> ComponentMapEntitiesBase for ComponentCloneSpecialization<T> {
+    fn map_entities_fn(&self) -> Option<ComponentMapEntitiesFn> {
+        None
+    }
+}
+
+#[doc(hidden)]
+pub trait ComponentMapEntitiesViaMapEntities {
+    fn map_entities_fn(&self) -> Option<ComponentMapEntitiesFn>;
+}
+
+impl<T: MapEntities> ComponentMapEntitiesViaMapEntities for &ComponentCloneSpecialization<T> {
+    fn map_entities_fn(&self) -> Option<ComponentMapEntitiesFn> {
+        Some(component_map_entities::<T>)
+    }
 }
 
 /// The storage used for a specific component type.
@@ -447,6 +583,28 @@ impl ComponentInfo {
         self.descriptor.drop
     }
 
+    /// Returns the function used to clone values of the underlying component type.
+    ///
+    /// Returns `None` if the component type cannot be cloned.
+    #[inline]
+    pub fn clone_fn(&self) -> Option<ComponentCloneFn> {
+        self.descriptor.clone
+    }
+
+    /// Returns how the component is handled when an entity is cloned.
+    #[inline]
+    pub fn clone_handler(&self) -> ComponentCloneHandler {
+        self.descriptor.clone_handler
+    }
+
+    /// Returns the function used to update the [`Entity`] references stored in the component.
+    ///
+    /// Returns `None` if the component type does not store entity references.
+    #[inline]
+    pub fn map_entities_fn(&self) -> Option<ComponentMapEntitiesFn> {
+        self.descriptor.map_entities
+    }
+
     /// Returns a value indicating the storage strategy for the current component.
     #[inline]
     pub fn storage_type(&self) -> StorageType {
@@ -567,9 +725,17 @@ pub struct ComponentDescriptor {
     // this descriptor describes.
     // None if the underlying type doesn't need to be dropped
     drop: Option<for<'a> unsafe fn(OwningPtr<'a>)>,
+    // SAFETY: this function must be safe to call with pointers pointing to items of the type
+    // this descriptor describes, and must pass a value of that same type to its target.
+    // None if the underlying type can't be cloned
+    clone: Option<ComponentCloneFn>,
+    clone_handler: ComponentCloneHandler,
+    // SAFETY: this function must be safe to call with pointers pointing to items of the type
+    // this descriptor describes.
+    map_entities: Option<ComponentMapEntitiesFn>,
 }
 
-// We need to ignore the `drop` field in our `Debug` impl
+// We need to ignore the `drop`, `clone` and `map_entities` fields in our `Debug` impl
 impl std::fmt::Debug for ComponentDescriptor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ComponentDescriptor")
@@ -578,6 +744,7 @@ impl std::fmt::Debug for ComponentDescriptor {
             .field("is_send_and_sync", &self.is_send_and_sync)
             .field("type_id", &self.type_id)
             .field("layout", &self.layout)
+            .field("clone_handler", &self.clone_handler)
             .finish()
     }
 }
@@ -602,6 +769,9 @@ impl ComponentDescriptor {
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            clone: T::clone_fn(),
+            clone_handler: T::get_component_clone_handler(),
+            map_entities: T::map_entities_fn(),
         }
     }
 
@@ -623,9 +793,37 @@ impl ComponentDescriptor {
             type_id: None,
             layout,
             drop,
+            clone: None,
+            clone_handler: ComponentCloneHandler::Default,
+            map_entities: None,
         }
     }
 
+    /// Sets the function used to clone values described by this `ComponentDescriptor`.
+    ///
+    /// # Safety
+    /// - the `clone` fn must be usable on a pointer to a value described by this descriptor,
+    ///   and must pass a value of the same type to its target
+    pub unsafe fn with_clone_fn(mut self, clone: ComponentCloneFn) -> Self {
+        self.clone = Some(clone);
+        self
+    }
+
+    /// Sets the function used to update the [`Entity`] references in values described by this `ComponentDescriptor`.
+    ///
+    /// # Safety
+    /// - the `map_entities` fn must be usable on a pointer to a value described by this descriptor
+    pub unsafe fn with_map_entities_fn(mut self, map_entities: ComponentMapEntitiesFn) -> Self {
+        self.map_entities = Some(map_entities);
+        self
+    }
+
+    /// Sets how the component is handled when an entity is cloned.
+    pub fn with_clone_handler(mut self, clone_handler: ComponentCloneHandler) -> Self {
+        self.clone_handler = clone_handler;
+        self
+    }
+
     /// Create a new `ComponentDescriptor` for a resource.
     ///
     /// The [`StorageType`] for resources is always [`StorageType::Table`].
@@ -639,6 +837,9 @@ impl ComponentDescriptor {
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            clone: T::clone_fn(),
+            clone_handler: ComponentCloneHandler::Default,
+            map_entities: None,
         }
     }
 
@@ -650,6 +851,9 @@ impl ComponentDescriptor {
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            clone: None,
+            clone_handler: ComponentCloneHandler::Default,
+            map_entities: None,
         }
     }
 
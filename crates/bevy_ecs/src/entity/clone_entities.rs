@@ -0,0 +1,536 @@
+use std::collections::VecDeque;
+
+use bevy_ptr::{OwningPtr, Ptr};
+use bevy_utils::{HashMap, HashSet};
+
+use crate::{
+    bundle::Bundle,
+    component::{
+        Component, ComponentCloneHandler, ComponentId, ComponentInfo, ComponentTicks, Tick,
+    },
+    entity::{Entity, EntityHashMap, EntityMapper},
+    storage::{Column, TableRow},
+    system::Commands,
+    world::{CommandQueue, World},
+};
+
+/// A function that clones a component from [`ComponentCloneCtx::source`] to [`ComponentCloneCtx::target`],
+/// used by [`ComponentCloneHandler::Custom`].
+pub type ComponentCloneHandlerFn = fn(&mut ComponentCloneCtx);
+
+/// The context passed to a [`ComponentCloneHandlerFn`] while an entity is being cloned.
+///
+/// Handlers read the component from the source entity and write the clone with
+/// [`write_target_component`](Self::write_target_component). Components written this way are inserted
+/// once every entity of the clone operation has been processed, after their [`Entity`] references
+/// have been updated with [`MapEntities`](crate::entity::MapEntities).
+pub struct ComponentCloneCtx<'a> {
+    world: &'a World,
+    component_info: &'a ComponentInfo,
+    source: Entity,
+    target: Entity,
+    components: &'a mut Vec<(ComponentId, Column)>,
+    queue: &'a mut VecDeque<(Entity, Entity)>,
+    mapper: &'a mut EntityHashMap<Entity>,
+    commands: &'a mut CommandQueue,
+}
+
+impl<'a> ComponentCloneCtx<'a> {
+    /// Returns the entity being cloned.
+    #[inline]
+    pub fn source(&self) -> Entity {
+        self.source
+    }
+
+    /// Returns the entity the clone is written to.
+    #[inline]
+    pub fn target(&self) -> Entity {
+        self.target
+    }
+
+    /// Returns the [`ComponentId`] of the component being cloned.
+    #[inline]
+    pub fn component_id(&self) -> ComponentId {
+        self.component_info.id()
+    }
+
+    /// Returns the [`ComponentInfo`] of the component being cloned.
+    #[inline]
+    pub fn component_info(&self) -> &ComponentInfo {
+        self.component_info
+    }
+
+    /// Returns the world the entity is cloned in.
+    #[inline]
+    pub fn world(&self) -> &'a World {
+        self.world
+    }
+
+    /// Returns the component `C` of the source entity.
+    pub fn read_source_component<C: Component>(&self) -> Option<&'a C> {
+        self.world.get::<C>(self.source)
+    }
+
+    /// Returns a pointer to the component being cloned on the source entity.
+    pub fn read_source_component_ptr(&self) -> Option<Ptr<'a>> {
+        self.world.get_by_id(self.source, self.component_id())
+    }
+
+    /// Writes `component` to the target entity, replacing any value previously written for `C`
+    /// during this clone operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` is not registered in the world.
+    pub fn write_target_component<C: Component>(&mut self, component: C) {
+        let component_id = self.world.component_id::<C>().unwrap_or_else(|| {
+            panic!(
+                "Component {} must be registered to be written by a clone handler.",
+                std::any::type_name::<C>()
+            )
+        });
+        // SAFETY: `component_id` was registered for `C`.
+        let component_info = unsafe { self.world.components().get_info_unchecked(component_id) };
+        let mut column = Column::with_capacity(component_info, 1);
+        OwningPtr::make(component, |ptr| {
+            // SAFETY: `column` stores values of type `C`.
+            unsafe { column.push(ptr, ComponentTicks::new(Tick::new(0))) };
+        });
+        self.push_component(component_id, column);
+    }
+
+    /// Clones `source` with an [`EntityCloneBuilder`] configured like the current one, as part of this
+    /// clone operation, and returns the entity the clone will be written to.
+    ///
+    /// This is used to clone related entities, such as children in a hierarchy.
+    /// If `source` is already cloned by this operation, its clone is returned instead.
+    pub fn queue_entity_clone(&mut self, source: Entity) -> Entity {
+        if let Some(&target) = self.mapper.get(&source) {
+            return target;
+        }
+        let target = self.world.entities().reserve_entity();
+        self.mapper.insert(source, target);
+        self.queue.push_back((source, target));
+        target
+    }
+
+    /// Returns the clone of `source`, if `source` is cloned by this clone operation.
+    pub fn mapped_entity(&self, source: Entity) -> Option<Entity> {
+        self.mapper.get(&source).copied()
+    }
+
+    /// Returns [`Commands`] that are applied once all cloned components have been inserted.
+    pub fn commands(&mut self) -> Commands<'a, '_> {
+        Commands::new_from_entities(self.commands, self.world.entities())
+    }
+
+    fn push_component(&mut self, component_id: ComponentId, column: Column) {
+        match self
+            .components
+            .iter_mut()
+            .find(|(id, _)| *id == component_id)
+        {
+            Some((_, existing)) => *existing = column,
+            None => self.components.push((component_id, column)),
+        }
+    }
+}
+
+/// Clones the component with its [`ComponentCloneFn`](crate::component::ComponentCloneFn), if it has one.
+fn component_clone_default(ctx: &mut ComponentCloneCtx) {
+    let Some(clone_fn) = ctx.component_info.clone_fn() else {
+        return;
+    };
+    let Some(source) = ctx.read_source_component_ptr() else {
+        return;
+    };
+    let mut column = Column::with_capacity(ctx.component_info, 1);
+    // SAFETY: `source` points to the component described by `component_info`,
+    // so `clone_fn` is valid for it and `column` stores the same type.
+    unsafe {
+        clone_fn(source, &mut |value| {
+            column.push(value, ComponentTicks::new(Tick::new(0)));
+        });
+    }
+    ctx.push_component(ctx.component_id(), column);
+}
+
+/// Maps the entities cloned by a clone operation to their clones, leaving other entities untouched.
+struct CloneEntityMapper<'a>(&'a EntityHashMap<Entity>);
+
+impl EntityMapper for CloneEntityMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+
+    fn mappings(&self) -> impl Iterator<Item = (Entity, Entity)> {
+        self.0.iter().map(|(&source, &target)| (source, target))
+    }
+}
+
+/// A builder for cloning the components of an entity onto another entity.
+///
+/// By default, every component is cloned according to its [`ComponentCloneHandler`]:
+/// components with a [`ComponentCloneFn`](crate::component::ComponentCloneFn), such as those deriving [`Clone`],
+/// are cloned, and other components are skipped. Components can be filtered with
+/// [`allow`](Self::allow) and [`deny`](Self::deny), and their handlers overridden with
+/// [`override_component_clone_handler`](Self::override_component_clone_handler).
+///
+/// After cloning, [`Entity`] references stored in the cloned components are updated through
+/// [`MapEntities`](crate::entity::MapEntities), so that references to the source entity,
+/// or any other entity cloned by the same operation, point at their clones.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::entity::EntityCloneBuilder;
+/// #
+/// #[derive(Component, Clone, PartialEq, Debug)]
+/// struct Health(u32);
+///
+/// #[derive(Component, Clone, PartialEq, Debug)]
+/// struct Target(Entity);
+///
+/// let mut world = World::new();
+/// let source = world.spawn(Health(10)).id();
+/// let target = world.spawn_empty().id();
+///
+/// let mut builder = EntityCloneBuilder::new(&mut world);
+/// builder.deny::<Target>();
+/// builder.clone_entity(source, target);
+///
+/// assert_eq!(world.get::<Health>(target), Some(&Health(10)));
+/// ```
+pub struct EntityCloneBuilder<'w> {
+    world: &'w mut World,
+    filter_allows_components: bool,
+    filter: HashSet<ComponentId>,
+    clone_handler_overrides: HashMap<ComponentId, ComponentCloneHandler>,
+}
+
+impl<'w> EntityCloneBuilder<'w> {
+    /// Creates a new [`EntityCloneBuilder`] for `world`, which clones every component by default.
+    pub fn new(world: &'w mut World) -> Self {
+        Self {
+            world,
+            filter_allows_components: false,
+            filter: Default::default(),
+            clone_handler_overrides: Default::default(),
+        }
+    }
+
+    /// Returns the world entities are cloned in.
+    pub fn world(&mut self) -> &mut World {
+        self.world
+    }
+
+    /// Clones the components of `source` onto `target`, and applies any commands queued by clone handlers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` or `target` does not exist.
+    pub fn clone_entity(self, source: Entity, target: Entity) {
+        let EntityCloneBuilder {
+            world,
+            filter_allows_components,
+            filter,
+            clone_handler_overrides,
+        } = self;
+        assert!(
+            world.entities().contains(target),
+            "Clone target {target:?} does not exist."
+        );
+
+        let mut mapper = EntityHashMap::default();
+        mapper.insert(source, target);
+        let mut queue = VecDeque::from([(source, target)]);
+        let mut commands = CommandQueue::default();
+        let mut cloned = Vec::new();
+
+        while let Some((source, target)) = queue.pop_front() {
+            let world: &World = world;
+            let source_entity = world
+                .get_entity(source)
+                .unwrap_or_else(|| panic!("Clone source {source:?} does not exist."));
+            let mut components = Vec::new();
+            for component_id in source_entity.archetype().components() {
+                if filter_allows_components != filter.contains(&component_id) {
+                    continue;
+                }
+                // SAFETY: Components in an archetype are registered.
+                let component_info = unsafe { world.components().get_info_unchecked(component_id) };
+                let handler = match clone_handler_overrides
+                    .get(&component_id)
+                    .copied()
+                    .unwrap_or(component_info.clone_handler())
+                {
+                    ComponentCloneHandler::Default => component_clone_default,
+                    ComponentCloneHandler::Ignore => continue,
+                    ComponentCloneHandler::Custom(handler) => handler,
+                };
+                handler(&mut ComponentCloneCtx {
+                    world,
+                    component_info,
+                    source,
+                    target,
+                    components: &mut components,
+                    queue: &mut queue,
+                    mapper: &mut mapper,
+                    commands: &mut commands,
+                });
+            }
+            cloned.push((target, components));
+        }
+
+        // Entities cloned through `ComponentCloneCtx::queue_entity_clone` were only reserved.
+        world.flush_entities();
+
+        let row = TableRow::from_usize(0);
+        for (target, mut components) in cloned {
+            for (component_id, column) in &mut components {
+                // SAFETY: The column was created from the registered component's info.
+                let component_info =
+                    unsafe { world.components().get_info_unchecked(*component_id) };
+                if let Some(map_entities) = component_info.map_entities_fn() {
+                    // SAFETY: The column holds a single value of the type described by `component_info`.
+                    unsafe {
+                        map_entities(
+                            column.get_data_unchecked_mut(row),
+                            &mut CloneEntityMapper(&mapper),
+                        );
+                    }
+                }
+            }
+            let component_ids: Vec<_> = components.iter().map(|(id, _)| *id).collect();
+            let values = components.iter_mut().map(|(_, column)| {
+                // SAFETY: Each column holds a single value, which is moved out here and owned by the target entity afterwards.
+                unsafe { column.swap_remove_and_forget_unchecked(row).0 }
+            });
+            // SAFETY: Each value is of the type of the component id it is paired with.
+            unsafe {
+                world
+                    .entity_mut(target)
+                    .insert_by_ids(&component_ids, values);
+            }
+        }
+
+        commands.apply(world);
+    }
+
+    /// Adds the components of `B` to the list of components to clone.
+    ///
+    /// The first call to `allow` switches the builder from cloning every component but the denied ones
+    /// to cloning only the allowed ones.
+    pub fn allow<B: Bundle>(&mut self) -> &mut Self {
+        let component_ids = self.world.init_bundle::<B>().components().to_vec();
+        self.allow_by_ids(component_ids)
+    }
+
+    /// Adds the components with the given ids to the list of components to clone.
+    ///
+    /// See [`allow`](Self::allow).
+    pub fn allow_by_ids(&mut self, ids: impl IntoIterator<Item = ComponentId>) -> &mut Self {
+        if self.filter_allows_components {
+            self.filter.extend(ids);
+        } else {
+            self.filter_allows_components = true;
+            self.filter = ids.into_iter().collect();
+        }
+        self
+    }
+
+    /// Excludes the components of `B` from cloning.
+    pub fn deny<B: Bundle>(&mut self) -> &mut Self {
+        let component_ids = self.world.init_bundle::<B>().components().to_vec();
+        self.deny_by_ids(component_ids)
+    }
+
+    /// Excludes the components with the given ids from cloning.
+    pub fn deny_by_ids(&mut self, ids: impl IntoIterator<Item = ComponentId>) -> &mut Self {
+        if self.filter_allows_components {
+            for id in ids {
+                self.filter.remove(&id);
+            }
+        } else {
+            self.filter.extend(ids);
+        }
+        self
+    }
+
+    /// Clones every component, removing any previous filter.
+    pub fn allow_all(&mut self) -> &mut Self {
+        self.filter_allows_components = false;
+        self.filter.clear();
+        self
+    }
+
+    /// Clones no component, removing any previous filter. Use [`allow`](Self::allow) to add components back.
+    pub fn deny_all(&mut self) -> &mut Self {
+        self.filter_allows_components = true;
+        self.filter.clear();
+        self
+    }
+
+    /// Overrides the [`ComponentCloneHandler`] of `C` for this clone operation.
+    pub fn override_component_clone_handler<C: Component>(
+        &mut self,
+        handler: ComponentCloneHandler,
+    ) -> &mut Self {
+        let component_id = self.world.init_component::<C>();
+        self.clone_handler_overrides.insert(component_id, handler);
+        self
+    }
+
+    /// Removes the override of the [`ComponentCloneHandler`] of `C`.
+    pub fn remove_component_clone_handler_override<C: Component>(&mut self) -> &mut Self {
+        if let Some(component_id) = self.world.component_id::<C>() {
+            self.clone_handler_overrides.remove(&component_id);
+        }
+        self
+    }
+}
+
+impl World {
+    /// Spawns a clone of `source` with an [`EntityCloneBuilder`] using the default configuration,
+    /// and returns the new entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` does not exist.
+    pub fn clone_entity(&mut self, source: Entity) -> Entity {
+        let target = self.spawn_empty().id();
+        EntityCloneBuilder::new(self).clone_entity(source, target);
+        target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentCloneCtx, EntityCloneBuilder};
+    use crate as bevy_ecs;
+    use crate::{
+        component::ComponentCloneHandler,
+        entity::{EntityMapper, MapEntities},
+        prelude::*,
+        world::CommandQueue,
+    };
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct A(u32);
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    #[component(storage = "SparseSet")]
+    struct B(String);
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    #[component(clone_handler = "Ignore")]
+    struct NoClone;
+
+    #[derive(Component)]
+    struct NotCloneable;
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Link(Entity);
+
+    impl MapEntities for Link {
+        fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+            self.0 = entity_mapper.map_entity(self.0);
+        }
+    }
+
+    #[derive(Component, Debug, PartialEq)]
+    #[component(clone_handler = clone_linked)]
+    struct Linked(Entity);
+
+    fn clone_linked(ctx: &mut ComponentCloneCtx) {
+        let linked = ctx.read_source_component::<Linked>().unwrap().0;
+        let clone = ctx.queue_entity_clone(linked);
+        ctx.write_target_component(Linked(clone));
+    }
+
+    #[test]
+    fn clone_entity() {
+        let mut world = World::new();
+        let source = world
+            .spawn((A(1), B("b".into()), NoClone, NotCloneable))
+            .id();
+        let clone = world.clone_entity(source);
+
+        assert_eq!(world.get::<A>(clone), Some(&A(1)));
+        assert_eq!(world.get::<B>(clone), Some(&B("b".into())));
+        assert!(!world.entity(clone).contains::<NoClone>());
+        assert!(!world.entity(clone).contains::<NotCloneable>());
+        assert!(world.entity(source).contains::<NoClone>());
+    }
+
+    #[test]
+    fn clone_entity_filters() {
+        let mut world = World::new();
+        let source = world.spawn((A(1), B("b".into()))).id();
+
+        let target = world.spawn_empty().id();
+        let mut builder = EntityCloneBuilder::new(&mut world);
+        builder.deny::<B>();
+        builder.clone_entity(source, target);
+        assert!(world.entity(target).contains::<A>());
+        assert!(!world.entity(target).contains::<B>());
+
+        let target = world.spawn_empty().id();
+        let mut builder = EntityCloneBuilder::new(&mut world);
+        builder.allow::<B>();
+        builder.clone_entity(source, target);
+        assert!(!world.entity(target).contains::<A>());
+        assert!(world.entity(target).contains::<B>());
+
+        let target = world.spawn_empty().id();
+        let mut builder = EntityCloneBuilder::new(&mut world);
+        builder
+            .override_component_clone_handler::<A>(ComponentCloneHandler::Ignore)
+            .override_component_clone_handler::<NoClone>(ComponentCloneHandler::Default);
+        builder.clone_entity(source, target);
+        assert!(!world.entity(target).contains::<A>());
+        assert!(world.entity(target).contains::<B>());
+    }
+
+    #[test]
+    fn clone_entity_maps_entities() {
+        let mut world = World::new();
+        let other = world.spawn_empty().id();
+        let source = world.spawn(A(1)).id();
+        world.entity_mut(source).insert(Link(source));
+        let linked = world.spawn((A(2), Link(source))).id();
+        world.entity_mut(source).insert(Linked(linked));
+        let with_other = world.spawn(Link(other)).id();
+
+        let clone = world.clone_entity(source);
+        assert_eq!(world.get::<Link>(clone), Some(&Link(clone)));
+        let linked_clone = world.get::<Linked>(clone).unwrap().0;
+        assert_ne!(linked_clone, linked);
+        assert_eq!(world.get::<A>(linked_clone), Some(&A(2)));
+        assert_eq!(world.get::<Link>(linked_clone), Some(&Link(clone)));
+
+        let clone = world.clone_entity(with_other);
+        assert_eq!(world.get::<Link>(clone), Some(&Link(other)));
+    }
+
+    #[test]
+    fn clone_and_spawn_command() {
+        let mut world = World::new();
+        let source = world.spawn((A(1), B("b".into()))).id();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let clone = commands.entity(source).clone_and_spawn().id();
+        let filtered = commands
+            .entity(source)
+            .clone_and_spawn_with(|builder| {
+                builder.deny::<A>();
+            })
+            .id();
+        queue.apply(&mut world);
+
+        assert_eq!(world.get::<A>(clone), Some(&A(1)));
+        assert_eq!(world.get::<B>(clone), Some(&B("b".into())));
+        assert!(!world.entity(filtered).contains::<A>());
+        assert!(world.entity(filtered).contains::<B>());
+    }
+}
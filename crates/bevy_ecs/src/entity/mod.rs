@@ -35,11 +35,13 @@
 //! [`World::despawn`]: crate::world::World::despawn
 //! [`EntityWorldMut::insert`]: crate::world::EntityWorldMut::insert
 //! [`EntityWorldMut::remove`]: crate::world::EntityWorldMut::remove
+mod clone_entities;
 mod map_entities;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
 #[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+pub use clone_entities::*;
 pub use map_entities::*;
 
 mod hash;
@@ -55,7 +57,7 @@ use crate::{
         masks::{IdentifierMask, HIGH_MASK},
         Identifier,
     },
-    storage::{SparseSetIndex, TableId, TableRow},
+    storage::{MemoryUsage, SparseSetIndex, TableId, TableRow},
 };
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
@@ -499,6 +501,17 @@ pub struct Entities {
     len: u32,
 }
 
+impl Clone for Entities {
+    fn clone(&self) -> Self {
+        Entities {
+            meta: self.meta.clone(),
+            pending: self.pending.clone(),
+            free_cursor: AtomicIdCursor::new(self.free_cursor.load(Ordering::Relaxed)),
+            len: self.len,
+        }
+    }
+}
+
 impl Entities {
     pub(crate) const fn new() -> Self {
         Entities {
@@ -891,6 +904,12 @@ impl Entities {
         self.meta.len()
     }
 
+    /// Returns the number of bytes used and allocated for the metadata of all entities,
+    /// including freed entities whose ids can be reused.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.meta) + MemoryUsage::of_vec(&self.pending)
+    }
+
     /// The count of currently allocated entities.
     #[inline]
     pub fn len(&self) -> u32 {
@@ -0,0 +1,171 @@
+//! Disabled entities do not show up in queries unless the query explicitly mentions them.
+//!
+//! This is useful for "turning off" entities, such as pooled enemies or unloaded level chunks,
+//! without despawning them and losing their data.
+//!
+//! Entities are disabled by adding a disabling component to them, such as the built-in [`Disabled`] marker.
+//! Every [`QueryState`](crate::query::QueryState) created for a [`World`] consults that world's [`DefaultQueryFilters`]
+//! and adds a `Without<C>` filter for each disabling component `C`, unless the query already refers to `C`
+//! (for example through [`With<C>`](crate::query::With), [`Has<C>`](crate::query::Has) or [`Allows<C>`]).
+//!
+//! Other crates can register their own disabling components with [`World::register_disabling_component`].
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::entity_disabling::Disabled;
+//! # use bevy_ecs::query::Allows;
+//! #
+//! # #[derive(Component)]
+//! # struct Enemy;
+//! #
+//! let mut world = World::new();
+//! world.spawn(Enemy);
+//! world.spawn((Enemy, Disabled));
+//!
+//! assert_eq!(world.query::<&Enemy>().iter(&world).count(), 1);
+//! assert_eq!(world.query_filtered::<&Enemy, Allows<Disabled>>().iter(&world).count(), 2);
+//! assert_eq!(world.query_filtered::<&Enemy, With<Disabled>>().iter(&world).count(), 1);
+//! ```
+//!
+//! Disabling components must use [`StorageType::Table`], as queries rely on the archetype and table of an
+//! entity to exclude it.
+//!
+//! [`Allows<C>`]: crate::query::Allows
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, ComponentId, StorageType},
+    query::FilteredAccess,
+    world::World,
+};
+#[cfg(feature = "bevy_reflect")]
+use {
+    crate::reflect::ReflectComponent, bevy_reflect::std_traits::ReflectDefault,
+    bevy_reflect::Reflect,
+};
+
+/// A marker component for disabled entities. See [the module docs](crate::entity_disabling) for more info.
+#[derive(Component, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component, Default))]
+pub struct Disabled;
+
+/// The set of components that are filtered out of every query by default, unless the query
+/// explicitly refers to them.
+///
+/// Each [`World`] stores its own set, with [`Disabled`] registered by default.
+/// It can be read with [`World::default_query_filters`].
+/// See [the module docs](crate::entity_disabling) for more info.
+#[derive(Debug, Default, Clone)]
+pub struct DefaultQueryFilters {
+    disabling: Vec<ComponentId>,
+}
+
+impl DefaultQueryFilters {
+    /// Registers the component with the given `id` as a disabling component.
+    fn register_disabling_component(&mut self, id: ComponentId) {
+        if !self.disabling.contains(&id) {
+            self.disabling.push(id);
+        }
+    }
+
+    /// Returns the ids of all registered disabling components.
+    pub fn disabling_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.disabling.iter().copied()
+    }
+
+    /// Adds a `Without` filter to `component_access` for every disabling component it does not already refer to.
+    pub(crate) fn apply(&self, component_access: &mut FilteredAccess<ComponentId>) {
+        for &id in &self.disabling {
+            if !component_access.contains(id) {
+                component_access.and_without(id);
+            }
+        }
+    }
+}
+
+impl World {
+    /// Registers `C` as a disabling component: entities with `C` are skipped by queries created afterwards,
+    /// unless the query refers to `C`. See [the module docs](crate::entity_disabling) for more info.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` does not use [`StorageType::Table`].
+    pub fn register_disabling_component<C: Component>(&mut self) {
+        assert_eq!(
+            C::STORAGE_TYPE,
+            StorageType::Table,
+            "Disabling component {} must use table storage.",
+            std::any::type_name::<C>()
+        );
+        let id = self.init_component::<C>();
+        self.default_query_filters.register_disabling_component(id);
+    }
+
+    /// Returns the [`DefaultQueryFilters`] applied to queries created for this world.
+    #[inline]
+    pub fn default_query_filters(&self) -> &DefaultQueryFilters {
+        &self.default_query_filters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Disabled;
+    use crate as bevy_ecs;
+    use crate::{
+        prelude::*,
+        query::Allows,
+        system::{Query, SystemState},
+    };
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    struct Unloaded;
+
+    #[test]
+    fn disabled_entities_are_hidden() {
+        let mut world = World::new();
+        let enabled = world.spawn(A).id();
+        let disabled = world.spawn((A, Disabled)).id();
+
+        let mut query = world.query::<Entity>();
+        assert!(query.get(&world, disabled).is_err());
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), [enabled]);
+
+        let mut query = world.query::<(Entity, Has<Disabled>)>();
+        assert_eq!(query.iter(&world).count(), 2);
+
+        let mut query = world.query_filtered::<Entity, (With<A>, Allows<Disabled>)>();
+        assert_eq!(query.iter(&world).count(), 2);
+
+        let mut query = world.query_filtered::<Entity, With<Disabled>>();
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), [disabled]);
+
+        let mut state = SystemState::<Query<&A>>::new(&mut world);
+        assert_eq!(state.get(&world).iter().count(), 1);
+
+        world.entity_mut(disabled).remove::<Disabled>();
+        assert_eq!(state.get(&world).iter().count(), 2);
+    }
+
+    #[test]
+    fn custom_disabling_components() {
+        let mut world = World::new();
+        world.register_disabling_component::<Unloaded>();
+        world.spawn(A);
+        world.spawn((A, Unloaded));
+        world.spawn((A, Disabled));
+
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+        assert_eq!(
+            world
+                .query_filtered::<&A, Allows<Unloaded>>()
+                .iter(&world)
+                .count(),
+            2
+        );
+        assert_eq!(world.default_query_filters().disabling_ids().count(), 2);
+    }
+}
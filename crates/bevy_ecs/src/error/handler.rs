@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+use std::sync::Mutex;
+
+use bevy_utils::{
+    tracing::{error as log_error, warn as log_warn},
+    HashSet,
+};
+
+use crate::{self as bevy_ecs, component::Tick, system::Resource};
+
+use super::Error;
+
+/// Context about the system that produced an error, passed to the [`SystemErrorHandler`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemErrorContext {
+    /// The name of the system that failed, as returned by [`SystemName`](crate::system::SystemName).
+    pub name: Cow<'static, str>,
+    /// The tick of the previous run of the system, before the run that failed.
+    ///
+    /// See [`System::get_last_run`](crate::system::System::get_last_run).
+    pub last_run: Tick,
+}
+
+/// A function that handles errors returned by fallible systems.
+pub type SystemErrorHandlerFn = fn(Error, SystemErrorContext);
+
+/// The [`Resource`] that decides what happens when a fallible system returns an error.
+///
+/// If this resource is not present in the [`World`](crate::world::World), errors [`panic`].
+/// Bevy provides [`panic`], [`error`], [`warn`], [`warn_once`] and [`ignore`],
+/// but any [`SystemErrorHandlerFn`] can be used.
+#[derive(Resource, Clone, Copy)]
+pub struct SystemErrorHandler(pub SystemErrorHandlerFn);
+
+impl Default for SystemErrorHandler {
+    fn default() -> Self {
+        Self(panic)
+    }
+}
+
+/// Error handler that panics with the system name and the error.
+#[track_caller]
+#[inline]
+pub fn panic(error: Error, context: SystemErrorContext) {
+    panic!("Encountered an error in system `{}`: {error}", context.name);
+}
+
+/// Error handler that logs the system name and the error at the `error` level.
+#[inline]
+pub fn error(error: Error, context: SystemErrorContext) {
+    log_error!("Encountered an error in system `{}`: {error}", context.name);
+}
+
+/// Error handler that logs the system name and the error at the `warn` level.
+#[inline]
+pub fn warn(error: Error, context: SystemErrorContext) {
+    log_warn!("Encountered an error in system `{}`: {error}", context.name);
+}
+
+/// Error handler that logs the system name and the error at the `warn` level,
+/// but only the first time each system fails.
+pub fn warn_once(error: Error, context: SystemErrorContext) {
+    static WARNED: Mutex<Option<HashSet<Cow<'static, str>>>> = Mutex::new(None);
+
+    let mut warned = WARNED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if warned
+        .get_or_insert_with(HashSet::default)
+        .insert(context.name.clone())
+    {
+        warn(error, context);
+    }
+}
+
+/// Error handler that silently discards the error.
+#[inline]
+pub fn ignore(_: Error, _: SystemErrorContext) {}
+
+/// The [`Resource`] that decides what happens when a fallible command fails, unless the command
+/// was queued with its own handler.
+///
+/// The [`SystemErrorContext`] passed to the handler describes the system that queued the command.
+/// If this resource is not present in the [`World`](crate::world::World), failing commands
+/// [`panic`].
+///
+/// See [`Commands::add_fallible`](crate::system::Commands::add_fallible) and
+/// [`EntityCommands::on_error`](crate::system::EntityCommands::on_error).
+#[derive(Resource, Clone, Copy)]
+pub struct CommandErrorHandler(pub SystemErrorHandlerFn);
+
+impl Default for CommandErrorHandler {
+    fn default() -> Self {
+        Self(panic)
+    }
+}
@@ -0,0 +1,48 @@
+//! Error types and handlers for fallible systems.
+//!
+//! Systems added to a [`Schedule`](crate::schedule::Schedule) may return [`Result`], which lets them use `?`
+//! instead of panicking or returning early by hand. Such systems are wrapped in a
+//! [`FallibleSystem`](crate::system::FallibleSystem) when added. Any error type that converts into [`Error`] can be returned,
+//! including the query errors [`QuerySingleError`](crate::query::QuerySingleError) and
+//! [`QueryEntityError`](crate::query::QueryEntityError).
+//!
+//! When a system returns an error, it is passed to the world's [`SystemErrorHandler`] resource,
+//! along with a [`SystemErrorContext`] describing the failing system. If the resource is not present,
+//! [`panic`] is used.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::error::{self, SystemErrorHandler};
+//! #
+//! # #[derive(Component)]
+//! # struct Player;
+//! # #[derive(Component)]
+//! # struct Health(u32);
+//! #
+//! fn heal_player(mut player: Query<&mut Health, With<Player>>) -> error::Result {
+//!     player.get_single_mut()?.0 += 1;
+//!     Ok(())
+//! }
+//!
+//! let mut world = World::new();
+//! // Log errors instead of panicking.
+//! world.insert_resource(SystemErrorHandler(error::warn));
+//!
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems(heal_player);
+//! // There is no player: the error is logged as a warning.
+//! schedule.run(&mut world);
+//! ```
+//!
+//! Fallible [commands](crate::system::Commands::add_fallible) report their errors the same way,
+//! through the [`CommandErrorHandler`] resource or a handler chosen for the individual command.
+
+mod handler;
+
+pub use handler::*;
+
+/// The error type returned by fallible systems: a boxed, type-erased [`std::error::Error`].
+pub type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// A result type for fallible systems, using [`Error`] by default.
+pub type Result<T = (), E = Error> = std::result::Result<T, E>;
@@ -1,4 +1,4 @@
-use crate::{component::Component, traversal::Traversal};
+use crate::{component::Component, entity::Entity, traversal::Traversal, world::DeferredWorld};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
 use std::{
@@ -46,6 +46,17 @@ pub trait Event: Component {
     /// [triggered]: crate::system::Commands::trigger_targets
     /// [`Trigger::propagate`]: crate::observer::Trigger::propagate
     const AUTO_PROPAGATE: bool = false;
+
+    /// Runs after this event has been [triggered] for `target` and has finished propagating, unless an
+    /// observer called [`Trigger::prevent_default`]. `target` is [`Entity::PLACEHOLDER`] for events
+    /// triggered without target entities.
+    ///
+    /// Does nothing by default.
+    ///
+    /// [triggered]: crate::system::Commands::trigger_targets
+    /// [`Trigger::prevent_default`]: crate::observer::Trigger::prevent_default
+    #[allow(unused_variables)]
+    fn default_action(world: DeferredWorld, event: &mut Self, target: Entity) {}
 }
 
 /// An `EventId` uniquely identifies an event stored in a specific [`World`].
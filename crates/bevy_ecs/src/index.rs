@@ -0,0 +1,913 @@
+//! Indexes that map component values to the entities that have them.
+//!
+//! See [`World::init_component_index`] for how to opt a component into indexing.
+
+use std::{
+    collections::{btree_map, BTreeMap},
+    hash::Hash,
+    marker::PhantomData,
+    ops::RangeBounds,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use bevy_utils::{hashbrown::hash_set, HashMap};
+
+use crate::{
+    self as bevy_ecs,
+    archetype::Archetype,
+    change_detection::DetectChangesMut,
+    component::{Component, ComponentHook, ComponentId, Components, Tick},
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    query::{
+        Changed, FilteredAccess, QueryData, QueryFilter, QueryManyIter, ROQueryItem, With,
+        WorldQuery,
+    },
+    storage::{Table, TableRow},
+    system::{Query, Res, ResMut, Resource, SystemMeta, SystemParam},
+    world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
+};
+
+/// How a [`ComponentIndex`] stores the entities for each value.
+///
+/// This is implemented by [`HashIndex`], which requires [`Hash`] and [`Eq`], and by
+/// [`OrderedIndex`], which requires [`Ord`] and additionally supports [range queries](ComponentIndex::range).
+pub trait IndexStorage<C>: Default + Send + Sync + 'static {
+    /// Records that `entity` has the component `value`.
+    fn insert(&mut self, value: C, entity: Entity);
+
+    /// Records that `entity` no longer has the component `value`.
+    fn remove(&mut self, value: &C, entity: Entity);
+
+    /// Returns the entities that have the component `value`.
+    fn get(&self, value: &C) -> Option<&EntityHashSet>;
+
+    /// Returns the number of distinct values in the index.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if no entity has an indexed value.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// An [`IndexStorage`] backed by a hash map.
+pub struct HashIndex<C>(HashMap<C, EntityHashSet>);
+
+impl<C> Default for HashIndex<C> {
+    fn default() -> Self {
+        Self(HashMap::default())
+    }
+}
+
+impl<C: Hash + Eq + Send + Sync + 'static> IndexStorage<C> for HashIndex<C> {
+    fn insert(&mut self, value: C, entity: Entity) {
+        self.0.entry(value).or_default().insert(entity);
+    }
+
+    fn remove(&mut self, value: &C, entity: Entity) {
+        if let Some(entities) = self.0.get_mut(value) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.0.remove(value);
+            }
+        }
+    }
+
+    fn get(&self, value: &C) -> Option<&EntityHashSet> {
+        self.0.get(value)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// An [`IndexStorage`] backed by a B-tree, which keeps values sorted.
+pub struct OrderedIndex<C>(BTreeMap<C, EntityHashSet>);
+
+impl<C> Default for OrderedIndex<C> {
+    fn default() -> Self {
+        Self(BTreeMap::new())
+    }
+}
+
+impl<C: Ord + Send + Sync + 'static> IndexStorage<C> for OrderedIndex<C> {
+    fn insert(&mut self, value: C, entity: Entity) {
+        self.0.entry(value).or_default().insert(entity);
+    }
+
+    fn remove(&mut self, value: &C, entity: Entity) {
+        if let Some(entities) = self.0.get_mut(value) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.0.remove(value);
+            }
+        }
+    }
+
+    fn get(&self, value: &C) -> Option<&EntityHashSet> {
+        self.0.get(value)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// A [`Resource`] mapping each value of the component `C` to the entities that have it.
+///
+/// The index is created with [`World::init_component_index`] or
+/// [`World::init_ordered_component_index`] and is kept up to date by the component hooks of `C`,
+/// so lookups never scan archetypes. Use it through [`Res<ComponentIndex<C>>`](Res) or
+/// [`QueryByIndex`].
+///
+/// The hooks only see `C` being inserted, replaced or removed. Components mutated in place
+/// through `&mut C` are re-indexed by [`QueryByIndex`] before it is used, or by the
+/// [`ComponentIndex::update_system`], which should run before other systems reading the index.
+#[derive(Resource)]
+pub struct ComponentIndex<C: Component, S: IndexStorage<C> = HashIndex<C>> {
+    storage: S,
+    /// The indexed value of each entity, which may differ from its current `C` until the
+    /// index is updated.
+    values: EntityHashMap<C>,
+}
+
+/// Marks that the hooks of a [`ComponentIndex`] were registered for `C`, and holds the hooks that
+/// were registered before them, which are run by the index hooks.
+///
+/// This outlives the index itself, so removing and re-creating the index neither loses the
+/// previous hooks nor registers the index hooks twice.
+#[derive(Resource)]
+struct ComponentIndexHooks<C: Component, S: IndexStorage<C>> {
+    on_insert: Option<ComponentHook>,
+    on_replace: Option<ComponentHook>,
+    marker: PhantomData<fn(C, S)>,
+}
+
+/// A [`ComponentIndex`] that keeps its values sorted, supporting [range queries](ComponentIndex::range).
+pub type OrderedComponentIndex<C> = ComponentIndex<C, OrderedIndex<C>>;
+
+impl<C: Component, S: IndexStorage<C>> ComponentIndex<C, S> {
+    /// Returns an iterator over the entities whose `C` equals `value`.
+    pub fn get(&self, value: &C) -> IndexedEntities<'_> {
+        IndexedEntities(self.storage.get(value).map(EntityHashSet::iter))
+    }
+
+    /// Returns the only entity whose `C` equals `value`, or `None` if there are zero or
+    /// several such entities.
+    pub fn get_single(&self, value: &C) -> Option<Entity> {
+        let mut entities = self.get(value);
+        let entity = entities.next()?;
+        entities.next().is_none().then_some(*entity)
+    }
+
+    /// Returns `true` if any entity's `C` equals `value`.
+    pub fn contains(&self, value: &C) -> bool {
+        self.storage.get(value).is_some()
+    }
+
+    /// Returns the number of distinct values of `C`.
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Returns `true` if no entity has `C`.
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    fn new() -> Self {
+        Self {
+            storage: S::default(),
+            values: EntityHashMap::default(),
+        }
+    }
+
+    fn insert(&mut self, entity: Entity, value: C)
+    where
+        C: Clone,
+    {
+        self.storage.insert(value.clone(), entity);
+        self.values.insert(entity, value);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        if let Some(value) = self.values.remove(&entity) {
+            self.storage.remove(&value, entity);
+        }
+    }
+
+    /// Re-indexes the entities among `changed` whose `C` differs from their indexed value.
+    ///
+    /// Returns `true` if any entity was re-indexed.
+    fn update<'a>(&mut self, changed: impl IntoIterator<Item = (Entity, &'a C)>) -> bool
+    where
+        C: Clone + PartialEq,
+    {
+        let mut updated = false;
+        for (entity, value) in changed {
+            if self.values.get(&entity) != Some(value) {
+                self.remove(entity);
+                self.insert(entity, value.clone());
+                updated = true;
+            }
+        }
+        updated
+    }
+
+    fn on_insert(mut world: DeferredWorld, entity: Entity, component_id: ComponentId)
+    where
+        C: Clone,
+    {
+        if world.contains_resource::<Self>() {
+            let value = world.get::<C>(entity).unwrap().clone();
+            world.resource_mut::<Self>().insert(entity, value);
+        }
+        let hook = world
+            .get_resource::<ComponentIndexHooks<C, S>>()
+            .and_then(|hooks| hooks.on_insert);
+        if let Some(hook) = hook {
+            hook(world, entity, component_id);
+        }
+    }
+
+    fn on_replace(mut world: DeferredWorld, entity: Entity, component_id: ComponentId) {
+        if let Some(mut index) = world.get_resource_mut::<Self>() {
+            index.remove(entity);
+        }
+        let hook = world
+            .get_resource::<ComponentIndexHooks<C, S>>()
+            .and_then(|hooks| hooks.on_replace);
+        if let Some(hook) = hook {
+            hook(world, entity, component_id);
+        }
+    }
+}
+
+impl<C: Component + Clone + PartialEq, S: IndexStorage<C>> ComponentIndex<C, S> {
+    /// A system that re-indexes the entities whose `C` was mutated in place since it last ran.
+    ///
+    /// [`QueryByIndex`] does this on its own, so this is only needed before systems that read the
+    /// index directly.
+    pub fn update_system(mut index: ResMut<Self>, changed: Query<(Entity, &C), Changed<C>>) {
+        if index.bypass_change_detection().update(&changed) {
+            index.set_changed();
+        }
+    }
+}
+
+impl<C: Component + Ord> ComponentIndex<C, OrderedIndex<C>> {
+    /// Returns an iterator over the entities whose `C` lies within `range`, with their values in
+    /// ascending order.
+    pub fn range<R: RangeBounds<C>>(&self, range: R) -> IndexedRange<'_, C> {
+        IndexedRange {
+            values: self.storage.0.range(range),
+            current: None,
+        }
+    }
+}
+
+/// An iterator over the entities of a single value in a [`ComponentIndex`].
+///
+/// Created by [`ComponentIndex::get`].
+pub struct IndexedEntities<'a>(Option<hash_set::Iter<'a, Entity>>);
+
+impl<'a> Iterator for IndexedEntities<'a> {
+    type Item = &'a Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.as_mut()?.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.as_ref().map_or((0, Some(0)), Iterator::size_hint)
+    }
+}
+
+impl ExactSizeIterator for IndexedEntities<'_> {}
+
+/// An iterator over the entities of a range of values in an [`OrderedComponentIndex`].
+///
+/// Created by [`ComponentIndex::range`].
+pub struct IndexedRange<'a, C> {
+    values: btree_map::Range<'a, C, EntityHashSet>,
+    current: Option<(&'a C, hash_set::Iter<'a, Entity>)>,
+}
+
+impl<'a, C> Iterator for IndexedRange<'a, C> {
+    type Item = (&'a C, Entity);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((value, entities)) = &mut self.current {
+                if let Some(&entity) = entities.next() {
+                    return Some((value, entity));
+                }
+            }
+            let (value, entities) = self.values.next()?;
+            self.current = Some((value, entities.iter()));
+        }
+    }
+}
+
+/// A [`QueryFilter`] that restricts the query of a [`QueryByIndex`] to the entities whose `C`
+/// equals the value passed to [`QueryByIndex::at`].
+///
+/// Outside of [`QueryByIndex::at`], this filter matches every entity.
+pub struct WithIndexValue<C, S = HashIndex<C>>(PhantomData<fn(C, S)>);
+
+/// SAFETY:
+/// `update_component_access` does not add any accesses.
+/// `fetch` does not access any components, and only reads the [`ComponentIndex`] resource once a
+/// value was set by [`QueryByIndex`], which has access to that resource.
+/// `matches_component_set` always returns `true`.
+unsafe impl<C: Component, S: IndexStorage<C>> WorldQuery for WithIndexValue<C, S> {
+    type Item<'w> = ();
+    /// `None` if no value was set, otherwise the entities that have the value.
+    type Fetch<'w> = Option<Option<&'w EntityHashSet>>;
+    type State = Arc<Mutex<Option<C>>>;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(_: Self::Item<'wlong>) -> Self::Item<'wshort> {}
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &Self::State,
+        _last_run: Tick,
+        _this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        let value = state.lock().unwrap_or_else(PoisonError::into_inner);
+        value.as_ref().map(|value| {
+            // SAFETY: A value is only set by `QueryByIndex`, which has access to the index.
+            unsafe { world.get_resource::<ComponentIndex<C, S>>() }
+                .and_then(|index| index.storage.get(value))
+        })
+    }
+
+    const IS_DENSE: bool = true;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _state: &Self::State,
+        _archetype: &'w Archetype,
+        _table: &'w Table,
+    ) {
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(_fetch: &mut Self::Fetch<'w>, _state: &Self::State, _table: &'w Table) {
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> Self::Item<'w> {
+    }
+
+    fn update_component_access(_state: &Self::State, _access: &mut FilteredAccess<ComponentId>) {}
+
+    fn init_state(_world: &mut World) -> Self::State {
+        Arc::default()
+    }
+
+    fn get_state(_components: &Components) -> Option<Self::State> {
+        Some(Arc::default())
+    }
+
+    fn matches_component_set(
+        _state: &Self::State,
+        _set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        true
+    }
+}
+
+impl<C: Component, S: IndexStorage<C>> QueryFilter for WithIndexValue<C, S> {
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        _table_row: TableRow,
+    ) -> bool {
+        fetch.map_or(true, |entities| {
+            entities.is_some_and(|entities| entities.contains(&entity))
+        })
+    }
+}
+
+/// The filter of the [`Query`] of a [`QueryByIndex`].
+pub type IndexFilter<C, F, S> = (F, With<C>, WithIndexValue<C, S>);
+
+/// A [`Query`] whose results can be looked up by the value of an indexed component `C`.
+///
+/// [`lookup`](Self::lookup) resolves a value to entities through the index and only fetches those
+/// entities, while [`at`](Self::at) restricts the whole [`Query`] to a value with the
+/// [`WithIndexValue`] filter. Only entities with `C` are matched, in addition to the filter `F`.
+///
+/// Before the system runs, entities whose `C` was mutated in place are re-indexed. For this, the
+/// system param reads `C` for changed entities, so `D` can't access `C` mutably.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::index::QueryByIndex;
+/// #[derive(Component, Clone, PartialEq, Eq, Hash)]
+/// struct GridCell(i32, i32);
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// fn damage_cell(mut query: QueryByIndex<GridCell, &mut Health>) {
+///     let mut targets = query.lookup_mut(&GridCell(3, 4));
+///     while let Some(mut health) = targets.fetch_next() {
+///         health.0 = health.0.saturating_sub(10);
+///     }
+/// }
+///
+/// fn heal_cell(mut query: QueryByIndex<GridCell, &mut Health>) {
+///     for mut health in query.at(&GridCell(3, 4)).iter_mut() {
+///         health.0 += 5;
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.init_component_index::<GridCell>();
+/// let target = world.spawn((GridCell(3, 4), Health(100))).id();
+/// let bystander = world.spawn((GridCell(0, 0), Health(100))).id();
+///
+/// world.run_system_once(damage_cell);
+/// assert_eq!(world.get::<Health>(target).unwrap().0, 90);
+/// assert_eq!(world.get::<Health>(bystander).unwrap().0, 100);
+///
+/// world.run_system_once(heal_cell);
+/// assert_eq!(world.get::<Health>(target).unwrap().0, 95);
+/// assert_eq!(world.get::<Health>(bystander).unwrap().0, 100);
+/// # use bevy_ecs::system::RunSystemOnce;
+/// ```
+pub struct QueryByIndex<
+    'w,
+    's,
+    C: Component,
+    D: QueryData + 'static,
+    F: QueryFilter + 'static = (),
+    S: IndexStorage<C> = HashIndex<C>,
+> {
+    index: Res<'w, ComponentIndex<C, S>>,
+    query: Query<'w, 's, D, IndexFilter<C, F, S>>,
+    /// The value of the [`WithIndexValue`] filter of `query`.
+    value: &'s Mutex<Option<C>>,
+}
+
+#[doc(hidden)]
+pub struct QueryByIndexState<C, D, F, S>
+where
+    C: Component,
+    D: QueryData + 'static,
+    F: QueryFilter + 'static,
+    S: IndexStorage<C>,
+{
+    index: <ResMut<'static, ComponentIndex<C, S>> as SystemParam>::State,
+    changed: <Query<'static, 'static, (Entity, &'static C), Changed<C>> as SystemParam>::State,
+    query: <Query<'static, 'static, D, IndexFilter<C, F, S>> as SystemParam>::State,
+    value: Arc<Mutex<Option<C>>>,
+}
+
+// SAFETY: The accesses of the index, the changed entities and the query are registered by their
+// own `SystemParam` impls. The `WithIndexValue` filter of the query only reads the index.
+unsafe impl<C, D, F, S> SystemParam for QueryByIndex<'_, '_, C, D, F, S>
+where
+    C: Component + Clone + PartialEq,
+    D: QueryData + 'static,
+    F: QueryFilter + 'static,
+    S: IndexStorage<C>,
+{
+    type State = QueryByIndexState<C, D, F, S>;
+    type Item<'w, 's> = QueryByIndex<'w, 's, C, D, F, S>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        let index = ResMut::<ComponentIndex<C, S>>::init_state(world, system_meta);
+        let changed = Query::<(Entity, &C), Changed<C>>::init_state(world, system_meta);
+        let query = Query::<D, IndexFilter<C, F, S>>::init_state(world, system_meta);
+        let value = query.filter_state.2.clone();
+        QueryByIndexState {
+            index,
+            changed,
+            query,
+            value,
+        }
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        // SAFETY: Caller guarantees the archetype is from the world used in `init_state`
+        unsafe {
+            Query::<(Entity, &C), Changed<C>>::new_archetype(
+                &mut state.changed,
+                archetype,
+                system_meta,
+            );
+            Query::<D, IndexFilter<C, F, S>>::new_archetype(
+                &mut state.query,
+                archetype,
+                system_meta,
+            );
+        }
+    }
+
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        let QueryByIndexState {
+            index,
+            changed,
+            query,
+            value,
+        } = state;
+        // SAFETY: The caller upholds the safety requirements of `get_param`, which are the same
+        // for each of the fields.
+        let (mut index, changed, query) = unsafe {
+            (
+                ResMut::<ComponentIndex<C, S>>::get_param(index, system_meta, world, change_tick),
+                Query::<(Entity, &C), Changed<C>>::get_param(
+                    changed,
+                    system_meta,
+                    world,
+                    change_tick,
+                ),
+                Query::<D, IndexFilter<C, F, S>>::get_param(query, system_meta, world, change_tick),
+            )
+        };
+        if index.bypass_change_detection().update(&changed) {
+            index.set_changed();
+        }
+        QueryByIndex {
+            index: index.into(),
+            query,
+            value,
+        }
+    }
+}
+
+impl<'w, 's, C, D, F, S> QueryByIndex<'w, 's, C, D, F, S>
+where
+    C: Component + Clone,
+    D: QueryData + 'static,
+    F: QueryFilter + 'static,
+    S: IndexStorage<C>,
+{
+    /// Returns an iterator over the read-only query items of the entities whose `C` equals `value`.
+    pub fn lookup(
+        &self,
+        value: &C,
+    ) -> QueryManyIter<'_, 's, D::ReadOnly, IndexFilter<C, F, S>, IndexedEntities<'_>> {
+        self.set_value(None);
+        self.query.iter_many(self.index.get(value))
+    }
+
+    /// Returns an iterator over the query items of the entities whose `C` equals `value`.
+    ///
+    /// Items are fetched with [`QueryManyIter::fetch_next`].
+    pub fn lookup_mut(
+        &mut self,
+        value: &C,
+    ) -> QueryManyIter<'_, 's, D, IndexFilter<C, F, S>, IndexedEntities<'_>> {
+        self.set_value(None);
+        self.query.iter_many_mut(self.index.get(value))
+    }
+
+    /// Returns the read-only query item of the only entity whose `C` equals `value`, or `None`
+    /// if there isn't exactly one such entity or it doesn't match the query.
+    pub fn lookup_single(&self, value: &C) -> Option<ROQueryItem<'_, D>> {
+        self.set_value(None);
+        self.query.get(self.index.get_single(value)?).ok()
+    }
+
+    /// Returns the underlying [`Query`], restricted to the entities whose `C` equals `value`.
+    ///
+    /// Unlike [`lookup`](Self::lookup), this visits every entity with `C` when iterating, and
+    /// checks whether it has the value.
+    pub fn at(&mut self, value: &C) -> &mut Query<'w, 's, D, IndexFilter<C, F, S>> {
+        self.set_value(Some(value));
+        &mut self.query
+    }
+
+    /// Returns the underlying [`ComponentIndex`].
+    pub fn index(&self) -> &ComponentIndex<C, S> {
+        &self.index
+    }
+
+    /// Returns the underlying [`Query`].
+    pub fn query(&self) -> &Query<'w, 's, D, IndexFilter<C, F, S>> {
+        self.set_value(None);
+        &self.query
+    }
+
+    /// Returns the underlying [`Query`] mutably.
+    pub fn query_mut(&mut self) -> &mut Query<'w, 's, D, IndexFilter<C, F, S>> {
+        self.set_value(None);
+        &mut self.query
+    }
+
+    /// Sets the value the [`WithIndexValue`] filter of the query restricts it to.
+    ///
+    /// Callers that only have `&self` may only clear the value, which is never set while shared
+    /// borrows of the query exist since [`at`](Self::at) takes `&mut self`.
+    fn set_value(&self, value: Option<&C>) {
+        *self.value.lock().unwrap_or_else(PoisonError::into_inner) = value.cloned();
+    }
+}
+
+impl World {
+    /// Creates a [`ComponentIndex`] for `C`, which maps each value of `C` to the entities that
+    /// have it.
+    ///
+    /// The index is maintained by `on_insert` and `on_replace` [hooks](crate::component::ComponentHooks)
+    /// registered for `C`. Hooks that `C` already has are kept and run after the index is updated.
+    ///
+    /// If the index was created before and then removed, the hooks are reused and the new index
+    /// is filled from the entities that currently have `C`.
+    ///
+    /// # Panics
+    ///
+    /// The first time an index is created for `C`, panics if `C` already exists in an archetype,
+    /// so this should be called before any entity with `C` is spawned.
+    /// Panics if the index already exists.
+    pub fn init_component_index<C: Component + Clone + Hash + Eq>(&mut self) {
+        self.init_component_index_with_storage::<C, HashIndex<C>>();
+    }
+
+    /// Creates an [`OrderedComponentIndex`] for `C`, which keeps its values sorted and supports
+    /// [range queries](ComponentIndex::range).
+    ///
+    /// See [`World::init_component_index`] for details.
+    ///
+    /// # Panics
+    ///
+    /// The first time an index is created for `C`, panics if `C` already exists in an archetype.
+    /// Panics if the index already exists.
+    pub fn init_ordered_component_index<C: Component + Clone + Ord>(&mut self) {
+        self.init_component_index_with_storage::<C, OrderedIndex<C>>();
+    }
+
+    fn init_component_index_with_storage<C: Component + Clone, S: IndexStorage<C>>(&mut self) {
+        assert!(
+            !self.contains_resource::<ComponentIndex<C, S>>(),
+            "A component index for {} already exists",
+            std::any::type_name::<C>()
+        );
+        let mut index = ComponentIndex::<C, S>::new();
+        if self.contains_resource::<ComponentIndexHooks<C, S>>() {
+            // The hooks are already registered: replacing them again would make them call themselves.
+            let mut query = self.query::<(Entity, &C)>();
+            for (entity, value) in query.iter(self) {
+                index.insert(entity, value.clone());
+            }
+        } else {
+            let hooks = self.register_component_hooks::<C>();
+            let on_insert = hooks.on_insert.replace(ComponentIndex::<C, S>::on_insert);
+            let on_replace = hooks.on_replace.replace(ComponentIndex::<C, S>::on_replace);
+            self.insert_resource(ComponentIndexHooks::<C, S> {
+                on_insert,
+                on_replace,
+                marker: PhantomData,
+            });
+        }
+        self.insert_resource(index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        component::{ComponentHooks, StorageType},
+        index::{ComponentIndex, OrderedComponentIndex, QueryByIndex},
+        prelude::*,
+        system::RunSystemOnce,
+    };
+
+    #[derive(Component, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+    struct NetId(u32);
+
+    #[derive(Component)]
+    struct Player;
+
+    #[test]
+    fn index_tracks_insert_replace_and_remove() {
+        let mut world = World::new();
+        world.init_component_index::<NetId>();
+
+        let a = world.spawn(NetId(1)).id();
+        let b = world.spawn(NetId(2)).id();
+        let c = world.spawn(NetId(2)).id();
+
+        let index = world.resource::<ComponentIndex<NetId>>();
+        assert_eq!(index.get_single(&NetId(1)), Some(a));
+        assert_eq!(index.get(&NetId(2)).count(), 2);
+        assert_eq!(index.get_single(&NetId(2)), None);
+        assert_eq!(index.len(), 2);
+
+        world.entity_mut(b).insert(NetId(3));
+        let index = world.resource::<ComponentIndex<NetId>>();
+        assert_eq!(index.get_single(&NetId(2)), Some(c));
+        assert_eq!(index.get_single(&NetId(3)), Some(b));
+
+        world.entity_mut(a).remove::<NetId>();
+        world.despawn(c);
+        let index = world.resource::<ComponentIndex<NetId>>();
+        assert!(!index.contains(&NetId(1)));
+        assert!(!index.contains(&NetId(2)));
+        assert_eq!(index.len(), 1);
+    }
+
+    #[test]
+    fn ordered_index_range() {
+        let mut world = World::new();
+        world.init_ordered_component_index::<NetId>();
+
+        let entities = (0..10)
+            .map(|i| world.spawn(NetId(i)).id())
+            .collect::<Vec<_>>();
+
+        let index = world.resource::<OrderedComponentIndex<NetId>>();
+        let found = index.range(NetId(3)..NetId(6)).collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                (&NetId(3), entities[3]),
+                (&NetId(4), entities[4]),
+                (&NetId(5), entities[5]),
+            ]
+        );
+        assert_eq!(index.get_single(&NetId(9)), Some(entities[9]));
+    }
+
+    #[test]
+    fn query_by_index() {
+        let mut world = World::new();
+        world.init_component_index::<NetId>();
+
+        let player = world.spawn((NetId(17), Player)).id();
+        world.spawn(NetId(17));
+        world.spawn((NetId(18), Player));
+
+        world.run_system_once(move |query: QueryByIndex<NetId, Entity, With<Player>>| {
+            assert_eq!(query.lookup(&NetId(17)).collect::<Vec<_>>(), vec![player]);
+            assert_eq!(query.lookup(&NetId(19)).count(), 0);
+            assert_eq!(query.lookup_single(&NetId(17)), None);
+        });
+    }
+
+    #[test]
+    fn query_by_index_at() {
+        let mut world = World::new();
+        world.init_component_index::<NetId>();
+
+        let player = world.spawn((NetId(17), Player)).id();
+        let other = world.spawn((NetId(17), Player)).id();
+        world.spawn(NetId(17));
+        world.spawn((NetId(18), Player));
+
+        world.run_system_once(
+            move |mut query: QueryByIndex<NetId, Entity, With<Player>>| {
+                let mut found = query.at(&NetId(17)).iter().collect::<Vec<_>>();
+                found.sort();
+                assert_eq!(found, vec![player, other]);
+                assert_eq!(query.at(&NetId(19)).iter().count(), 0);
+                assert!(query.at(&NetId(17)).get(player).is_ok());
+
+                // The value only applies to the query returned by `at`.
+                assert_eq!(query.query().iter().count(), 3);
+                assert_eq!(query.lookup(&NetId(18)).count(), 1);
+            },
+        );
+    }
+
+    #[test]
+    fn index_tracks_in_place_mutation() {
+        let mut world = World::new();
+        world.init_component_index::<NetId>();
+
+        let a = world.spawn(NetId(1)).id();
+        let b = world.spawn(NetId(2)).id();
+
+        world.run_system_once(move |mut query: Query<&mut NetId>| {
+            query.get_mut(a).unwrap().0 = 3;
+        });
+        world.run_system_once(move |query: QueryByIndex<NetId, Entity>| {
+            assert_eq!(query.lookup(&NetId(1)).count(), 0);
+            assert_eq!(query.lookup(&NetId(3)).collect::<Vec<_>>(), vec![a]);
+            assert_eq!(query.lookup(&NetId(2)).collect::<Vec<_>>(), vec![b]);
+        });
+
+        world.run_system_once(move |mut query: Query<&mut NetId>| {
+            query.get_mut(b).unwrap().0 = 4;
+        });
+        world.run_system_once(ComponentIndex::<NetId>::update_system);
+        let index = world.resource::<ComponentIndex<NetId>>();
+        assert!(!index.contains(&NetId(2)));
+        assert_eq!(index.get_single(&NetId(4)), Some(b));
+
+        // Replacing a component mutated in place removes its indexed value, not its current one.
+        world.run_system_once(move |mut query: Query<&mut NetId>| {
+            query.get_mut(a).unwrap().0 = 5;
+        });
+        world.entity_mut(a).insert(NetId(6));
+        world.run_system_once(ComponentIndex::<NetId>::update_system);
+        let index = world.resource::<ComponentIndex<NetId>>();
+        assert!(!index.contains(&NetId(3)));
+        assert!(!index.contains(&NetId(5)));
+        assert_eq!(index.get_single(&NetId(6)), Some(a));
+    }
+
+    #[test]
+    #[should_panic]
+    fn query_by_index_cannot_mutate_indexed_component() {
+        let mut world = World::new();
+        world.init_component_index::<NetId>();
+        world.run_system_once(|_: QueryByIndex<NetId, &mut NetId>| {});
+    }
+
+    #[test]
+    fn index_keeps_existing_hooks() {
+        #[derive(Clone, PartialEq, Eq, Hash)]
+        struct Hooked(u32);
+
+        #[derive(Resource, Default)]
+        struct Inserted(u32);
+
+        impl Component for Hooked {
+            const STORAGE_TYPE: StorageType = StorageType::Table;
+
+            fn register_component_hooks(hooks: &mut ComponentHooks) {
+                hooks.on_insert(|mut world, _, _| world.resource_mut::<Inserted>().0 += 1);
+            }
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Inserted>();
+        world.init_component_index::<Hooked>();
+
+        let entity = world.spawn(Hooked(1)).id();
+        assert_eq!(world.resource::<Inserted>().0, 1);
+        assert_eq!(
+            world
+                .resource::<ComponentIndex<Hooked>>()
+                .get_single(&Hooked(1)),
+            Some(entity)
+        );
+    }
+
+    #[test]
+    fn index_can_be_recreated() {
+        #[derive(Clone, PartialEq, Eq, Hash)]
+        struct Hooked(u32);
+
+        #[derive(Resource, Default)]
+        struct Inserted(u32);
+
+        impl Component for Hooked {
+            const STORAGE_TYPE: StorageType = StorageType::Table;
+
+            fn register_component_hooks(hooks: &mut ComponentHooks) {
+                hooks.on_insert(|mut world, _, _| world.resource_mut::<Inserted>().0 += 1);
+            }
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Inserted>();
+        world.init_component_index::<Hooked>();
+        let a = world.spawn(Hooked(1)).id();
+
+        world.remove_resource::<ComponentIndex<Hooked>>();
+        let b = world.spawn(Hooked(2)).id();
+        assert_eq!(world.resource::<Inserted>().0, 2);
+
+        world.init_component_index::<Hooked>();
+        let c = world.spawn(Hooked(3)).id();
+        assert_eq!(world.resource::<Inserted>().0, 3);
+
+        let index = world.resource::<ComponentIndex<Hooked>>();
+        assert_eq!(index.get_single(&Hooked(1)), Some(a));
+        assert_eq!(index.get_single(&Hooked(2)), Some(b));
+        assert_eq!(index.get_single(&Hooked(3)), Some(c));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_must_be_created_before_spawning() {
+        let mut world = World::new();
+        world.spawn(NetId(1));
+        world.init_component_index::<NetId>();
+    }
+}
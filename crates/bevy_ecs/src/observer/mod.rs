@@ -9,27 +9,29 @@ pub use trigger_event::*;
 
 use crate::observer::entity_observer::ObservedBy;
 use crate::{archetype::ArchetypeFlags, system::IntoObserverSystem, world::*};
-use crate::{component::ComponentId, prelude::*, world::DeferredWorld};
+use crate::{
+    component::ComponentId, prelude::*, schedule::InternedSystemSet, world::DeferredWorld,
+};
 use bevy_ptr::Ptr;
-use bevy_utils::{EntityHashMap, HashMap};
-use std::marker::PhantomData;
+use bevy_utils::{tracing::warn, EntityHashMap, HashMap};
+use std::{cmp::Reverse, marker::PhantomData};
 
 /// Type containing triggered [`Event`] information for a given run of an [`Observer`]. This contains the
 /// [`Event`] data itself. If it was triggered for a specific [`Entity`], it includes that as well. It also
 /// contains event propagation information. See [`Trigger::propagate`] for more information.
 pub struct Trigger<'w, E, B: Bundle = ()> {
     event: &'w mut E,
-    propagate: &'w mut bool,
+    state: &'w mut TriggerState,
     trigger: ObserverTrigger,
     _marker: PhantomData<B>,
 }
 
 impl<'w, E, B: Bundle> Trigger<'w, E, B> {
     /// Creates a new trigger for the given event and observer information.
-    pub fn new(event: &'w mut E, propagate: &'w mut bool, trigger: ObserverTrigger) -> Self {
+    pub fn new(event: &'w mut E, state: &'w mut TriggerState, trigger: ObserverTrigger) -> Self {
         Self {
             event,
-            propagate,
+            state,
             trigger,
             _marker: PhantomData,
         }
@@ -60,6 +62,11 @@ impl<'w, E, B: Bundle> Trigger<'w, E, B> {
         self.trigger.entity
     }
 
+    /// Returns the [`ObserverPhase`] the observer runs in.
+    pub fn phase(&self) -> ObserverPhase {
+        self.trigger.phase
+    }
+
     /// Enables or disables event propagation, allowing the same event to trigger observers on a chain of different entities.
     ///
     /// The path an event will propagate along is specified by its associated [`Traversal`] component. By default, events
@@ -70,18 +77,58 @@ impl<'w, E, B: Bundle> Trigger<'w, E, B> {
     /// + Either call `propagate(true)` in the first observer or set [`Event::AUTO_PROPAGATE`] to `true`.
     ///
     /// You can prevent an event from propagating further using `propagate(false)`.
+    /// Calling `propagate(false)` in an [`ObserverPhase::Capture`] observer stops the event entirely:
+    /// the remaining capture observers and all bubbling observers are skipped.
     ///
     /// [`Traversal`]: crate::traversal::Traversal
     pub fn propagate(&mut self, should_propagate: bool) {
-        *self.propagate = should_propagate;
+        self.state.propagate = should_propagate;
     }
 
     /// Returns the value of the flag that controls event propagation. See [`propagate`] for more information.
     ///
     /// [`propagate`]: Trigger::propagate
     pub fn get_propagate(&self) -> bool {
-        *self.propagate
+        self.state.propagate
+    }
+
+    /// Prevents the [`Event::default_action`] from running once the event has finished propagating.
+    pub fn prevent_default(&mut self) {
+        self.state.default_prevented = true;
     }
+
+    /// Returns `true` if an observer called [`prevent_default`](Trigger::prevent_default) for this event.
+    pub fn is_default_prevented(&self) -> bool {
+        self.state.default_prevented
+    }
+}
+
+/// The state shared by the observers run by a single trigger of an [`Event`] for a target.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerState {
+    /// Whether the event propagates further. See [`Trigger::propagate`].
+    pub propagate: bool,
+    /// Whether the [`Event::default_action`] is prevented. See [`Trigger::prevent_default`].
+    pub default_prevented: bool,
+}
+
+/// The phase of event propagation an [`Observer`] runs in.
+///
+/// When an [`Event`] is triggered for an entity, [`Capture`](ObserverPhase::Capture) observers run first,
+/// from the root of the event's [`Traversal`](crate::traversal::Traversal) path down to the target entity.
+/// [`Bubble`](ObserverPhase::Bubble) observers then run from the target entity up the path, as long as the event
+/// [propagates](Trigger::propagate). Finally, the [`Event::default_action`] runs unless it was
+/// [prevented](Trigger::prevent_default).
+///
+/// The capture phase only applies to events triggered with [`World::trigger`], [`World::trigger_targets`] or
+/// their [`Commands`] equivalents, not to component lifecycle events such as [`OnAdd`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObserverPhase {
+    /// Runs from the root of the propagation path down to the target, before any [`Bubble`](ObserverPhase::Bubble) observer.
+    Capture,
+    /// Runs from the target up the propagation path. This is the default.
+    #[default]
+    Bubble,
 }
 
 /// A description of what an [`Observer`] observes.
@@ -95,6 +142,9 @@ pub struct ObserverDescriptor {
 
     /// The entities the observer is watching.
     entities: Vec<Entity>,
+
+    /// The propagation phase the observer runs in.
+    phase: ObserverPhase,
 }
 
 impl ObserverDescriptor {
@@ -119,14 +169,99 @@ impl ObserverDescriptor {
         self
     }
 
+    /// Set the propagation `phase` the observer runs in.
+    pub fn with_phase(mut self, phase: ObserverPhase) -> Self {
+        self.phase = phase;
+        self
+    }
+
     pub(crate) fn merge(&mut self, descriptor: &ObserverDescriptor) {
         self.events.extend(descriptor.events.iter().copied());
         self.components
             .extend(descriptor.components.iter().copied());
         self.entities.extend(descriptor.entities.iter().copied());
+        if descriptor.phase == ObserverPhase::Capture {
+            self.phase = ObserverPhase::Capture;
+        }
     }
 }
 
+/// Determines when an [`Observer`] runs relative to the other observers of the same trigger.
+///
+/// Observers run in descending [`priority`](Observer::with_priority) order, and observers with the same
+/// priority run in an unspecified order. [`before`](Observer::before) and [`after`](Observer::after)
+/// constraints on the [`SystemSet`]s of other observers take precedence over priorities.
+#[derive(Default, Clone, Debug)]
+pub struct ObserverOrder {
+    /// Observers with a higher priority run first.
+    pub(crate) priority: i32,
+    /// The sets the observer belongs to, including the default sets of its system.
+    pub(crate) sets: Vec<InternedSystemSet>,
+    /// Observers in these sets run after this observer.
+    pub(crate) before: Vec<InternedSystemSet>,
+    /// Observers in these sets run before this observer.
+    pub(crate) after: Vec<InternedSystemSet>,
+}
+
+impl ObserverOrder {
+    /// Returns `true` if the observer does not run in the default order.
+    fn is_ordered(&self) -> bool {
+        self.priority != 0 || !self.before.is_empty() || !self.after.is_empty()
+    }
+
+    /// Returns `true` if the observer must run before the `other` observer.
+    fn runs_before(&self, other: &ObserverOrder) -> bool {
+        self.before.iter().any(|set| other.sets.contains(set))
+            || other.after.iter().any(|set| self.sets.contains(set))
+    }
+}
+
+/// Sorts the `observers` of a trigger according to their [`ObserverOrder`].
+fn sort_observers(observers: &mut Vec<Entity>, orders: &EntityHashMap<Entity, ObserverOrder>) {
+    let default_order = ObserverOrder::default();
+    let order = |observer: &Entity| orders.get(observer).unwrap_or(&default_order);
+    observers.sort_by_key(|observer| Reverse(order(observer).priority));
+
+    if !observers.iter().any(|observer| {
+        let order = order(observer);
+        !order.before.is_empty() || !order.after.is_empty()
+    }) {
+        return;
+    }
+
+    // Topologically sort the observers, picking the ready observer with the highest priority first.
+    let len = observers.len();
+    let mut predecessors = vec![0usize; len];
+    let mut successors = vec![Vec::new(); len];
+    for (i, a) in observers.iter().enumerate() {
+        for (j, b) in observers.iter().enumerate() {
+            if i != j && order(a).runs_before(order(b)) {
+                successors[i].push(j);
+                predecessors[j] += 1;
+            }
+        }
+    }
+    let mut done = vec![false; len];
+    let mut sorted = Vec::with_capacity(len);
+    while sorted.len() < len {
+        let next = match (0..len).find(|&i| !done[i] && predecessors[i] == 0) {
+            Some(next) => next,
+            None => {
+                warn!(
+                    "Observers have cyclic ordering constraints, falling back to their priorities."
+                );
+                (0..len).find(|&i| !done[i]).unwrap()
+            }
+        };
+        done[next] = true;
+        for &successor in &successors[next] {
+            predecessors[successor] = predecessors[successor].saturating_sub(1);
+        }
+        sorted.push(observers[next]);
+    }
+    *observers = sorted;
+}
+
 /// Event trigger metadata for a given [`Observer`],
 #[derive(Debug)]
 pub struct ObserverTrigger {
@@ -138,6 +273,9 @@ pub struct ObserverTrigger {
 
     /// The entity the trigger targeted.
     pub entity: Entity,
+
+    /// The propagation phase the observer runs in.
+    pub phase: ObserverPhase,
 }
 
 // Map between an observer entity and its runner
@@ -161,6 +299,50 @@ pub struct CachedObservers {
     component_observers: HashMap<ComponentId, CachedComponentObservers>,
     // Observers listening for this trigger fired at a specific entity
     entity_observers: EntityHashMap<Entity, ObserverMap>,
+    // Position of each observer in the run order, empty if all observers run in the default order
+    run_order: EntityHashMap<Entity, usize>,
+}
+
+impl CachedObservers {
+    fn is_empty(&self) -> bool {
+        self.map.is_empty()
+            && self.component_observers.is_empty()
+            && self.entity_observers.is_empty()
+    }
+
+    /// Recomputes the run order of the observers, called when an observer is added or removed.
+    fn update_run_order(&mut self, orders: &EntityHashMap<Entity, ObserverOrder>) {
+        self.run_order.clear();
+
+        let mut observers: Vec<Entity> = self
+            .map
+            .keys()
+            .chain(self.entity_observers.values().flat_map(ObserverMap::keys))
+            .chain(self.component_observers.values().flat_map(|observers| {
+                observers
+                    .map
+                    .keys()
+                    .chain(observers.entity_map.values().flat_map(ObserverMap::keys))
+            }))
+            .copied()
+            .collect();
+        if !observers
+            .iter()
+            .any(|observer| orders.get(observer).is_some_and(ObserverOrder::is_ordered))
+        {
+            return;
+        }
+
+        observers.sort_unstable();
+        observers.dedup();
+        sort_observers(&mut observers, orders);
+        self.run_order.extend(
+            observers
+                .into_iter()
+                .enumerate()
+                .map(|(i, observer)| (observer, i)),
+        );
+    }
 }
 
 /// Metadata for observers. Stores a cache mapping trigger ids to the registered observers.
@@ -173,10 +355,21 @@ pub struct Observers {
     on_remove: CachedObservers,
     // Map from trigger type to set of observers
     cache: HashMap<ComponentId, CachedObservers>,
+    // Map from trigger type to set of capture phase observers
+    capture: HashMap<ComponentId, CachedObservers>,
+    // Ordering of each registered observer
+    orders: EntityHashMap<Entity, ObserverOrder>,
 }
 
 impl Observers {
-    pub(crate) fn get_observers(&mut self, event_type: ComponentId) -> &mut CachedObservers {
+    pub(crate) fn get_observers(
+        &mut self,
+        event_type: ComponentId,
+        phase: ObserverPhase,
+    ) -> &mut CachedObservers {
+        if phase == ObserverPhase::Capture {
+            return self.capture.entry(event_type).or_default();
+        }
         match event_type {
             ON_ADD => &mut self.on_add,
             ON_INSERT => &mut self.on_insert,
@@ -186,7 +379,14 @@ impl Observers {
         }
     }
 
-    pub(crate) fn try_get_observers(&self, event_type: ComponentId) -> Option<&CachedObservers> {
+    pub(crate) fn try_get_observers(
+        &self,
+        event_type: ComponentId,
+        phase: ObserverPhase,
+    ) -> Option<&CachedObservers> {
+        if phase == ObserverPhase::Capture {
+            return self.capture.get(&event_type);
+        }
         match event_type {
             ON_ADD => Some(&self.on_add),
             ON_INSERT => Some(&self.on_insert),
@@ -196,65 +396,101 @@ impl Observers {
         }
     }
 
-    /// This will run the observers of the given `event_type`, targeting the given `entity` and `components`.
+    /// Recomputes the run order of the observers of the given `event_type` and `phase`.
+    fn update_run_order(&mut self, event_type: ComponentId, phase: ObserverPhase) {
+        let observers = if phase == ObserverPhase::Capture {
+            self.capture.get_mut(&event_type)
+        } else {
+            match event_type {
+                ON_ADD => Some(&mut self.on_add),
+                ON_INSERT => Some(&mut self.on_insert),
+                ON_REPLACE => Some(&mut self.on_replace),
+                ON_REMOVE => Some(&mut self.on_remove),
+                _ => self.cache.get_mut(&event_type),
+            }
+        };
+        if let Some(observers) = observers {
+            observers.update_run_order(&self.orders);
+        }
+    }
+
+    /// Returns `true` if capture phase observers are registered for the given `event_type`.
+    pub(crate) fn has_capture_observers(&self, event_type: ComponentId) -> bool {
+        self.capture.contains_key(&event_type)
+    }
+
+    /// This will run the observers of the given `event_type` and `phase`, targeting the given `entity` and `components`.
     pub(crate) fn invoke<T>(
         mut world: DeferredWorld,
         event_type: ComponentId,
         entity: Entity,
         components: impl Iterator<Item = ComponentId>,
         data: &mut T,
-        propagate: &mut bool,
+        phase: ObserverPhase,
+        state: &mut TriggerState,
     ) {
         // SAFETY: You cannot get a mutable reference to `observers` from `DeferredWorld`
         let (mut world, observers) = unsafe {
             let world = world.as_unsafe_world_cell();
             // SAFETY: There are no outstanding world references
             world.increment_trigger_id();
-            let observers = world.observers();
-            let Some(observers) = observers.try_get_observers(event_type) else {
+            let all_observers = world.observers();
+            let Some(observers) = all_observers.try_get_observers(event_type, phase) else {
                 return;
             };
             // SAFETY: The only outstanding reference to world is `observers`
             (world.into_deferred(), observers)
         };
 
-        let mut trigger_observer = |(&observer, runner): (&Entity, &ObserverRunner)| {
+        let mut trigger_observer = |(observer, runner): (Entity, ObserverRunner)| {
             (runner)(
                 world.reborrow(),
                 ObserverTrigger {
                     observer,
                     event_type,
                     entity,
+                    phase,
                 },
                 data.into(),
-                propagate,
+                state,
             );
         };
-        // Trigger observers listening for any kind of this trigger
-        observers.map.iter().for_each(&mut trigger_observer);
 
-        // Trigger entity observers listening for this kind of trigger
-        if entity != Entity::PLACEHOLDER {
-            if let Some(map) = observers.entity_observers.get(&entity) {
-                map.iter().for_each(&mut trigger_observer);
-            }
+        // Observers listening for any kind of this trigger
+        let matched = observers
+            .map
+            .iter()
+            // Entity observers listening for this kind of trigger
+            .chain(
+                (entity != Entity::PLACEHOLDER)
+                    .then(|| observers.entity_observers.get(&entity))
+                    .flatten()
+                    .into_iter()
+                    .flatten(),
+            )
+            // Observers listening to this trigger targeting a specific component
+            .chain(
+                components
+                    .filter_map(|id| observers.component_observers.get(&id))
+                    .flat_map(|component_observers| {
+                        component_observers.map.iter().chain(
+                            (entity != Entity::PLACEHOLDER)
+                                .then(|| component_observers.entity_map.get(&entity))
+                                .flatten()
+                                .into_iter()
+                                .flatten(),
+                        )
+                    }),
+            )
+            .map(|(&observer, &runner)| (observer, runner));
+
+        if observers.run_order.is_empty() {
+            matched.for_each(&mut trigger_observer);
+        } else {
+            let mut matched: Vec<_> = matched.collect();
+            matched.sort_by_key(|(observer, _)| observers.run_order[observer]);
+            matched.into_iter().for_each(&mut trigger_observer);
         }
-
-        // Trigger observers listening to this trigger targeting a specific component
-        components.for_each(|id| {
-            if let Some(component_observers) = observers.component_observers.get(&id) {
-                component_observers
-                    .map
-                    .iter()
-                    .for_each(&mut trigger_observer);
-
-                if entity != Entity::PLACEHOLDER {
-                    if let Some(map) = component_observers.entity_map.get(&entity) {
-                        map.iter().for_each(&mut trigger_observer);
-                    }
-                }
-            }
-        });
     }
 
     pub(crate) fn is_archetype_cached(event_type: ComponentId) -> Option<ArchetypeFlags> {
@@ -337,8 +573,12 @@ impl World {
         };
         let descriptor = &observer_state.descriptor;
 
+        observers
+            .orders
+            .insert(observer_entity, observer_state.order.clone());
+
         for &event_type in &descriptor.events {
-            let cache = observers.get_observers(event_type);
+            let cache = observers.get_observers(event_type, descriptor.phase);
 
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache.map.insert(observer_entity, observer_state.runner);
@@ -356,8 +596,10 @@ impl World {
                             .component_observers
                             .entry(component)
                             .or_insert_with(|| {
-                                if let Some(flag) = Observers::is_archetype_cached(event_type) {
-                                    archetypes.update_flags(component, flag, true);
+                                if descriptor.phase == ObserverPhase::Bubble {
+                                    if let Some(flag) = Observers::is_archetype_cached(event_type) {
+                                        archetypes.update_flags(component, flag, true);
+                                    }
                                 }
                                 CachedComponentObservers::default()
                             });
@@ -373,6 +615,7 @@ impl World {
                     }
                 }
             }
+            observers.update_run_order(event_type, descriptor.phase);
         }
     }
 
@@ -381,8 +624,10 @@ impl World {
         let archetypes = &mut self.archetypes;
         let observers = &mut self.observers;
 
+        observers.orders.remove(&entity);
+
         for &event_type in &descriptor.events {
-            let cache = observers.get_observers(event_type);
+            let cache = observers.get_observers(event_type, descriptor.phase);
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache.map.remove(&entity);
             } else if descriptor.components.is_empty() {
@@ -417,12 +662,19 @@ impl World {
 
                     if observers.map.is_empty() && observers.entity_map.is_empty() {
                         cache.component_observers.remove(component);
-                        if let Some(flag) = Observers::is_archetype_cached(event_type) {
-                            archetypes.update_flags(*component, flag, false);
+                        if descriptor.phase == ObserverPhase::Bubble {
+                            if let Some(flag) = Observers::is_archetype_cached(event_type) {
+                                archetypes.update_flags(*component, flag, false);
+                            }
                         }
                     }
                 }
             }
+
+            if descriptor.phase == ObserverPhase::Capture && cache.is_empty() {
+                observers.capture.remove(&event_type);
+            }
+            observers.update_run_order(event_type, descriptor.phase);
         }
     }
 }
@@ -433,10 +685,11 @@ mod tests {
 
     use crate as bevy_ecs;
     use crate::observer::{
-        EmitDynamicTrigger, Observer, ObserverDescriptor, ObserverState, OnReplace,
+        EmitDynamicTrigger, Observer, ObserverDescriptor, ObserverPhase, ObserverState, OnReplace,
     };
     use crate::prelude::*;
     use crate::traversal::Traversal;
+    use crate::world::DeferredWorld;
 
     #[derive(Component)]
     struct A;
@@ -483,6 +736,19 @@ mod tests {
         const AUTO_PROPAGATE: bool = true;
     }
 
+    #[derive(Component)]
+    struct EventWithDefault;
+
+    impl Event for EventWithDefault {
+        type Traversal = Parent;
+
+        const AUTO_PROPAGATE: bool = true;
+
+        fn default_action(mut world: DeferredWorld, _: &mut Self, _: Entity) {
+            world.resource_mut::<R>().0 += 10;
+        }
+    }
+
     #[test]
     fn observer_order_spawn_despawn() {
         let mut world = World::new();
@@ -552,6 +818,124 @@ mod tests {
         assert_eq!(2, world.resource::<R>().0);
     }
 
+    #[test]
+    fn observer_order_priority() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(2))
+                .with_priority(-1),
+        );
+        world.spawn(Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| {
+            res.assert_order(1);
+        }));
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(0))
+                .with_priority(10),
+        );
+        world.flush();
+
+        world.trigger(EventA);
+        assert_eq!(3, world.resource::<R>().0);
+    }
+
+    #[test]
+    fn observer_order_updates_on_add_and_remove() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+
+        let first = world
+            .spawn(
+                Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(0))
+                    .with_priority(10),
+            )
+            .id();
+        world.spawn(Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| {
+            res.assert_order(1);
+        }));
+        world.flush();
+
+        world.trigger(EventA);
+        assert_eq!(2, world.resource::<R>().0);
+
+        world.despawn(first);
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(2))
+                .with_priority(-1),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(0))
+                .with_priority(5),
+        );
+        world.flush();
+
+        world.resource_mut::<R>().0 = 0;
+        world.trigger(EventA);
+        assert_eq!(3, world.resource::<R>().0);
+    }
+
+    #[test]
+    fn observer_order_before_after() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Validation;
+
+        fn validate(_: Trigger<EventA>, mut res: ResMut<R>) {
+            res.assert_order(1);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<R>();
+        let entity = world.spawn_empty().id();
+
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(2))
+                .after(Validation)
+                .with_priority(10),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(3))
+                .with_entity(entity)
+                .after(validate),
+        );
+        world.spawn(
+            Observer::new(validate)
+                .in_set(Validation)
+                .with_entity(entity),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(0))
+                .before(Validation),
+        );
+        world.flush();
+
+        world.trigger_targets(EventA, entity);
+        assert_eq!(4, world.resource::<R>().0);
+    }
+
+    #[test]
+    fn observer_run_if() {
+        #[derive(Resource)]
+        struct Enabled(bool);
+
+        let mut world = World::new();
+        world.init_resource::<R>();
+        world.insert_resource(Enabled(false));
+
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.0 += 1)
+                .run_if(|enabled: Res<Enabled>| enabled.0),
+        );
+        world.flush();
+
+        world.trigger(EventA);
+        assert_eq!(0, world.resource::<R>().0);
+
+        world.resource_mut::<Enabled>().0 = true;
+        world.trigger(EventA);
+        assert_eq!(1, world.resource::<R>().0);
+    }
+
     #[test]
     fn observer_order_recursive() {
         let mut world = World::new();
@@ -771,6 +1155,91 @@ mod tests {
         assert_eq!(2, world.resource::<R>().0);
     }
 
+    #[test]
+    fn observer_propagating_capture() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+
+        let parent = world.spawn_empty().id();
+        let child = world.spawn(Parent(parent)).id();
+
+        world.spawn(
+            Observer::new(|_: Trigger<EventPropagating>, mut res: ResMut<R>| res.assert_order(3))
+                .with_entity(parent),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<EventPropagating>, mut res: ResMut<R>| res.assert_order(2))
+                .with_entity(child),
+        );
+        world.spawn(
+            Observer::new(|trigger: Trigger<EventPropagating>, mut res: ResMut<R>| {
+                assert_eq!(trigger.phase(), ObserverPhase::Capture);
+                res.assert_order(1);
+            })
+            .with_entity(child)
+            .with_phase(ObserverPhase::Capture),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<EventPropagating>, mut res: ResMut<R>| res.assert_order(0))
+                .with_entity(parent)
+                .with_phase(ObserverPhase::Capture),
+        );
+        world.flush();
+
+        world.trigger_targets(EventPropagating, child);
+        world.flush();
+        assert_eq!(4, world.resource::<R>().0);
+    }
+
+    #[test]
+    fn observer_propagating_capture_halt() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+
+        let parent = world.spawn_empty().id();
+        let child = world
+            .spawn(Parent(parent))
+            .observe(|_: Trigger<EventPropagating>, mut res: ResMut<R>| res.0 += 1)
+            .id();
+
+        world.spawn(
+            Observer::new(|mut trigger: Trigger<EventPropagating>| trigger.propagate(false))
+                .with_entity(parent)
+                .with_phase(ObserverPhase::Capture),
+        );
+        world.flush();
+
+        world.trigger_targets(EventPropagating, child);
+        world.flush();
+        assert_eq!(0, world.resource::<R>().0);
+    }
+
+    #[test]
+    fn observer_default_action() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+
+        let parent = world.spawn_empty().id();
+        let child = world
+            .spawn(Parent(parent))
+            .observe(|_: Trigger<EventWithDefault>, mut res: ResMut<R>| res.0 += 1)
+            .id();
+        world.flush();
+
+        world.trigger_targets(EventWithDefault, child);
+        world.flush();
+        assert_eq!(11, world.resource::<R>().0);
+
+        world
+            .entity_mut(parent)
+            .observe(|mut trigger: Trigger<EventWithDefault>| trigger.prevent_default());
+        world.flush();
+
+        world.trigger_targets(EventWithDefault, child);
+        world.flush();
+        assert_eq!(12, world.resource::<R>().0);
+    }
+
     #[test]
     fn observer_propagating_redundant_dispatch_same_entity() {
         let mut world = World::new();
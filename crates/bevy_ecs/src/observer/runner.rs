@@ -1,8 +1,9 @@
 use crate::{
     component::{ComponentHooks, ComponentId, StorageType},
-    observer::{ObserverDescriptor, ObserverTrigger},
+    observer::{ObserverDescriptor, ObserverOrder, ObserverPhase, ObserverTrigger, TriggerState},
     prelude::*,
     query::DebugCheckedUnwrap,
+    schedule::{new_condition, BoxedCondition, IntoSystemSet},
     system::{IntoObserverSystem, ObserverSystem},
     world::DeferredWorld,
 };
@@ -12,6 +13,7 @@ use bevy_ptr::PtrMut;
 /// "source of truth" for a given observer entity's behavior.
 pub struct ObserverState {
     pub(crate) descriptor: ObserverDescriptor,
+    pub(crate) order: ObserverOrder,
     pub(crate) runner: ObserverRunner,
     pub(crate) last_trigger_id: u32,
     pub(crate) despawned_watched_entities: u32,
@@ -24,6 +26,7 @@ impl Default for ObserverState {
             last_trigger_id: 0,
             despawned_watched_entities: 0,
             descriptor: Default::default(),
+            order: Default::default(),
         }
     }
 }
@@ -86,7 +89,7 @@ impl Component for ObserverState {
 /// Type for function that is run when an observer is triggered.
 /// Typically refers to the default runner that runs the system stored in the associated [`Observer`] component,
 /// but can be overridden for custom behaviour.
-pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, propagate: &mut bool);
+pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, state: &mut TriggerState);
 
 /// An [`Observer`] system. Add this [`Component`] to an [`Entity`] to turn it into an "observer".
 ///
@@ -256,6 +259,30 @@ pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, propagate:
 ///
 /// You can call [`Observer::watch_entity`] more than once, which allows you to watch multiple entities with the same [`Observer`].
 ///
+/// When several observers watch the same trigger, they run in an unspecified order by default.
+/// Use [`Observer::with_priority`], [`Observer::before`] and [`Observer::after`] to order them,
+/// and [`Observer::run_if`] to only run an observer when its run conditions are met:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # let mut world = World::default();
+/// # #[derive(Event)]
+/// # struct Damage(u32);
+/// # #[derive(Resource)]
+/// # struct GodMode(bool);
+/// # world.insert_resource(GodMode(false));
+/// fn validate_damage(trigger: Trigger<Damage>) {}
+///
+/// fn apply_damage(trigger: Trigger<Damage>) {}
+///
+/// world.spawn(Observer::new(validate_damage));
+/// world.spawn(
+///     Observer::new(apply_damage)
+///         .after(validate_damage)
+///         .run_if(|god_mode: Res<GodMode>| !god_mode.0),
+/// );
+/// ```
+///
 /// When first added, [`Observer`] will also create an [`ObserverState`] component, which registers the observer with the [`World`] and
 /// serves as the "source of truth" of the observer.
 ///
@@ -263,6 +290,8 @@ pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, propagate:
 pub struct Observer<T: 'static, B: Bundle> {
     system: BoxedObserverSystem<T, B>,
     descriptor: ObserverDescriptor,
+    order: ObserverOrder,
+    conditions: Vec<BoxedCondition>,
 }
 
 impl<E: Event, B: Bundle> Observer<E, B> {
@@ -272,6 +301,8 @@ impl<E: Event, B: Bundle> Observer<E, B> {
         Self {
             system: Box::new(IntoObserverSystem::into_system(system)),
             descriptor: Default::default(),
+            order: Default::default(),
+            conditions: Vec::new(),
         }
     }
 
@@ -305,6 +336,49 @@ impl<E: Event, B: Bundle> Observer<E, B> {
         self.descriptor.events.push(event);
         self
     }
+
+    /// Runs the [`Observer`] in the given propagation `phase`, see [`ObserverPhase`].
+    pub fn with_phase(mut self, phase: ObserverPhase) -> Self {
+        self.descriptor.phase = phase;
+        self
+    }
+
+    /// Sets the priority of the [`Observer`]. Observers of the same trigger with a higher priority run first.
+    ///
+    /// Defaults to `0`.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.order.priority = priority;
+        self
+    }
+
+    /// Adds the [`Observer`] to the given `set`, which other observers can be ordered against.
+    ///
+    /// Observers are always part of the default sets of their system, so they can be ordered against
+    /// the function they were created from.
+    pub fn in_set(mut self, set: impl SystemSet) -> Self {
+        self.order.sets.push(set.intern());
+        self
+    }
+
+    /// Runs the [`Observer`] before the observers in `set`, when both watch the same trigger.
+    pub fn before<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.order.before.push(set.into_system_set().intern());
+        self
+    }
+
+    /// Runs the [`Observer`] after the observers in `set`, when both watch the same trigger.
+    pub fn after<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.order.after.push(set.into_system_set().intern());
+        self
+    }
+
+    /// Only runs the [`Observer`] if the given `condition` is `true` when it is triggered.
+    ///
+    /// Like for scheduled systems, every run condition is evaluated, even if a previous one returned `false`.
+    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
+        self.conditions.push(new_condition(condition));
+        self
+    }
 }
 
 impl<E: Event, B: Bundle> Component for Observer<E, B> {
@@ -324,23 +398,29 @@ impl<E: Event, B: Bundle> Component for Observer<E, B> {
                 };
 
                 // Initialize System
-                let system: *mut dyn ObserverSystem<E, B> =
-                    if let Some(mut observe) = world.get_mut::<Self>(entity) {
-                        descriptor.merge(&observe.descriptor);
-                        &mut *observe.system
-                    } else {
-                        return;
-                    };
-                // SAFETY: World reference is exclusive and initialize does not touch system, so references do not alias
-                unsafe {
-                    (*system).initialize(world);
-                }
+                let observer: *mut Self = if let Some(mut observe) = world.get_mut::<Self>(entity) {
+                    descriptor.merge(&observe.descriptor);
+                    &mut *observe
+                } else {
+                    return;
+                };
+                // SAFETY: World reference is exclusive and initialize does not touch the observer, so references do not alias
+                let order = unsafe {
+                    (*observer).system.initialize(world);
+                    for condition in &mut (*observer).conditions {
+                        condition.initialize(world);
+                    }
+                    let mut order = (*observer).order.clone();
+                    order.sets.extend((*observer).system.default_system_sets());
+                    order
+                };
 
                 {
                     let mut entity = world.entity_mut(entity);
                     if let crate::world::Entry::Vacant(entry) = entity.entry::<ObserverState>() {
                         entry.insert(ObserverState {
                             descriptor,
+                            order,
                             runner: observer_system_runner::<E, B>,
                             ..Default::default()
                         });
@@ -358,7 +438,7 @@ fn observer_system_runner<E: Event, B: Bundle>(
     mut world: DeferredWorld,
     observer_trigger: ObserverTrigger,
     ptr: PtrMut,
-    propagate: &mut bool,
+    trigger_state: &mut TriggerState,
 ) {
     let world = world.as_unsafe_world_cell();
     // SAFETY: Observer was triggered so must still exist in world
@@ -382,10 +462,35 @@ fn observer_system_runner<E: Event, B: Bundle>(
     }
     state.last_trigger_id = last_trigger;
 
+    // SAFETY: Observer was triggered so must have an `Observer` component.
+    let observer = unsafe {
+        observer_cell
+            .get_mut::<Observer<E, B>>()
+            .debug_checked_unwrap()
+            .into_inner()
+    };
+
+    // not short-circuiting is intentional
+    #[allow(clippy::unnecessary_fold)]
+    let should_run = observer
+        .conditions
+        .iter_mut()
+        .map(|condition| {
+            condition.update_archetype_component_access(world);
+            // SAFETY:
+            // - `update_archetype_component_access` was just called
+            // - conditions are read-only systems, so they don't conflict with the observer's own access
+            unsafe { condition.run_unsafe((), world) }
+        })
+        .fold(true, |acc, res| acc && res);
+    if !should_run {
+        return;
+    }
+
     let trigger: Trigger<E, B> = Trigger::new(
         // SAFETY: Caller ensures `ptr` is castable to `&mut T`
         unsafe { ptr.deref_mut() },
-        propagate,
+        trigger_state,
         observer_trigger,
     );
     // SAFETY: the static lifetime is encapsulated in Trigger / cannot leak out.
@@ -395,13 +500,7 @@ fn observer_system_runner<E: Event, B: Bundle>(
     // This transmute is obviously not ideal, but it is safe. Ideally we can remove the
     // static constraint from ObserverSystem, but so far we have not found a way.
     let trigger: Trigger<'static, E, B> = unsafe { std::mem::transmute(trigger) };
-    // SAFETY: Observer was triggered so must have an `Observer` component.
-    let system = unsafe {
-        &mut observer_cell
-            .get_mut::<Observer<E, B>>()
-            .debug_checked_unwrap()
-            .system
-    };
+    let system = &mut observer.system;
 
     system.update_archetype_component_access(world);
 
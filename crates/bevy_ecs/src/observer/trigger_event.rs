@@ -67,27 +67,33 @@ fn trigger_event<E: Event, Targets: TriggerTargets>(
     let mut world = DeferredWorld::from(world);
     if targets.entities().is_empty() {
         // SAFETY: T is accessible as the type represented by self.trigger, ensured in `Self::new`
-        unsafe {
+        let state = unsafe {
             world.trigger_observers_with_data::<_, E::Traversal>(
                 event_type,
                 Entity::PLACEHOLDER,
                 targets.components(),
                 event_data,
                 false,
-            );
+            )
         };
+        if !state.default_prevented {
+            E::default_action(world.reborrow(), event_data, Entity::PLACEHOLDER);
+        }
     } else {
         for target in targets.entities() {
             // SAFETY: T is accessible as the type represented by self.trigger, ensured in `Self::new`
-            unsafe {
+            let state = unsafe {
                 world.trigger_observers_with_data::<_, E::Traversal>(
                     event_type,
                     *target,
                     targets.components(),
                     event_data,
                     E::AUTO_PROPAGATE,
-                );
+                )
             };
+            if !state.default_prevented {
+                E::default_action(world.reborrow(), event_data, *target);
+            }
         }
     }
 }
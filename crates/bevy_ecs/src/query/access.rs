@@ -196,6 +196,7 @@ impl<T: SparseSetIndex> Access<T> {
         self.writes_all = self.writes_all || other.writes_all;
         self.reads_and_writes.union_with(&other.reads_and_writes);
         self.writes.union_with(&other.writes);
+        self.archetypal.union_with(&other.archetypal);
     }
 
     /// Returns `true` if the access and `other` can be active at the same time.
@@ -332,6 +333,10 @@ pub struct FilteredAccess<T: SparseSetIndex> {
     // An array of filter sets to express `With` or `Without` clauses in disjunctive normal form, for example: `Or<(With<A>, With<B>)>`.
     // Filters like `(With<A>, Or<(With<B>, Without<C>)>` are expanded into `Or<((With<A>, With<B>), (With<A>, Without<C>))>`.
     pub(crate) filter_sets: Vec<AccessFilters<T>>,
+    // The subset of `access` that is made on entities reached through a traversal rather than the matched entity,
+    // such as with `Traverse`. The filters above say nothing about those entities, so this access can never be
+    // proven disjoint by filters.
+    pub(crate) traversed: Access<T>,
 }
 
 impl<T: SparseSetIndex> Default for FilteredAccess<T> {
@@ -340,6 +345,7 @@ impl<T: SparseSetIndex> Default for FilteredAccess<T> {
             access: Access::default(),
             required: FixedBitSet::default(),
             filter_sets: vec![AccessFilters::default()],
+            traversed: Access::default(),
         }
     }
 }
@@ -379,6 +385,21 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         self.and_with(index);
     }
 
+    /// Adds access to the element given by `index` on an entity reached by traversing from the matched entity.
+    ///
+    /// Unlike [`Self::add_read`], this does not add a `With` filter, and the access is never
+    /// considered disjoint from other accesses because of filters.
+    pub fn add_traversed_read(&mut self, index: T) {
+        self.access.add_read(index.clone());
+        self.traversed.add_read(index);
+    }
+
+    /// Returns the subset of the access that is made on entities reached by traversing from the matched entity.
+    #[inline]
+    pub fn traversed_access(&self) -> &Access<T> {
+        &self.traversed
+    }
+
     fn add_required(&mut self, index: T) {
         self.required.grow_and_insert(index.sparse_set_index());
     }
@@ -415,6 +436,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     /// Adds all of the accesses from `other` to `self`.
     pub fn extend_access(&mut self, other: &FilteredAccess<T>) {
         self.access.extend(&other.access);
+        self.traversed.extend(&other.traversed);
     }
 
     /// Returns `true` if this and `other` can be active at the same time.
@@ -423,6 +445,13 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             return true;
         }
 
+        // Traversed accesses may land on any entity, so filters cannot make them disjoint.
+        if !self.traversed.is_compatible(&other.access)
+            || !other.traversed.is_compatible(&self.access)
+        {
+            return false;
+        }
+
         // If the access instances are incompatible, we want to check that whether filters can
         // guarantee that queries are disjoint.
         // Since the `filter_sets` array represents a Disjunctive Normal Form formula ("ORs of ANDs"),
@@ -455,6 +484,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     /// `Or<((With<A>, With<C>), (With<A>, Without<D>), (Without<B>, With<C>), (Without<B>, Without<D>))>`.
     pub fn extend(&mut self, other: &FilteredAccess<T>) {
         self.access.extend(&other.access);
+        self.traversed.extend(&other.traversed);
         self.required.union_with(&other.required);
 
         // We can avoid allocating a new array of bitsets if `other` contains just a single set of filters:
@@ -495,6 +525,17 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         self.required.is_subset(&other.required) && self.access().is_subset(other.access())
     }
 
+    /// Returns `true` if this access refers to the element given by `index` in any way:
+    /// as a read or write, as an archetypal access, or in a `With` or `Without` filter.
+    pub fn contains(&self, index: T) -> bool {
+        let sparse_set_index = index.sparse_set_index();
+        self.access.has_read(index.clone())
+            || self.access.has_archetypal(index)
+            || self.filter_sets.iter().any(|filter| {
+                filter.with.contains(sparse_set_index) || filter.without.contains(sparse_set_index)
+            })
+    }
+
     /// Returns the indices of the elements that this access filters for.
     pub fn with_filters(&self) -> impl Iterator<Item = T> + '_ {
         self.filter_sets
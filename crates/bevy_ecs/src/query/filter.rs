@@ -320,6 +320,110 @@ impl<T: Component> QueryFilter for Without<T> {
     }
 }
 
+/// Filter that allows entities with a component `T` to be matched, overriding the world's
+/// [default query filters](crate::entity_disabling::DefaultQueryFilters) for `T`.
+///
+/// By default, queries skip entities with a disabling component such as [`Disabled`].
+/// `Allows<T>` matches entities regardless of whether they have `T` or not.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::entity_disabling::Disabled;
+/// # use bevy_ecs::query::Allows;
+/// #
+/// # #[derive(Component)]
+/// # struct Enemy;
+/// #
+/// fn count_pooled_enemies(query: Query<Has<Disabled>, (With<Enemy>, Allows<Disabled>)>) {
+///     let pooled = query.iter().filter(|&disabled| disabled).count();
+///     println!("{pooled} enemies are waiting in the pool");
+/// }
+/// # bevy_ecs::system::assert_is_system(count_pooled_enemies);
+/// ```
+///
+/// [`Disabled`]: crate::entity_disabling::Disabled
+pub struct Allows<T>(PhantomData<T>);
+
+/// SAFETY:
+/// `update_component_access` does not add any accesses.
+/// This is sound because `fetch` does not access any components.
+/// `update_component_access` adds an archetypal access for `T`, which does not cause conflicts.
+/// This is sound because `matches_component_set` always returns `true`.
+unsafe impl<T: Component> WorldQuery for Allows<T> {
+    type Item<'w> = ();
+    type Fetch<'w> = ();
+    type State = ComponentId;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(_: Self::Item<'wlong>) -> Self::Item<'wshort> {}
+
+    #[inline]
+    unsafe fn init_fetch(
+        _world: UnsafeWorldCell,
+        _state: &ComponentId,
+        _last_run: Tick,
+        _this_run: Tick,
+    ) {
+    }
+
+    const IS_DENSE: bool = true;
+
+    #[inline]
+    unsafe fn set_archetype(
+        _fetch: &mut (),
+        _state: &ComponentId,
+        _archetype: &Archetype,
+        _table: &Table,
+    ) {
+    }
+
+    #[inline]
+    unsafe fn set_table(_fetch: &mut (), _state: &Self::State, _table: &Table) {}
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> Self::Item<'w> {
+    }
+
+    #[inline]
+    fn update_component_access(&id: &ComponentId, access: &mut FilteredAccess<ComponentId>) {
+        access.access_mut().add_archetypal(id);
+    }
+
+    fn init_state(world: &mut World) -> ComponentId {
+        world.init_component::<T>()
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        components.component_id::<T>()
+    }
+
+    fn matches_component_set(
+        _state: &ComponentId,
+        _set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        // `Allows<T>` always matches
+        true
+    }
+}
+
+impl<T: Component> QueryFilter for Allows<T> {
+    const IS_ARCHETYPAL: bool = true;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        _fetch: &mut Self::Fetch<'_>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> bool {
+        true
+    }
+}
+
 /// A filter that tests if any of the given filters apply.
 ///
 /// This is useful for example if a system with multiple components in a query only wants to run
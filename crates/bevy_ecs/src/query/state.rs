@@ -166,6 +166,8 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             unsafe {
                 if state.new_archetype_internal(archetype) {
                     state.update_archetype_component_access(archetype, access);
+                } else {
+                    state.update_archetype_traversed_access(archetype, access);
                 }
             }
         }
@@ -194,6 +196,9 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         // properly considered in a global "cross-query" context (both within systems and across systems).
         component_access.extend(&filter_component_access);
 
+        // Exclude disabled entities unless the query explicitly refers to the disabling components.
+        world.default_query_filters.apply(&mut component_access);
+
         Self {
             world_id: world.id(),
             archetype_generation: ArchetypeGeneration::initial(),
@@ -218,13 +223,19 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let filter_state = F::init_state(builder.world_mut());
         D::set_access(&mut fetch_state, builder.access());
 
+        let mut component_access = builder.access().clone();
+        builder
+            .world()
+            .default_query_filters
+            .apply(&mut component_access);
+
         let mut state = Self {
             world_id: builder.world().id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
             fetch_state,
             filter_state,
-            component_access: builder.access().clone(),
+            component_access,
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
             #[cfg(feature = "trace")]
@@ -392,6 +403,8 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         if matches {
             // SAFETY: The caller ensures that `archetype` is from the World the state was initialized from.
             unsafe { self.update_archetype_component_access(archetype, access) };
+        } else {
+            self.update_archetype_traversed_access(archetype, access);
         }
     }
 
@@ -445,6 +458,21 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         })
     }
 
+    /// For the given `archetype`, adds the components this query accesses on traversed entities to `access`.
+    ///
+    /// Traversed entities can live in any archetype, including ones not matched by this query.
+    fn update_archetype_traversed_access(
+        &self,
+        archetype: &Archetype,
+        access: &mut Access<ArchetypeComponentId>,
+    ) {
+        self.component_access.traversed.reads().for_each(|id| {
+            if let Some(id) = archetype.get_archetype_component_id(id) {
+                access.add_read(id);
+            }
+        });
+    }
+
     /// For the given `archetype`, adds any component accessed used by this query's underlying [`FilteredAccess`] to `access`.
     ///
     /// The passed in `access` will be updated with any new accesses introduced by the new archetype.
@@ -0,0 +1,387 @@
+use crate::{
+    archetype::Archetype,
+    component::{ComponentId, Components, Tick},
+    entity::Entity,
+    query::{FilteredAccess, QueryData, QueryFilter, ReadOnlyQueryData, WorldQuery},
+    storage::{Table, TableRow},
+    traversal::Traversal,
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+use std::marker::PhantomData;
+
+/// Query data that follows the [`Traversal`] component `T` of each entity and fetches `D` on the entity it points to.
+///
+/// Only entities that have a `T` component are matched. The fetched item is `None` if `T` does not point
+/// anywhere, the target entity does not exist, or the target does not match `D`.
+///
+/// Accesses made on the target entity are tracked separately in the query's [`FilteredAccess`]: as the target
+/// can be any entity in the world, `With`/`Without` filters on the query never make them disjoint from other accesses.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::Traverse;
+/// # use bevy_ecs::traversal::Traversal;
+/// #[derive(Component)]
+/// struct Owner(Entity);
+///
+/// impl Traversal for Owner {
+///     fn traverse(&self) -> Option<Entity> {
+///         Some(self.0)
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Name(&'static str);
+///
+/// #[derive(Component)]
+/// struct Weapon;
+///
+/// fn print_weapon_owners(weapons: Query<Traverse<Owner, &Name>, With<Weapon>>) {
+///     for owner_name in &weapons {
+///         if let Some(Name(name)) = owner_name {
+///             println!("This weapon belongs to {name}");
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(print_weapon_owners);
+/// ```
+pub struct Traverse<T, D>(PhantomData<(T, D)>);
+
+/// Filter that follows the [`Traversal`] component `T` of each entity and only selects entities whose target matches `F`.
+///
+/// Entities without a `T` component, or whose `T` does not point to an existing entity, are never selected.
+///
+/// See [`Traverse`] for how accesses on the target entity are tracked.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::TraverseFilter;
+/// # use bevy_ecs::traversal::Traversal;
+/// #[derive(Component)]
+/// struct Owner(Entity);
+///
+/// impl Traversal for Owner {
+///     fn traverse(&self) -> Option<Entity> {
+///         Some(self.0)
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Stunned;
+///
+/// #[derive(Component)]
+/// struct Weapon;
+///
+/// fn disarm_stunned(weapons: Query<Entity, (With<Weapon>, TraverseFilter<Owner, With<Stunned>>)>) {
+///     for weapon in &weapons {
+///         println!("{weapon:?} is wielded by a stunned character");
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(disarm_stunned);
+/// ```
+pub struct TraverseFilter<T, F>(PhantomData<(T, F)>);
+
+/// The [`WorldQuery::Fetch`] type for [`Traverse`] and [`TraverseFilter`].
+pub struct TraverseFetch<'w, T: Traversal, Q: WorldQuery> {
+    source: <&'static T as WorldQuery>::Fetch<'w>,
+    world: UnsafeWorldCell<'w>,
+    // The state is owned by the `QueryState` that created this fetch, which outlives it.
+    state: *const Q::State,
+    last_run: Tick,
+    this_run: Tick,
+}
+
+impl<T: Traversal, Q: WorldQuery> Clone for TraverseFetch<'_, T, Q> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source,
+            world: self.world,
+            state: self.state,
+            last_run: self.last_run,
+            this_run: self.this_run,
+        }
+    }
+}
+
+impl<'w, T: Traversal, Q: WorldQuery> TraverseFetch<'w, T, Q> {
+    /// # Safety
+    ///
+    /// Same as [`WorldQuery::init_fetch`].
+    unsafe fn new(
+        world: UnsafeWorldCell<'w>,
+        state: &(ComponentId, Q::State),
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self {
+        Self {
+            source: <&T as WorldQuery>::init_fetch(world, &state.0, last_run, this_run),
+            world,
+            state: &state.1,
+            last_run,
+            this_run,
+        }
+    }
+
+    /// Returns the traversal target of the given entity, along with an initialized fetch for `Q` on it.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`WorldQuery::fetch`].
+    unsafe fn target(
+        &mut self,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Option<(Entity, TableRow, Q::Fetch<'w>)> {
+        let source = <&T as WorldQuery>::fetch(&mut self.source, entity, table_row);
+        let target = source.traverse()?;
+        // SAFETY: The state is kept alive by the `QueryState` this fetch was created from.
+        let state = unsafe { &*self.state };
+        let location = self.world.entities().get(target)?;
+        let archetype = self.world.archetypes().get(location.archetype_id)?;
+        if !Q::matches_component_set(state, &|id| archetype.contains(id)) {
+            return None;
+        }
+        let table = self.world.storages().tables.get(location.table_id)?;
+        let mut fetch = Q::init_fetch(self.world, state, self.last_run, self.this_run);
+        Q::set_archetype(&mut fetch, state, archetype, table);
+        Some((target, location.table_row, fetch))
+    }
+}
+
+/// Registers the accesses of `Q` as traversed accesses of `access`.
+fn add_traversed_access<T: Traversal, Q: WorldQuery>(
+    state: &(ComponentId, Q::State),
+    access: &mut FilteredAccess<ComponentId>,
+) {
+    let mut target_access = FilteredAccess::default();
+    Q::update_component_access(&state.1, &mut target_access);
+    assert!(
+        !target_access.access().has_read_all(),
+        "{} cannot traverse to queries that access every component.",
+        std::any::type_name::<Traverse<T, Q>>(),
+    );
+    for id in target_access.access().reads_and_writes() {
+        assert!(
+            !access.access().has_write(id),
+            "{} conflicts with a previous access in this query. Shared access cannot coexist with exclusive access.",
+            std::any::type_name::<Traverse<T, Q>>(),
+        );
+        access.add_traversed_read(id);
+    }
+}
+
+macro_rules! impl_traverse_world_query {
+    ($name:ident, $bound:ident, $item:ty, |$fetch:ident, $entity:ident, $table_row:ident| $fetch_body:expr, |$shrink_item:ident| $shrink:expr) => {
+        /// SAFETY:
+        /// `fetch` reads `T` on the matched entity and reads the accesses of the inner query on the target entity.
+        /// This is sound because `update_component_access` adds read access for `T` and registers every access of the
+        /// inner query as a traversed read, which is applied to all archetypes regardless of filters.
+        /// `update_component_access` adds a `With` filter for `T`.
+        /// This is sound because `matches_component_set` returns whether the set contains `T`.
+        unsafe impl<T: Traversal, Q: $bound> WorldQuery for $name<T, Q> {
+            type Item<'w> = $item;
+            type Fetch<'w> = TraverseFetch<'w, T, Q>;
+            type State = (ComponentId, Q::State);
+
+            fn shrink<'wlong: 'wshort, 'wshort>(
+                $shrink_item: Self::Item<'wlong>,
+            ) -> Self::Item<'wshort> {
+                $shrink
+            }
+
+            #[inline]
+            unsafe fn init_fetch<'w>(
+                world: UnsafeWorldCell<'w>,
+                state: &Self::State,
+                last_run: Tick,
+                this_run: Tick,
+            ) -> Self::Fetch<'w> {
+                TraverseFetch::new(world, state, last_run, this_run)
+            }
+
+            const IS_DENSE: bool = <&T as WorldQuery>::IS_DENSE;
+
+            #[inline]
+            unsafe fn set_archetype<'w>(
+                fetch: &mut Self::Fetch<'w>,
+                state: &Self::State,
+                archetype: &'w Archetype,
+                table: &'w Table,
+            ) {
+                <&T as WorldQuery>::set_archetype(&mut fetch.source, &state.0, archetype, table);
+            }
+
+            #[inline]
+            unsafe fn set_table<'w>(
+                fetch: &mut Self::Fetch<'w>,
+                state: &Self::State,
+                table: &'w Table,
+            ) {
+                <&T as WorldQuery>::set_table(&mut fetch.source, &state.0, table);
+            }
+
+            #[inline(always)]
+            unsafe fn fetch<'w>(
+                $fetch: &mut Self::Fetch<'w>,
+                $entity: Entity,
+                $table_row: TableRow,
+            ) -> Self::Item<'w> {
+                $fetch_body
+            }
+
+            fn update_component_access(
+                state: &Self::State,
+                access: &mut FilteredAccess<ComponentId>,
+            ) {
+                <&T as WorldQuery>::update_component_access(&state.0, access);
+                add_traversed_access::<T, Q>(state, access);
+            }
+
+            fn init_state(world: &mut World) -> Self::State {
+                (world.init_component::<T>(), Q::init_state(world))
+            }
+
+            fn get_state(components: &Components) -> Option<Self::State> {
+                Some((components.component_id::<T>()?, Q::get_state(components)?))
+            }
+
+            fn matches_component_set(
+                state: &Self::State,
+                set_contains_id: &impl Fn(ComponentId) -> bool,
+            ) -> bool {
+                set_contains_id(state.0)
+            }
+        }
+    };
+}
+
+impl_traverse_world_query!(
+    Traverse,
+    ReadOnlyQueryData,
+    Option<Q::Item<'w>>,
+    |fetch, entity, table_row| {
+        let (target, target_row, mut target_fetch) = fetch.target(entity, table_row)?;
+        Some(Q::fetch(&mut target_fetch, target, target_row))
+    },
+    |item| item.map(Q::shrink)
+);
+
+impl_traverse_world_query!(
+    TraverseFilter,
+    QueryFilter,
+    bool,
+    |fetch, entity, table_row| {
+        fetch
+            .target(entity, table_row)
+            .is_some_and(|(target, target_row, mut target_fetch)| {
+                Q::filter_fetch(&mut target_fetch, target, target_row)
+            })
+    },
+    |item| item
+);
+
+/// SAFETY: `Self` is the same as `Self::ReadOnly`
+unsafe impl<T: Traversal, D: ReadOnlyQueryData> QueryData for Traverse<T, D> {
+    type ReadOnly = Self;
+}
+
+/// SAFETY: `T` is only read and `D` is read-only
+unsafe impl<T: Traversal, D: ReadOnlyQueryData> ReadOnlyQueryData for Traverse<T, D> {}
+
+impl<T: Traversal, F: QueryFilter> QueryFilter for TraverseFilter<T, F> {
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        Self::fetch(fetch, entity, table_row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        prelude::*,
+        query::{Traverse, TraverseFilter},
+        schedule::ScheduleBuildSettings,
+        traversal::Traversal,
+    };
+
+    #[derive(Component)]
+    struct Owner(Entity);
+
+    impl Traversal for Owner {
+        fn traverse(&self) -> Option<Entity> {
+            Some(self.0)
+        }
+    }
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Health(u32);
+
+    #[derive(Component)]
+    struct Stunned;
+
+    #[derive(Component)]
+    struct Weapon;
+
+    #[test]
+    fn traverse_fetches_target_data() {
+        let mut world = World::new();
+        let healthy = world.spawn(Health(10)).id();
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+        let a = world.spawn(Owner(healthy)).id();
+        let b = world.spawn(Owner(missing)).id();
+        world.spawn(Health(5));
+
+        let mut query = world.query::<Traverse<Owner, &Health>>();
+        assert_eq!(query.iter(&world).count(), 2);
+        assert_eq!(query.get(&world, a).unwrap(), Some(&Health(10)));
+        assert_eq!(query.get(&world, b).unwrap(), None);
+    }
+
+    #[test]
+    fn traverse_filter_matches_target() {
+        let mut world = World::new();
+        let stunned = world.spawn(Stunned).id();
+        let awake = world.spawn_empty().id();
+        let a = world.spawn((Weapon, Owner(stunned))).id();
+        world.spawn((Weapon, Owner(awake)));
+        world.spawn(Weapon);
+
+        let mut query =
+            world.query_filtered::<Entity, (With<Weapon>, TraverseFilter<Owner, With<Stunned>>)>();
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), [a]);
+    }
+
+    #[test]
+    fn traversed_access_ignores_filters() {
+        fn read_owner_health(_: Query<Traverse<Owner, &Health>, With<Weapon>>) {}
+        fn write_health(_: Query<&mut Health, Without<Weapon>>) {}
+
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.set_build_settings(ScheduleBuildSettings {
+            ambiguity_detection: bevy_ecs::schedule::LogLevel::Error,
+            ..Default::default()
+        });
+        schedule.add_systems((read_owner_health, write_health));
+        assert!(schedule.initialize(&mut world).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn traverse_conflicts_with_mutable_access() {
+        let mut world = World::new();
+        world.query::<(&mut Health, Traverse<Owner, &Health>)>();
+    }
+}
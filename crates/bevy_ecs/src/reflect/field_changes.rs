@@ -0,0 +1,672 @@
+//! Per-field change detection for reflected [`Component`]s.
+//!
+//! [`Changed`](crate::query::Changed) and [`Ref`](crate::change_detection::Ref) only tell that a
+//! component was accessed mutably. [`FieldChangeTracker`] instead keeps a reflected snapshot of
+//! every tracked component and compares it field by field with the current value, producing
+//! [`FieldChanges`] that contain only the fields whose value actually changed. These can then be
+//! [applied](FieldChanges::apply) to another world, for example to replicate it over the network.
+
+use std::any::TypeId;
+
+use crate as bevy_ecs;
+use crate::{
+    change_detection::Mut,
+    component::{Component, Tick},
+    entity::{Entity, EntityHashMap},
+    reflect::ReflectComponent,
+    system::Resource,
+    world::World,
+};
+use bevy_reflect::{
+    access::Access, ApplyError, FromType, OffsetAccess, ParsedPath, Reflect, ReflectFromReflect,
+    ReflectMut, ReflectPath, ReflectRef, TypeInfo, TypeRegistry, VariantType,
+};
+use thiserror::Error;
+
+/// The new value of a single field of a reflected value, created by [`diff_reflect`].
+#[derive(Debug)]
+pub struct FieldChange {
+    /// The path of the field, relative to the root value.
+    ///
+    /// The path is empty if the whole value changed.
+    pub path: ParsedPath,
+    /// The new value of the field.
+    pub value: Box<dyn Reflect>,
+}
+
+/// How a tracked component of an entity changed, see [`FieldChanges`].
+#[derive(Debug)]
+pub enum ComponentChange {
+    /// The component was added to the entity, or it started being tracked.
+    Added(Box<dyn Reflect>),
+    /// Some fields of the component changed.
+    Changed(Vec<FieldChange>),
+    /// The component was removed from the entity, or the entity was despawned.
+    Removed,
+}
+
+/// A change of one tracked component of one entity.
+#[derive(Debug)]
+pub struct EntityComponentChange {
+    /// The entity whose component changed.
+    pub entity: Entity,
+    /// The [`TypeId`] of the component.
+    pub type_id: TypeId,
+    /// What changed.
+    pub change: ComponentChange,
+}
+
+/// The changes found by one [`FieldChangeTracker::update`].
+#[derive(Debug, Default)]
+pub struct FieldChanges {
+    /// The change tick of the world when the changes were collected.
+    pub tick: Tick,
+    /// The changes, grouped by component type.
+    pub changes: Vec<EntityComponentChange>,
+}
+
+/// An error returned by [`FieldChanges::apply`].
+#[derive(Debug, Error)]
+pub enum FieldChangeError {
+    /// The component type is not registered with [`ReflectComponent`] type data.
+    #[error("component with type id {0:?} is not registered with `ReflectComponent`")]
+    UnregisteredComponent(TypeId),
+    /// The entity the changes are applied to was despawned.
+    #[error("entity {0:?} does not exist")]
+    MissingEntity(Entity),
+    /// The entity the changes are applied to does not have the changed component.
+    #[error("entity {0:?} has no component with type id {1:?}")]
+    MissingComponent(Entity, TypeId),
+    /// The path of a changed field does not exist in the component.
+    #[error("cannot access field `{path}`: {message}")]
+    InvalidPath {
+        /// The path of the field.
+        path: ParsedPath,
+        /// The reason the field could not be accessed.
+        message: String,
+    },
+    /// The new value of a field could not be applied.
+    #[error("cannot apply field `{path}`: {error}")]
+    Apply {
+        /// The path of the field.
+        path: ParsedPath,
+        /// The reason the value could not be applied.
+        error: ApplyError,
+    },
+}
+
+impl FieldChanges {
+    /// Returns `true` if no tracked component changed.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Applies the changes to the entities of `world`.
+    ///
+    /// `entity_map` maps the entities the changes were collected from to entities of `world`.
+    /// Entities that are not in the map yet are spawned and added to it, unless their component
+    /// was removed.
+    pub fn apply(
+        &self,
+        world: &mut World,
+        registry: &TypeRegistry,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), FieldChangeError> {
+        for EntityComponentChange {
+            entity,
+            type_id,
+            change,
+        } in &self.changes
+        {
+            let reflect_component = registry
+                .get_type_data::<ReflectComponent>(*type_id)
+                .ok_or(FieldChangeError::UnregisteredComponent(*type_id))?;
+            let target = match (entity_map.get(entity), change) {
+                (Some(target), _) => *target,
+                (None, ComponentChange::Removed) => continue,
+                (None, _) => *entity_map
+                    .entry(*entity)
+                    .or_insert_with(|| world.spawn_empty().id()),
+            };
+            let mut target_entity = world
+                .get_entity_mut(target)
+                .ok_or(FieldChangeError::MissingEntity(target))?;
+            match change {
+                ComponentChange::Added(value) => {
+                    // Applying would only merge lists, maps and sets into the existing component.
+                    reflect_component.insert(&mut target_entity, &**value, registry);
+                }
+                ComponentChange::Removed => reflect_component.remove(&mut target_entity),
+                ComponentChange::Changed(fields) => {
+                    let mut component = reflect_component
+                        .reflect_mut(&mut target_entity)
+                        .ok_or(FieldChangeError::MissingComponent(target, *type_id))?;
+                    for FieldChange { path, value } in fields {
+                        apply_field(&mut component, path, &**value, registry)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Replaces the field at `path` with `value`.
+///
+/// [`Reflect::try_apply`] only merges lists, maps and sets, so items that were removed from them
+/// would be kept. The field is instead replaced by a value built with [`ReflectFromReflect`], or
+/// cleared before applying if its type is not registered with it.
+fn apply_field(
+    component: &mut Mut<dyn Reflect>,
+    path: &ParsedPath,
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<(), FieldChangeError> {
+    let field = path.reflect_element_mut(&mut **component).map_err(|err| {
+        FieldChangeError::InvalidPath {
+            path: path.clone(),
+            message: err.to_string(),
+        }
+    })?;
+    let replacement = field
+        .get_represented_type_info()
+        .and_then(|info| registry.get_type_data::<ReflectFromReflect>(info.type_id()))
+        .and_then(|from_reflect| from_reflect.from_reflect(value));
+    let result = match replacement {
+        Some(replacement) => {
+            field
+                .set(replacement)
+                .map_err(|replacement| ApplyError::MismatchedTypes {
+                    from_type: replacement.reflect_type_path().into(),
+                    to_type: field.reflect_type_path().into(),
+                })
+        }
+        None => {
+            clear_collection(field);
+            field.try_apply(value)
+        }
+    };
+    result.map_err(|error| FieldChangeError::Apply {
+        path: path.clone(),
+        error,
+    })
+}
+
+/// Removes every item of `value` if it is a list, map or set.
+fn clear_collection(value: &mut dyn Reflect) {
+    match value.reflect_mut() {
+        ReflectMut::List(list) => while list.pop().is_some() {},
+        ReflectMut::Map(map) => {
+            let keys: Vec<_> = map.iter().map(|(key, _)| key.clone_value()).collect();
+            for key in keys {
+                map.remove(&*key);
+            }
+        }
+        ReflectMut::Set(set) => {
+            let values: Vec<_> = set.iter().map(Reflect::clone_value).collect();
+            for value in values {
+                set.remove(&*value);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the fields of `new` whose value differs from `old`.
+///
+/// Structs, tuples, tuple structs, lists and arrays of the same length, and enums with the same
+/// variant are compared field by field. Any other value is compared with
+/// [`Reflect::reflect_partial_eq`] and reported as a whole if it differs. Values that don't
+/// support comparison are always reported.
+///
+/// ```
+/// # use bevy_ecs::reflect::diff_reflect;
+/// # use bevy_reflect::Reflect;
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     position: (f32, f32),
+/// }
+///
+/// let old = Player { name: "Ferris".into(), position: (0.0, 0.0) };
+/// let new = Player { name: "Ferris".into(), position: (0.0, 4.0) };
+///
+/// let changes = diff_reflect(&old, &new);
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].path.to_string(), ".position.1");
+/// assert_eq!(changes[0].value.downcast_ref::<f32>(), Some(&4.0));
+/// ```
+pub fn diff_reflect(old: &dyn Reflect, new: &dyn Reflect) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_into(&mut Vec::new(), old, new, &mut changes);
+    changes
+}
+
+fn diff_into(
+    path: &mut Vec<OffsetAccess>,
+    old: &dyn Reflect,
+    new: &dyn Reflect,
+    changes: &mut Vec<FieldChange>,
+) {
+    let mut diff_field = |access: Access<'static>, old: Option<&dyn Reflect>, new: &dyn Reflect| {
+        path.push(access.into());
+        match old {
+            Some(old) => diff_into(path, old, new, changes),
+            None => changes.push(FieldChange {
+                path: ParsedPath(path.clone()),
+                value: new.clone_value(),
+            }),
+        }
+        path.pop();
+    };
+
+    let same_type = old.get_represented_type_info().map(TypeInfo::type_id)
+        == new.get_represented_type_info().map(TypeInfo::type_id);
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old), ReflectRef::Struct(new)) if same_type => {
+            for (index, field) in new.iter_fields().enumerate() {
+                let name = new.name_at(index).unwrap();
+                diff_field(
+                    Access::Field(name.to_owned().into()),
+                    old.field(name),
+                    field,
+                );
+            }
+        }
+        (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new)) if same_type => {
+            for (index, field) in new.iter_fields().enumerate() {
+                diff_field(Access::TupleIndex(index), old.field(index), field);
+            }
+        }
+        (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) if old.field_len() == new.field_len() => {
+            for (index, field) in new.iter_fields().enumerate() {
+                diff_field(Access::TupleIndex(index), old.field(index), field);
+            }
+        }
+        (ReflectRef::List(old), ReflectRef::List(new)) if old.len() == new.len() => {
+            for (index, item) in new.iter().enumerate() {
+                diff_field(Access::ListIndex(index), old.get(index), item);
+            }
+        }
+        (ReflectRef::Array(old), ReflectRef::Array(new)) if old.len() == new.len() => {
+            for (index, item) in new.iter().enumerate() {
+                diff_field(Access::ListIndex(index), old.get(index), item);
+            }
+        }
+        (ReflectRef::Enum(old), ReflectRef::Enum(new))
+            if same_type && old.variant_name() == new.variant_name() =>
+        {
+            for (index, field) in new.iter_fields().enumerate() {
+                match new.variant_type() {
+                    VariantType::Struct => {
+                        let name = new.name_at(index).unwrap();
+                        diff_field(
+                            Access::Field(name.to_owned().into()),
+                            old.field(name),
+                            field.value(),
+                        );
+                    }
+                    _ => diff_field(
+                        Access::TupleIndex(index),
+                        old.field_at(index),
+                        field.value(),
+                    ),
+                }
+            }
+        }
+        _ => {
+            if old.reflect_partial_eq(new) != Some(true) {
+                changes.push(FieldChange {
+                    path: ParsedPath(path.clone()),
+                    value: new.clone_value(),
+                });
+            }
+        }
+    }
+}
+
+/// Collects the changed fields of reflected components, see the [module docs](self).
+///
+/// Tracking is opt-in per component type with [`track`](Self::track). Every call to
+/// [`update`](Self::update), usually through the [`track_field_changes`] system, compares the
+/// components that were mutably accessed since the previous update against their snapshot.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::reflect::{ComponentChange, FieldChangeTracker};
+/// # use bevy_reflect::Reflect;
+/// #[derive(Component, Reflect)]
+/// struct Health {
+///     current: u32,
+///     max: u32,
+/// }
+///
+/// let mut world = World::new();
+/// let entity = world.spawn(Health { current: 10, max: 10 }).id();
+///
+/// let mut tracker = FieldChangeTracker::default();
+/// tracker.track::<Health>();
+/// tracker.update(&world);
+///
+/// world.get_mut::<Health>(entity).unwrap().current = 7;
+/// let changes = tracker.update(&world);
+/// let ComponentChange::Changed(fields) = &changes.changes[0].change else {
+///     unreachable!();
+/// };
+/// assert_eq!(fields.len(), 1);
+/// assert_eq!(fields[0].path.to_string(), ".current");
+/// ```
+#[derive(Resource, Default)]
+pub struct FieldChangeTracker {
+    components: Vec<TrackedComponent>,
+    last_run: Tick,
+    changes: FieldChanges,
+}
+
+struct TrackedComponent {
+    type_id: TypeId,
+    reflect_component: ReflectComponent,
+    snapshots: EntityHashMap<Box<dyn Reflect>>,
+}
+
+impl FieldChangeTracker {
+    /// Starts tracking the changes of the component `C`.
+    ///
+    /// Every entity that already has the component is reported as
+    /// [added](ComponentChange::Added) by the next update.
+    pub fn track<C: Component + Reflect>(&mut self) -> &mut Self {
+        self.track_by_id(
+            TypeId::of::<C>(),
+            <ReflectComponent as FromType<C>>::from_type(),
+        )
+    }
+
+    /// Starts tracking the changes of the component with the given [`TypeId`], accessed with
+    /// `reflect_component`.
+    ///
+    /// This is useful for components only known at runtime, whose [`ReflectComponent`] comes from
+    /// the [`TypeRegistry`].
+    pub fn track_by_id(
+        &mut self,
+        type_id: TypeId,
+        reflect_component: ReflectComponent,
+    ) -> &mut Self {
+        if !self.is_tracked(type_id) {
+            self.components.push(TrackedComponent {
+                type_id,
+                reflect_component,
+                snapshots: EntityHashMap::default(),
+            });
+        }
+        self
+    }
+
+    /// Returns `true` if the component with the given [`TypeId`] is tracked.
+    pub fn is_tracked(&self, type_id: TypeId) -> bool {
+        self.components
+            .iter()
+            .any(|component| component.type_id == type_id)
+    }
+
+    /// Returns the changes found by the last [`update`](Self::update).
+    pub fn changes(&self) -> &FieldChanges {
+        &self.changes
+    }
+
+    /// Compares the tracked components of `world` against their snapshots, and returns the
+    /// changes since the previous update.
+    pub fn update(&mut self, world: &World) -> &FieldChanges {
+        let this_run = world.increment_change_tick();
+        let mut changes = Vec::new();
+        for tracked in &mut self.components {
+            let Some(component_id) = world.components().get_id(tracked.type_id) else {
+                continue;
+            };
+
+            for archetype in world.archetypes().iter() {
+                if !archetype.contains(component_id) {
+                    continue;
+                }
+                for entity in archetype.entities() {
+                    let entity = world.entity(entity.id());
+                    let change = match tracked.snapshots.get_mut(&entity.id()) {
+                        Some(snapshot) => {
+                            let ticks = entity.get_change_ticks_by_id(component_id).unwrap();
+                            if !ticks.is_changed(self.last_run, this_run) {
+                                continue;
+                            }
+                            let value = tracked.reflect_component.reflect(entity).unwrap();
+                            let fields = diff_reflect(&**snapshot, value);
+                            if fields.is_empty() {
+                                continue;
+                            }
+                            *snapshot = value.clone_value();
+                            ComponentChange::Changed(fields)
+                        }
+                        None => {
+                            let value = tracked.reflect_component.reflect(entity).unwrap();
+                            tracked.snapshots.insert(entity.id(), value.clone_value());
+                            ComponentChange::Added(value.clone_value())
+                        }
+                    };
+                    changes.push(EntityComponentChange {
+                        entity: entity.id(),
+                        type_id: tracked.type_id,
+                        change,
+                    });
+                }
+            }
+
+            tracked.snapshots.retain(|&entity, _| {
+                let exists = world
+                    .get_entity(entity)
+                    .is_some_and(|entity| entity.contains_id(component_id));
+                if !exists {
+                    changes.push(EntityComponentChange {
+                        entity,
+                        type_id: tracked.type_id,
+                        change: ComponentChange::Removed,
+                    });
+                }
+                exists
+            });
+        }
+
+        self.last_run = this_run;
+        self.changes = FieldChanges {
+            tick: this_run,
+            changes,
+        };
+        &self.changes
+    }
+}
+
+/// A system that calls [`FieldChangeTracker::update`] on the [`FieldChangeTracker`] resource.
+pub fn track_field_changes(world: &mut World) {
+    world.resource_scope(|world, mut tracker: Mut<FieldChangeTracker>| {
+        tracker.update(world);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use bevy_reflect::TypeRegistry;
+    use bevy_utils::HashMap;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Stats {
+        speed: f32,
+        inventory: Vec<u32>,
+        mode: Mode,
+        skills: HashMap<String, u32>,
+    }
+
+    #[derive(Reflect, Default, Debug, PartialEq)]
+    enum Mode {
+        #[default]
+        Idle,
+        Moving(f32, f32),
+    }
+
+    fn stats() -> Stats {
+        Stats {
+            speed: 1.0,
+            inventory: vec![1, 2, 3],
+            mode: Mode::Moving(0.0, 1.0),
+            skills: HashMap::from_iter([("archery".to_string(), 2), ("stealth".to_string(), 5)]),
+        }
+    }
+
+    fn paths(changes: &[FieldChange]) -> Vec<String> {
+        changes
+            .iter()
+            .map(|change| change.path.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn diff_nested_fields() {
+        let old = stats();
+        let mut new = stats();
+        assert!(diff_reflect(&old, &new).is_empty());
+
+        new.inventory[1] = 5;
+        new.mode = Mode::Moving(0.0, 2.0);
+        assert_eq!(
+            paths(&diff_reflect(&old, &new)),
+            [".inventory[1]", ".mode.1"]
+        );
+
+        // Changing the length of a list or the variant of an enum replaces it.
+        new.inventory.push(4);
+        new.mode = Mode::Idle;
+        assert_eq!(paths(&diff_reflect(&old, &new)), [".inventory", ".mode"]);
+
+        // Snapshots are dynamic values.
+        assert_eq!(
+            paths(&diff_reflect(&*old.clone_value(), &new)),
+            [".inventory", ".mode"]
+        );
+    }
+
+    #[test]
+    fn tracker_reports_only_changed_fields() {
+        let mut world = World::new();
+        let entity = world.spawn(stats()).id();
+
+        let mut tracker = FieldChangeTracker::default();
+        tracker.track::<Stats>();
+        let changes = tracker.update(&world);
+        assert!(matches!(
+            changes.changes[0].change,
+            ComponentChange::Added(_)
+        ));
+
+        // Mutable access without changing any value is not reported.
+        world.get_mut::<Stats>(entity).unwrap().speed = 1.0;
+        assert!(tracker.update(&world).is_empty());
+
+        world.get_mut::<Stats>(entity).unwrap().speed = 2.0;
+        let changes = tracker.update(&world);
+        let ComponentChange::Changed(fields) = &changes.changes[0].change else {
+            panic!("expected a change");
+        };
+        assert_eq!(paths(fields), [".speed"]);
+        assert!(tracker.update(&world).is_empty());
+
+        world.entity_mut(entity).remove::<Stats>();
+        let changes = tracker.update(&world);
+        assert!(matches!(
+            changes.changes[0].change,
+            ComponentChange::Removed
+        ));
+    }
+
+    #[test]
+    fn apply_to_other_world() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Stats>();
+
+        let mut source = World::new();
+        let entity = source.spawn(stats()).id();
+        let mut target = World::new();
+        let mut entity_map = EntityHashMap::default();
+
+        let mut tracker = FieldChangeTracker::default();
+        tracker.track::<Stats>();
+        let mut sync = |source: &World, target: &mut World, map: &mut EntityHashMap<Entity>| {
+            tracker
+                .update(source)
+                .apply(target, &registry, map)
+                .unwrap();
+        };
+
+        sync(&source, &mut target, &mut entity_map);
+        let mirrored = *entity_map.get(&entity).unwrap();
+        assert_eq!(target.get::<Stats>(mirrored), Some(&stats()));
+
+        {
+            let mut stats = source.get_mut::<Stats>(entity).unwrap();
+            stats.inventory.push(4);
+            stats.mode = Mode::Idle;
+        }
+        sync(&source, &mut target, &mut entity_map);
+        assert_eq!(target.get::<Stats>(mirrored), source.get::<Stats>(entity));
+
+        source.despawn(entity);
+        sync(&source, &mut target, &mut entity_map);
+        assert!(target.get::<Stats>(mirrored).is_none());
+    }
+
+    #[test]
+    fn apply_removes_items() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Stats>();
+
+        let mut source = World::new();
+        let entity = source.spawn(stats()).id();
+
+        // The target already has a component with more items than the source.
+        let mut target = World::new();
+        let mut larger = stats();
+        larger.inventory.push(4);
+        larger.skills.insert("magic".to_string(), 1);
+        let mirrored = target.spawn(larger).id();
+        let mut entity_map = EntityHashMap::from_iter([(entity, mirrored)]);
+
+        let mut tracker = FieldChangeTracker::default();
+        tracker.track::<Stats>();
+        let mut sync = |source: &World, target: &mut World, map: &mut EntityHashMap<Entity>| {
+            tracker
+                .update(source)
+                .apply(target, &registry, map)
+                .unwrap();
+        };
+
+        sync(&source, &mut target, &mut entity_map);
+        assert_eq!(target.get::<Stats>(mirrored), Some(&stats()));
+
+        {
+            let mut stats = source.get_mut::<Stats>(entity).unwrap();
+            stats.inventory.truncate(1);
+            stats.skills.remove("stealth");
+        }
+        sync(&source, &mut target, &mut entity_map);
+        let mirrored_stats = target.get::<Stats>(mirrored).unwrap();
+        assert_eq!(mirrored_stats.inventory, [1]);
+        assert_eq!(mirrored_stats.skills.len(), 1);
+        assert_eq!(Some(mirrored_stats), source.get::<Stats>(entity));
+    }
+
+    #[test]
+    fn clear_collections() {
+        let mut stats = stats();
+        clear_collection(&mut stats.inventory);
+        clear_collection(&mut stats.skills);
+        assert!(stats.inventory.is_empty());
+        assert!(stats.skills.is_empty());
+    }
+}
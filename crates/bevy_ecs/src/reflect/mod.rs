@@ -11,6 +11,7 @@ use bevy_reflect::{Reflect, ReflectFromReflect, TypeRegistry, TypeRegistryArc};
 mod bundle;
 mod component;
 mod entity_commands;
+mod field_changes;
 mod from_world;
 mod map_entities;
 mod resource;
@@ -18,6 +19,10 @@ mod resource;
 pub use bundle::{ReflectBundle, ReflectBundleFns};
 pub use component::{ReflectComponent, ReflectComponentFns};
 pub use entity_commands::ReflectCommandExt;
+pub use field_changes::{
+    diff_reflect, track_field_changes, ComponentChange, EntityComponentChange, FieldChange,
+    FieldChangeError, FieldChangeTracker, FieldChanges,
+};
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
 pub use map_entities::{ReflectMapEntities, ReflectMapEntitiesResource};
 pub use resource::{ReflectResource, ReflectResourceFns};
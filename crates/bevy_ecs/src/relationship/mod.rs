@@ -0,0 +1,303 @@
+//! This module provides functionality to link entities to each other using specialized components called "relationships". See the [`Relationship`] trait for more info.
+
+mod relationship_query;
+
+use crate::{
+    component::{Component, ComponentId},
+    entity::Entity,
+    world::{DeferredWorld, World},
+};
+use bevy_utils::tracing::warn;
+
+/// A [`Component`] on a "source" entity that stores a reference to a "target" entity, forming a relationship between the two.
+///
+/// A [`Relationship`] is always paired with a [`RelationshipTarget`], which lives on the target entity and stores the list
+/// of every source entity currently pointing at it. The [`RelationshipTarget`] is maintained automatically through
+/// [component hooks](crate::component::ComponentHooks): inserting, replacing or removing a [`Relationship`] updates the
+/// target's collection, and removing or despawning the target removes the [`Relationship`] from every source
+/// (or despawns the sources, see [`RelationshipTarget::LINKED_SPAWN`]).
+///
+/// The [`RelationshipTarget`] should be treated as read-only: mutate the relationship by inserting or removing
+/// the [`Relationship`] component on the source entity.
+///
+/// Both traits are usually implemented through the [`Component`] derive:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::relationship::RelationshipTarget;
+/// #[derive(Component)]
+/// #[relationship(relationship_target = Inventory)]
+/// struct StoredIn(Entity);
+///
+/// #[derive(Component)]
+/// #[relationship_target(relationship = StoredIn)]
+/// struct Inventory(Vec<Entity>);
+///
+/// let mut world = World::new();
+/// let chest = world.spawn_empty().id();
+/// let sword = world.spawn(StoredIn(chest)).id();
+/// world.flush();
+///
+/// assert_eq!(world.get::<Inventory>(chest).unwrap().collection(), &[sword]);
+///
+/// world.despawn(sword);
+/// world.flush();
+/// assert!(world.get::<Inventory>(chest).is_none());
+/// ```
+///
+/// When implementing [`Relationship`] by hand, [`Component::register_component_hooks`] must register
+/// [`Relationship::on_insert`] and [`Relationship::on_replace`] as the component's `on_insert` and `on_replace` hooks.
+pub trait Relationship: Component + Sized {
+    /// The [`Component`] added to the "target" entities of this [`Relationship`], which stores the list of
+    /// source entities pointing at them.
+    type RelationshipTarget: RelationshipTarget<Relationship = Self>;
+
+    /// Gets the [`Entity`] this relationship points to.
+    fn get(&self) -> Entity;
+
+    /// Creates this [`Relationship`] from the given `entity`.
+    fn from(entity: Entity) -> Self;
+
+    /// The `on_insert` component hook that keeps the [`RelationshipTarget`] of the new target in sync.
+    fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target = world.get::<Self>(entity).unwrap().get();
+        if target == entity {
+            warn!(
+                "The {}({target:?}) relationship on entity {entity:?} points to itself. The invalid {} relationship has been removed.",
+                std::any::type_name::<Self>(),
+                std::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        }
+        if world.get_entity(target).is_none() {
+            warn!(
+                "The {}({target:?}) relationship on entity {entity:?} relates to an entity that does not exist. The invalid {} relationship has been removed.",
+                std::any::type_name::<Self>(),
+                std::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        }
+        if let Some(mut relationship_target) = world.get_mut::<Self::RelationshipTarget>(target) {
+            relationship_target.collection_mut_risky().push(entity);
+        } else {
+            // Inserting the target component is a structural change, so it has to be deferred. Several sources
+            // may be queued against the same target before this runs, so re-check the world when applying.
+            world.commands().add(move |world: &mut World| {
+                if world.get::<Self>(entity).map(Self::get) != Some(target) {
+                    return;
+                }
+                let Some(mut target_entity) = world.get_entity_mut(target) else {
+                    return;
+                };
+                if let Some(mut relationship_target) =
+                    target_entity.get_mut::<Self::RelationshipTarget>()
+                {
+                    let collection = relationship_target.collection_mut_risky();
+                    if !collection.contains(&entity) {
+                        collection.push(entity);
+                    }
+                } else {
+                    target_entity.insert(Self::RelationshipTarget::from_collection_risky(vec![
+                        entity,
+                    ]));
+                }
+            });
+        }
+    }
+
+    /// The `on_replace` component hook that removes this source from the [`RelationshipTarget`] of its current target.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target = world.get::<Self>(entity).unwrap().get();
+        let Some(mut relationship_target) = world.get_mut::<Self::RelationshipTarget>(target)
+        else {
+            return;
+        };
+        let collection = relationship_target.collection_mut_risky();
+        collection.retain(|&source| source != entity);
+        if collection.is_empty() {
+            world.commands().add(move |world: &mut World| {
+                let Some(mut target_entity) = world.get_entity_mut(target) else {
+                    return;
+                };
+                if target_entity
+                    .get::<Self::RelationshipTarget>()
+                    .is_some_and(RelationshipTarget::is_empty)
+                {
+                    target_entity.remove::<Self::RelationshipTarget>();
+                }
+            });
+        }
+    }
+}
+
+/// A [`Component`] containing the collection of entities that relate to this [`Entity`] via the associated
+/// [`Relationship`] type. See the [`Relationship`] documentation for more information.
+///
+/// When implementing [`RelationshipTarget`] by hand, [`Component::register_component_hooks`] must register
+/// [`RelationshipTarget::on_replace`] as the component's `on_replace` hook.
+pub trait RelationshipTarget: Component + Sized {
+    /// If `true`, the source entities are despawned when this [`RelationshipTarget`] is removed from its entity
+    /// (including when that entity is despawned). Otherwise, only their [`Relationship`] component is removed.
+    const LINKED_SPAWN: bool;
+
+    /// The [`Relationship`] that populates this [`RelationshipTarget`] collection.
+    type Relationship: Relationship<RelationshipTarget = Self>;
+
+    /// Returns a reference to the stored source entities.
+    fn collection(&self) -> &Vec<Entity>;
+
+    /// Returns a mutable reference to the stored source entities.
+    ///
+    /// # Warning
+    /// This should generally not be called outside of the relationship hooks, as modifying the collection
+    /// directly will leave it out of sync with the [`Relationship`] components of the source entities.
+    fn collection_mut_risky(&mut self) -> &mut Vec<Entity>;
+
+    /// Creates a new [`RelationshipTarget`] from the given collection of source entities.
+    ///
+    /// # Warning
+    /// This should generally not be called outside of the relationship hooks, as the resulting component
+    /// will not be in sync with the [`Relationship`] components of the source entities.
+    fn from_collection_risky(collection: Vec<Entity>) -> Self;
+
+    /// Iterates the entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> std::iter::Copied<std::slice::Iter<'_, Entity>> {
+        self.collection().iter().copied()
+    }
+
+    /// Returns the number of entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this entity collection is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+
+    /// The `on_replace` component hook that cleans up the [`Relationship`] of every source entity.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let sources = world.get::<Self>(entity).unwrap().collection().clone();
+        let mut commands = world.commands();
+        for source in sources {
+            if let Some(mut source_commands) = commands.get_entity(source) {
+                if Self::LINKED_SPAWN {
+                    source_commands.despawn();
+                } else {
+                    source_commands.remove::<Self::Relationship>();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        entity::Entity,
+        prelude::{Component, World},
+        relationship::{Relationship, RelationshipTarget},
+    };
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Likes)]
+    struct LikedBy(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = LikedBy)]
+    struct Likes(Vec<Entity>);
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Contents)]
+    struct ContainedIn {
+        container: Entity,
+    }
+
+    #[derive(Component)]
+    #[relationship_target(relationship = ContainedIn, linked_spawn)]
+    struct Contents {
+        items: Vec<Entity>,
+    }
+
+    #[test]
+    fn relationship_target_is_kept_in_sync() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(LikedBy(a)).id();
+        let c = world.spawn(LikedBy(a)).id();
+        world.flush();
+
+        assert_eq!(world.get::<Likes>(a).unwrap().collection(), &[b, c]);
+
+        world.entity_mut(b).remove::<LikedBy>();
+        world.flush();
+        assert_eq!(world.get::<Likes>(a).unwrap().collection(), &[c]);
+
+        world.despawn(c);
+        world.flush();
+        assert!(world.get::<Likes>(a).is_none());
+    }
+
+    #[test]
+    fn replacing_relationship_moves_source() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn(LikedBy(a)).id();
+        world.flush();
+
+        world.entity_mut(c).insert(LikedBy(b));
+        world.flush();
+        assert!(world.get::<Likes>(a).is_none());
+        assert_eq!(world.get::<Likes>(b).unwrap().collection(), &[c]);
+    }
+
+    #[test]
+    fn despawning_target_removes_relationship() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(LikedBy(a)).id();
+        world.flush();
+
+        world.despawn(a);
+        world.flush();
+        assert!(world.get::<LikedBy>(b).is_none());
+    }
+
+    #[test]
+    fn linked_spawn_despawns_sources() {
+        let mut world = World::new();
+        let chest = world.spawn_empty().id();
+        let item = world.spawn(ContainedIn { container: chest }).id();
+        world.flush();
+        assert_eq!(world.get::<ContainedIn>(item).unwrap().get(), chest);
+
+        world.despawn(chest);
+        world.flush();
+        assert!(world.get_entity(item).is_none());
+    }
+
+    #[test]
+    fn invalid_relationships_are_removed() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        world.entity_mut(a).insert(LikedBy(a));
+        world.flush();
+        assert!(world.get::<LikedBy>(a).is_none());
+        assert!(world.get::<Likes>(a).is_none());
+
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+        let b = world.spawn(LikedBy(missing)).id();
+        world.flush();
+        assert!(world.get::<LikedBy>(b).is_none());
+    }
+}
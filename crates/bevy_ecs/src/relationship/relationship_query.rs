@@ -0,0 +1,141 @@
+use crate::{
+    entity::Entity,
+    query::{QueryData, QueryFilter, WorldQuery},
+    relationship::{Relationship, RelationshipTarget},
+    system::Query,
+};
+
+impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
+    /// If the given `entity` contains the `R` [`Relationship`] component, returns the
+    /// target entity of that relationship.
+    pub fn related<R: Relationship>(&'w self, entity: Entity) -> Option<Entity>
+    where
+        <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+    {
+        self.get(entity).map(R::get).ok()
+    }
+
+    /// If the given `entity` contains the `S` [`RelationshipTarget`] component, returns the
+    /// source entities stored on that component.
+    pub fn relationship_sources<S: RelationshipTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'w
+    where
+        <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+    {
+        self.get(entity)
+            .into_iter()
+            .flat_map(RelationshipTarget::iter)
+    }
+
+    /// Recursively walks up the tree defined by the given `R` [`Relationship`] until
+    /// there are no more related entities, returning the "root entity" of the relationship hierarchy.
+    ///
+    /// # Warning
+    ///
+    /// For relationship graphs that contain loops, this could loop infinitely.
+    pub fn root_ancestor<R: Relationship>(&'w self, entity: Entity) -> Entity
+    where
+        <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+    {
+        let mut current = entity;
+        while let Some(next) = self.related::<R>(current) {
+            current = next;
+        }
+        current
+    }
+
+    /// Iterates all "ancestors" of the given `entity` as defined by the `R` [`Relationship`],
+    /// starting with the direct target of `entity`.
+    ///
+    /// # Warning
+    ///
+    /// For relationship graphs that contain loops, this could loop infinitely.
+    pub fn iter_related_ancestors<R: Relationship>(
+        &'w self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'w
+    where
+        <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+    {
+        std::iter::successors(self.related::<R>(entity), move |&current| {
+            self.related::<R>(current)
+        })
+    }
+
+    /// Iterates all "descendants" of the given `entity` as defined by the `S` [`RelationshipTarget`],
+    /// in depth-first order.
+    ///
+    /// # Warning
+    ///
+    /// For relationship graphs that contain loops, this could loop infinitely.
+    pub fn iter_related_descendants<S: RelationshipTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'w
+    where
+        <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+    {
+        let mut stack: Vec<Entity> = self.relationship_sources::<S>(entity).collect();
+        stack.reverse();
+        std::iter::from_fn(move || {
+            let next = stack.pop()?;
+            let start = stack.len();
+            stack.extend(self.relationship_sources::<S>(next));
+            stack[start..].reverse();
+            Some(next)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        entity::Entity,
+        prelude::{Component, World},
+        system::{Query, SystemState},
+    };
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Minions)]
+    struct Boss(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = Boss)]
+    struct Minions(Vec<Entity>);
+
+    #[test]
+    fn relationship_queries() {
+        let mut world = World::new();
+        let root = world.spawn_empty().id();
+        let a = world.spawn(Boss(root)).id();
+        let b = world.spawn(Boss(a)).id();
+        let c = world.spawn(Boss(root)).id();
+        world.flush();
+
+        let mut state = SystemState::<(Query<&Boss>, Query<&Minions>)>::new(&mut world);
+        let (bosses, minions) = state.get(&world);
+
+        assert_eq!(bosses.related::<Boss>(b), Some(a));
+        assert_eq!(bosses.related::<Boss>(root), None);
+        assert_eq!(bosses.root_ancestor::<Boss>(b), root);
+        assert_eq!(
+            bosses.iter_related_ancestors::<Boss>(b).collect::<Vec<_>>(),
+            [a, root]
+        );
+        assert_eq!(
+            minions
+                .relationship_sources::<Minions>(root)
+                .collect::<Vec<_>>(),
+            [a, c]
+        );
+        assert_eq!(
+            minions
+                .iter_related_descendants::<Minions>(root)
+                .collect::<Vec<_>>(),
+            [a, b, c]
+        );
+    }
+}
@@ -1,16 +1,18 @@
 use bevy_utils::all_tuples;
+use std::marker::PhantomData;
 
 use crate::{
+    error::Error,
     schedule::{
         condition::{BoxedCondition, Condition},
         graph_utils::{Ambiguity, Dependency, DependencyKind, GraphInfo},
         set::{InternedSystemSet, IntoSystemSet, SystemSet},
         Chain,
     },
-    system::{BoxedSystem, IntoSystem, System},
+    system::{BoxedSystem, IntoFallibleSystem, IntoSystem, System},
 };
 
-fn new_condition<M>(condition: impl Condition<M>) -> BoxedCondition {
+pub(crate) fn new_condition<M>(condition: impl Condition<M>) -> BoxedCondition {
     let condition_system = IntoSystem::into_system(condition);
     assert!(
         condition_system.is_send(),
@@ -33,7 +35,15 @@ fn ambiguous_with(graph_info: &mut GraphInfo, set: InternedSystemSet) {
     }
 }
 
-impl<Marker, F> IntoSystemConfigs<Marker> for F
+/// Marker for systems returning `()`.
+#[doc(hidden)]
+pub struct Infallible;
+
+/// Marker for systems returning a [`Result`], see [`IntoFallibleSystem`].
+#[doc(hidden)]
+pub struct Fallible<E>(PhantomData<fn() -> E>);
+
+impl<Marker, F> IntoSystemConfigs<(Infallible, Marker)> for F
 where
     F: IntoSystem<(), (), Marker>,
 {
@@ -42,6 +52,16 @@ where
     }
 }
 
+impl<Marker, F, E> IntoSystemConfigs<(Fallible<E>, Marker)> for F
+where
+    F: IntoSystem<(), Result<(), E>, Marker>,
+    E: Into<Error> + 'static,
+{
+    fn into_configs(self) -> SystemConfigs {
+        SystemConfigs::new_system(Box::new(self.handle_errors()))
+    }
+}
+
 impl IntoSystemConfigs<()> for BoxedSystem<(), ()> {
     fn into_configs(self) -> SystemConfigs {
         SystemConfigs::new_system(self)
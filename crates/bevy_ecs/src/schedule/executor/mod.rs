@@ -1,10 +1,15 @@
 mod multi_threaded;
 mod simple;
 mod single_threaded;
+mod timings;
 
 pub use self::multi_threaded::{MainThreadExecutor, MultiThreadedExecutor};
 pub use self::simple::SimpleExecutor;
 pub use self::single_threaded::SingleThreadedExecutor;
+pub(super) use self::timings::SystemSpan;
+pub use self::timings::{SystemTiming, SystemTimingKind, SystemTimings};
+
+use std::sync::Arc;
 
 use fixedbitset::FixedBitSet;
 
@@ -48,6 +53,41 @@ pub enum ExecutorKind {
     MultiThreaded,
 }
 
+/// Specifies the order in which a [`Schedule`](super::Schedule) runs systems that are not ordered
+/// relative to each other.
+///
+/// Set through [`ScheduleBuildSettings::execution_order`](super::ScheduleBuildSettings::execution_order).
+#[derive(PartialEq, Eq, Default, Debug, Clone)]
+pub enum ExecutionOrder {
+    /// Systems run in whatever order the executor finds most efficient.
+    ///
+    /// With the [`MultiThreaded`](ExecutorKind::MultiThreaded) executor, two ambiguous systems
+    /// that access the same data can run in a different order every time the schedule runs, and
+    /// [`Commands`](crate::prelude::Commands) can reserve entities in a different order.
+    #[default]
+    Any,
+    /// Systems still run in parallel, but every pair of systems that could observe each other's
+    /// effects runs in the order of the schedule's topological sort.
+    ///
+    /// Two systems are ordered if they (or their run conditions) have conflicting data access, if
+    /// either of them is exclusive, or if both have [deferred](crate::system::System::has_deferred)
+    /// parameters. The latter makes entity reservation and the application of command buffers at
+    /// sync points happen in the same order every run, and on every machine that builds the same
+    /// schedule, which is what lockstep networking and replays need.
+    ///
+    /// This can reduce the amount of parallelism compared to [`Any`](ExecutionOrder::Any).
+    Deterministic,
+    /// Like [`Deterministic`](ExecutionOrder::Deterministic), but conflicting systems run in the
+    /// order they started in a recorded run, as returned by
+    /// [`Schedule::recorded_execution_order`](super::Schedule::recorded_execution_order).
+    ///
+    /// This can be used to reproduce an interleaving that was observed with
+    /// [`Any`](ExecutionOrder::Any). Systems missing from the recording (for example because a run
+    /// condition skipped them) and recorded positions that contradict the schedule's dependencies
+    /// fall back to the topological order.
+    Replay(Arc<[NodeId]>),
+}
+
 /// Holds systems and conditions of a [`Schedule`](super::Schedule) sorted in topological order
 /// (along with dependency information for `multi_threaded` execution).
 ///
@@ -79,6 +119,17 @@ pub struct SystemSchedule {
     ///
     /// If a set doesn't run because of its conditions, this is used to skip all systems in it.
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    /// Indexed by system node id.
+    /// When each system last started and finished running.
+    ///
+    /// Empty unless [`SystemTimings`] is enabled, in which case executors fill in the spans of the
+    /// systems they ran.
+    pub(super) system_spans: Vec<Option<SystemSpan>>,
+    /// The node ids of the systems that ran, in the order they started.
+    ///
+    /// `None` unless [`Schedule::record_execution_order`](super::Schedule::record_execution_order)
+    /// is enabled.
+    pub(super) recorded_order: Option<Vec<NodeId>>,
 }
 
 impl SystemSchedule {
@@ -94,6 +145,8 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            system_spans: Vec::new(),
+            recorded_order: None,
         }
     }
 }
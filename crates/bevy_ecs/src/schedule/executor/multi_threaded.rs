@@ -4,10 +4,10 @@ use std::{
 };
 
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
-use bevy_utils::default;
 use bevy_utils::syncunsafecell::SyncUnsafeCell;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::{info_span, Span};
+use bevy_utils::{default, Instant};
 use std::panic::AssertUnwindSafe;
 
 use concurrent_queue::ConcurrentQueue;
@@ -17,7 +17,9 @@ use crate::{
     archetype::ArchetypeComponentId,
     prelude::Resource,
     query::Access,
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    schedule::{
+        is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule, SystemSpan,
+    },
     system::BoxedSystem,
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
@@ -32,6 +34,10 @@ struct Environment<'env, 'sys> {
     systems: &'sys [SyncUnsafeCell<BoxedSystem>],
     conditions: SyncUnsafeCell<Conditions<'sys>>,
     world_cell: UnsafeWorldCell<'env>,
+    /// Whether system tasks should measure their [`SystemSpan`].
+    record_spans: bool,
+    /// Whether the order in which systems start should be recorded.
+    record_order: bool,
 }
 
 struct Conditions<'a> {
@@ -49,6 +55,8 @@ impl<'env, 'sys> Environment<'env, 'sys> {
     ) -> Self {
         Environment {
             executor,
+            record_spans: !schedule.system_spans.is_empty(),
+            record_order: schedule.recorded_order.is_some(),
             systems: SyncUnsafeCell::from_mut(schedule.systems.as_mut_slice()).as_slice_of_cells(),
             conditions: SyncUnsafeCell::new(Conditions {
                 system_conditions: &mut schedule.system_conditions,
@@ -77,6 +85,7 @@ struct SystemTaskMetadata {
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    span: Option<SystemSpan>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -123,6 +132,10 @@ pub struct ExecutorState {
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
     unapplied_systems: FixedBitSet,
+    /// Spans of the systems that have run, if [`SystemTimings`](super::SystemTimings) is enabled.
+    system_spans: Vec<Option<SystemSpan>>,
+    /// Systems that have started, in order, if the schedule records its execution order.
+    started_systems: Vec<usize>,
 }
 
 /// References to data required by the executor.
@@ -194,6 +207,8 @@ impl SystemExecutor for MultiThreadedExecutor {
             .num_dependencies_remaining
             .clone_from(&schedule.system_dependencies);
         state.ready_systems.clone_from(&self.starting_systems);
+        state.system_spans.clear();
+        state.system_spans.resize(schedule.system_spans.len(), None);
 
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
@@ -251,6 +266,16 @@ impl SystemExecutor for MultiThreadedExecutor {
             std::panic::resume_unwind(payload);
         }
 
+        schedule.system_spans.clone_from(&state.system_spans);
+        if let Some(order) = &mut schedule.recorded_order {
+            order.extend(
+                state
+                    .started_systems
+                    .drain(..)
+                    .map(|system_index| schedule.system_ids[system_index]),
+            );
+        }
+
         debug_assert!(state.ready_systems.is_clear());
         debug_assert!(state.running_systems.is_clear());
         state.active_access.clear();
@@ -270,12 +295,13 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         system_index: usize,
         res: Result<(), Box<dyn Any + Send>>,
         system: &BoxedSystem,
+        span: Option<SystemSpan>,
     ) {
         // tell the executor that the system finished
         self.environment
             .executor
             .system_completion
-            .push(SystemResult { system_index })
+            .push(SystemResult { system_index, span })
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
             eprintln!("Encountered a panic in system `{}`!", &*system.name());
@@ -349,6 +375,8 @@ impl ExecutorState {
             skipped_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
+            system_spans: Vec::new(),
+            started_systems: Vec::new(),
         }
     }
 
@@ -580,10 +608,14 @@ impl ExecutorState {
         let system = unsafe { &mut *context.environment.systems[system_index].get() };
         // Move the full context object into the new future.
         let context = *context;
+        if context.environment.record_order {
+            self.started_systems.push(system_index);
+        }
 
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            let span_start = context.environment.record_spans.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
@@ -596,7 +628,8 @@ impl ExecutorState {
                     );
                 };
             }));
-            context.system_completed(system_index, res, system);
+            let span = SystemSpan::until_now(span_start);
+            context.system_completed(system_index, res, system, span);
         };
 
         self.active_access
@@ -620,23 +653,30 @@ impl ExecutorState {
         let system = unsafe { &mut *context.environment.systems[system_index].get() };
         // Move the full context object into the new future.
         let context = *context;
+        if context.environment.record_order {
+            self.started_systems.push(system_index);
+        }
 
         if is_apply_deferred(system) {
             // TODO: avoid allocation
             let unapplied_systems = self.unapplied_systems.clone();
             self.unapplied_systems.clear();
             let task = async move {
+                let span_start = context.environment.record_spans.then(Instant::now);
                 let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                let span = SystemSpan::until_now(span_start);
+                context.system_completed(system_index, res, system, span);
             };
 
             context.scope.spawn_on_scope(task);
         } else {
             let task = async move {
+                let span_start = context.environment.record_spans.then(Instant::now);
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     __rust_begin_short_backtrace::run(&mut **system, world);
                 }));
-                context.system_completed(system_index, res, system);
+                let span = SystemSpan::until_now(span_start);
+                context.system_completed(system_index, res, system, span);
             };
 
             context.scope.spawn_on_scope(task);
@@ -647,7 +687,11 @@ impl ExecutorState {
     }
 
     fn finish_system_and_handle_dependents(&mut self, result: SystemResult) {
-        let SystemResult { system_index, .. } = result;
+        let SystemResult { system_index, span } = result;
+
+        if span.is_some() {
+            self.system_spans[system_index] = span;
+        }
 
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
@@ -1,11 +1,13 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
+use bevy_utils::Instant;
 use fixedbitset::FixedBitSet;
 use std::panic::AssertUnwindSafe;
 
 use crate::{
     schedule::{
         executor::is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule,
+        SystemSpan,
     },
     world::World,
 };
@@ -48,6 +50,7 @@ impl SystemExecutor for SimpleExecutor {
             self.completed_systems |= skipped_systems;
         }
 
+        let record_spans = !schedule.system_spans.is_empty();
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -94,6 +97,11 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
+            if let Some(order) = &mut schedule.recorded_order {
+                order.push(schedule.system_ids[system_index]);
+            }
+
+            let span_start = record_spans.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 __rust_begin_short_backtrace::run(&mut **system, world);
             }));
@@ -101,6 +109,9 @@ impl SystemExecutor for SimpleExecutor {
                 eprintln!("Encountered a panic in system `{}`!", &*system.name());
                 std::panic::resume_unwind(payload);
             }
+            if record_spans {
+                schedule.system_spans[system_index] = SystemSpan::until_now(span_start);
+            }
         }
 
         self.evaluated_sets.clear();
@@ -1,10 +1,13 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
+use bevy_utils::Instant;
 use fixedbitset::FixedBitSet;
 use std::panic::AssertUnwindSafe;
 
 use crate::{
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    schedule::{
+        is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule, SystemSpan,
+    },
     world::World,
 };
 
@@ -54,6 +57,7 @@ impl SystemExecutor for SingleThreadedExecutor {
             self.completed_systems |= skipped_systems;
         }
 
+        let record_spans = !schedule.system_spans.is_empty();
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -95,9 +99,16 @@ impl SystemExecutor for SingleThreadedExecutor {
                 continue;
             }
 
+            if let Some(order) = &mut schedule.recorded_order {
+                order.push(schedule.system_ids[system_index]);
+            }
+            let span_start = record_spans.then(Instant::now);
             let system = &mut schedule.systems[system_index];
             if is_apply_deferred(system) {
                 self.apply_deferred(schedule, world);
+                if record_spans {
+                    schedule.system_spans[system_index] = SystemSpan::until_now(span_start);
+                }
                 continue;
             }
 
@@ -117,6 +128,9 @@ impl SystemExecutor for SingleThreadedExecutor {
                 eprintln!("Encountered a panic in system `{}`!", &*system.name());
                 std::panic::resume_unwind(payload);
             }
+            if record_spans {
+                schedule.system_spans[system_index] = SystemSpan::until_now(span_start);
+            }
             self.unapplied_systems.insert(system_index);
         }
 
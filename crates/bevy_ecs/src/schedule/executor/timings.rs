@@ -0,0 +1,280 @@
+use std::sync::Arc;
+
+use bevy_utils::{get_short_name, Duration, HashMap, Instant};
+use petgraph::Direction::Outgoing;
+
+use crate::{
+    self as bevy_ecs,
+    schedule::{InternedScheduleLabel, NodeId, ScheduleGraph, SystemSchedule},
+    system::Resource,
+};
+
+/// When a system started and finished running.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SystemSpan {
+    pub(crate) start: Instant,
+    pub(crate) end: Instant,
+}
+
+impl SystemSpan {
+    /// Returns a span from `start` until now, if `start` is set.
+    pub(crate) fn until_now(start: Option<Instant>) -> Option<Self> {
+        start.map(|start| SystemSpan {
+            start,
+            end: Instant::now(),
+        })
+    }
+}
+
+/// Whether a [`SystemTiming`] measures a single system or a system set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SystemTimingKind {
+    /// The time a system took to run.
+    System,
+    /// The wall-clock time from the first system of a set starting until the last one finished.
+    Set,
+}
+
+/// A single wall-clock measurement recorded while [`SystemTimings`] is enabled.
+#[derive(Clone, Debug)]
+pub struct SystemTiming {
+    /// The schedule the system or system set ran in.
+    pub schedule: InternedScheduleLabel,
+    /// Whether this measures a system or a system set.
+    pub kind: SystemTimingKind,
+    /// The name of the system or system set.
+    ///
+    /// Names are shortened if the schedule's
+    /// [`ScheduleBuildSettings::use_shortnames`](crate::schedule::ScheduleBuildSettings::use_shortnames) is set.
+    pub name: Arc<str>,
+    /// How long the system or system set took to run.
+    pub duration: Duration,
+}
+
+/// Opt-in instrumentation of schedule executors.
+///
+/// While this resource exists in a [`World`](crate::world::World), every [`Schedule`](crate::schedule::Schedule)
+/// run on that world records how long each of its systems and named system sets took. Measurements
+/// accumulate until they are [drained](SystemTimings::drain).
+///
+/// Sync points inserted with [`apply_deferred`](crate::schedule::apply_deferred) are measured as
+/// systems too, since they are where commands are applied.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{SystemTimings, SystemTimingKind};
+/// fn my_system() {}
+///
+/// let mut world = World::new();
+/// world.init_resource::<SystemTimings>();
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(my_system);
+/// schedule.run(&mut world);
+///
+/// let mut timings = world.resource_mut::<SystemTimings>();
+/// let timing = timings.drain().next().unwrap();
+/// assert_eq!(timing.kind, SystemTimingKind::System);
+/// assert_eq!(&*timing.name, "my_system");
+/// ```
+#[derive(Resource, Default)]
+pub struct SystemTimings {
+    timings: Vec<SystemTiming>,
+    layouts: HashMap<InternedScheduleLabel, TimingLayout>,
+}
+
+/// Names and set membership of the systems of a built schedule.
+struct TimingLayout {
+    system_ids: Vec<NodeId>,
+    systems: Vec<Arc<str>>,
+    /// Named system sets, with the indices of the systems they contain.
+    sets: Vec<(Arc<str>, Vec<usize>)>,
+}
+
+impl TimingLayout {
+    fn new(graph: &ScheduleGraph, schedule: &SystemSchedule, use_shortnames: bool) -> Self {
+        let name = |name: String| -> Arc<str> {
+            if use_shortnames {
+                get_short_name(&name).into()
+            } else {
+                name.into()
+            }
+        };
+
+        let systems = schedule
+            .systems
+            .iter()
+            .map(|system| name(system.name().to_string()))
+            .collect();
+        let system_indices = schedule
+            .system_ids
+            .iter()
+            .enumerate()
+            .map(|(index, &id)| (id, index))
+            .collect::<HashMap<_, _>>();
+
+        let hierarchy = graph.hierarchy().graph();
+        let mut sets = Vec::new();
+        for (id, set, _) in graph.system_sets() {
+            if set.is_anonymous() || set.system_type().is_some() {
+                continue;
+            }
+            let mut members = Vec::new();
+            let mut stack = vec![id];
+            while let Some(node) = stack.pop() {
+                for member in hierarchy.neighbors_directed(node, Outgoing) {
+                    match member {
+                        NodeId::System(_) => members.extend(system_indices.get(&member)),
+                        NodeId::Set(_) => stack.push(member),
+                    }
+                }
+            }
+            members.sort_unstable();
+            members.dedup();
+            sets.push((name(format!("{set:?}")), members));
+        }
+
+        Self {
+            system_ids: schedule.system_ids.clone(),
+            systems,
+            sets,
+        }
+    }
+}
+
+impl SystemTimings {
+    /// Returns an iterator over the measurements recorded since the last [`drain`](Self::drain).
+    pub fn iter(&self) -> impl Iterator<Item = &SystemTiming> {
+        self.timings.iter()
+    }
+
+    /// Removes and returns all recorded measurements.
+    pub fn drain(&mut self) -> impl Iterator<Item = SystemTiming> + '_ {
+        self.timings.drain(..)
+    }
+
+    /// Records the spans the executor measured during the last run of `schedule`.
+    pub(crate) fn record(
+        &mut self,
+        label: InternedScheduleLabel,
+        graph: &ScheduleGraph,
+        schedule: &SystemSchedule,
+        use_shortnames: bool,
+    ) {
+        let layout = match self.layouts.get_mut(&label) {
+            Some(layout) if layout.system_ids == schedule.system_ids => layout,
+            _ => {
+                let layout = TimingLayout::new(graph, schedule, use_shortnames);
+                self.layouts.insert(label, layout);
+                self.layouts.get_mut(&label).unwrap()
+            }
+        };
+
+        for (index, span) in schedule.system_spans.iter().enumerate() {
+            if let Some(span) = span {
+                self.timings.push(SystemTiming {
+                    schedule: label,
+                    kind: SystemTimingKind::System,
+                    name: layout.systems[index].clone(),
+                    duration: span.end - span.start,
+                });
+            }
+        }
+
+        for (name, members) in &layout.sets {
+            let span = members
+                .iter()
+                .filter_map(|&index| schedule.system_spans[index])
+                .reduce(|a, b| SystemSpan {
+                    start: a.start.min(b.start),
+                    end: a.end.max(b.end),
+                });
+            if let Some(span) = span {
+                self.timings.push(SystemTiming {
+                    schedule: label,
+                    kind: SystemTimingKind::Set,
+                    name: name.clone(),
+                    duration: span.end - span.start,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread::sleep, time::Duration};
+
+    use crate::{
+        self as bevy_ecs,
+        prelude::*,
+        schedule::{ExecutorKind, SystemTimingKind, SystemTimings},
+    };
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestSet;
+
+    fn slow() {
+        sleep(Duration::from_millis(5));
+    }
+
+    fn fast() {}
+
+    fn run_instrumented(executor: ExecutorKind) {
+        let mut world = World::new();
+        world.init_resource::<SystemTimings>();
+
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(executor);
+        schedule.add_systems((slow, fast.after(slow)).in_set(TestSet));
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        let timings = world
+            .resource_mut::<SystemTimings>()
+            .drain()
+            .collect::<Vec<_>>();
+        let find = |kind, name: &str| {
+            timings
+                .iter()
+                .filter(|timing| timing.kind == kind && &*timing.name == name)
+                .collect::<Vec<_>>()
+        };
+        let slow = find(SystemTimingKind::System, "slow");
+        assert_eq!(slow.len(), 2);
+        assert!(slow[0].duration >= Duration::from_millis(5));
+        assert_eq!(find(SystemTimingKind::System, "fast").len(), 2);
+
+        let set = find(SystemTimingKind::Set, "TestSet");
+        assert_eq!(set.len(), 2);
+        assert!(set[0].duration >= slow[0].duration);
+    }
+
+    #[test]
+    fn single_threaded_timings() {
+        run_instrumented(ExecutorKind::SingleThreaded);
+    }
+
+    #[test]
+    fn simple_timings() {
+        run_instrumented(ExecutorKind::Simple);
+    }
+
+    #[test]
+    fn multi_threaded_timings() {
+        run_instrumented(ExecutorKind::MultiThreaded);
+    }
+
+    #[test]
+    fn timings_are_opt_in() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(fast);
+        schedule.run(&mut world);
+
+        world.init_resource::<SystemTimings>();
+        assert_eq!(world.resource::<SystemTimings>().iter().count(), 0);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<SystemTimings>().iter().count(), 1);
+    }
+}
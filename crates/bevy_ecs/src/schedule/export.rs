@@ -0,0 +1,582 @@
+//! Exporting built [`Schedule`]s to Graphviz DOT and JSON.
+
+use std::fmt::Write;
+
+use bevy_utils::{get_short_name, HashMap};
+use petgraph::Direction::Outgoing;
+
+use crate::{
+    component::Components,
+    schedule::{
+        NodeId, Schedule, ScheduleBuildError, ScheduleLabel, ScheduleNotInitialized, Schedules,
+    },
+    world::World,
+};
+
+/// What a [`ScheduleExportNode`] represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScheduleExportNodeKind {
+    /// A system added to the schedule.
+    System,
+    /// An [`apply_deferred`](super::apply_deferred) sync point inserted automatically while
+    /// building the schedule.
+    SyncPoint,
+    /// A system set.
+    Set,
+}
+
+impl ScheduleExportNodeKind {
+    /// Returns the name used for this kind in the JSON output.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ScheduleExportNodeKind::System => "system",
+            ScheduleExportNodeKind::SyncPoint => "sync_point",
+            ScheduleExportNodeKind::Set => "set",
+        }
+    }
+}
+
+/// A system, sync point or system set in a [`ScheduleExport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleExportNode {
+    /// The id of the node in the [`ScheduleGraph`](super::ScheduleGraph).
+    pub id: NodeId,
+    /// What this node represents.
+    pub kind: ScheduleExportNodeKind,
+    /// The name of the system or system set.
+    pub name: String,
+    /// The names of the run conditions attached directly to this node.
+    pub conditions: Vec<String>,
+}
+
+/// A pair of systems that were reported by ambiguity detection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleExportConflict {
+    /// The first conflicting system.
+    pub first: NodeId,
+    /// The second conflicting system.
+    pub second: NodeId,
+    /// The names of the components the systems conflict on.
+    ///
+    /// If this is empty, the systems conflict on [`World`] access.
+    pub components: Vec<String>,
+}
+
+/// A snapshot of a built [`Schedule`] that can be rendered to [Graphviz] DOT or JSON.
+///
+/// Everything in the export is sorted, so rendering the same schedule twice produces identical
+/// output. This makes it suitable for checking in and diffing scheduling changes.
+///
+/// [Graphviz]: https://graphviz.org
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleExport {
+    /// The label of the exported schedule.
+    pub label: String,
+    /// The systems, sync points and system sets in the schedule, sorted by [`NodeId`].
+    pub nodes: Vec<ScheduleExportNode>,
+    /// `(set, member)` edges of the hierarchy graph.
+    pub hierarchy: Vec<(NodeId, NodeId)>,
+    /// `(before, after)` edges that were configured on systems and system sets.
+    pub dependencies: Vec<(NodeId, NodeId)>,
+    /// The systems and sync points in the order they are handed to the executor.
+    pub execution_order: Vec<NodeId>,
+    /// `(before, after)` edges between systems and sync points that the executor respects, after
+    /// flattening system sets and inserting sync points.
+    pub execution_edges: Vec<(NodeId, NodeId)>,
+    /// The ambiguities found in the schedule.
+    pub conflicts: Vec<ScheduleExportConflict>,
+}
+
+impl ScheduleExport {
+    /// Exports `schedule`, using `components` to name the components of ambiguity conflicts.
+    ///
+    /// Returns [`ScheduleNotInitialized`] if the schedule has never been initialized or run.
+    pub fn new(
+        schedule: &Schedule,
+        components: &Components,
+    ) -> Result<Self, ScheduleNotInitialized> {
+        let graph = schedule.graph();
+        let shorten = schedule.get_build_settings().use_shortnames;
+        let name = |name: String| {
+            if shorten {
+                get_short_name(&name)
+            } else {
+                name
+            }
+        };
+
+        // systems and their conditions are moved into the executable schedule when it is built
+        let mut nodes = Vec::new();
+        let mut system_names = HashMap::new();
+        for (index, (id, system)) in schedule.systems()?.enumerate() {
+            let system_name = name(system.name().to_string());
+            system_names.insert(id, system_name.clone());
+            nodes.push(ScheduleExportNode {
+                id,
+                kind: if graph.is_auto_sync_point(id) {
+                    ScheduleExportNodeKind::SyncPoint
+                } else {
+                    ScheduleExportNodeKind::System
+                },
+                name: system_name,
+                conditions: schedule.executable().system_conditions[index]
+                    .iter()
+                    .map(|condition| name(condition.name().to_string()))
+                    .collect(),
+            });
+        }
+
+        let executable = schedule.executable();
+        let set_conditions = executable
+            .set_ids
+            .iter()
+            .zip(&executable.set_conditions)
+            .collect::<HashMap<_, _>>();
+        for (id, set, _) in graph.system_sets() {
+            let set_name = if set.is_anonymous() {
+                let members = graph
+                    .hierarchy()
+                    .graph()
+                    .neighbors_directed(id, Outgoing)
+                    .map(|member| match member {
+                        NodeId::System(_) => system_names[&member].clone(),
+                        NodeId::Set(_) => name(format!("{:?}", graph.set_at(member))),
+                    })
+                    .collect::<Vec<_>>();
+                format!("({})", members.join(", "))
+            } else {
+                name(format!("{set:?}"))
+            };
+            nodes.push(ScheduleExportNode {
+                id,
+                kind: ScheduleExportNodeKind::Set,
+                name: set_name,
+                conditions: set_conditions
+                    .get(&id)
+                    .into_iter()
+                    .flat_map(|conditions| conditions.iter())
+                    .map(|condition| name(condition.name().to_string()))
+                    .collect(),
+            });
+        }
+        nodes.sort_by_key(|node| node.id);
+
+        let mut hierarchy = graph
+            .hierarchy()
+            .graph()
+            .all_edges()
+            .map(|(set, member, _)| (set, member))
+            .collect::<Vec<_>>();
+        hierarchy.sort();
+
+        let mut dependencies = graph
+            .dependency()
+            .graph()
+            .all_edges()
+            .map(|(before, after, _)| (before, after))
+            .collect::<Vec<_>>();
+        dependencies.sort();
+
+        let execution_order = executable.system_ids.clone();
+        let mut execution_edges = executable
+            .system_dependents
+            .iter()
+            .enumerate()
+            .flat_map(|(index, dependents)| {
+                dependents.iter().map(move |&dependent| (index, dependent))
+            })
+            .map(|(before, after)| (execution_order[before], execution_order[after]))
+            .collect::<Vec<_>>();
+        execution_edges.sort();
+
+        let mut conflicts = graph
+            .conflicting_systems()
+            .iter()
+            .map(|(a, b, conflicts)| {
+                let (first, second) = if a <= b { (*a, *b) } else { (*b, *a) };
+                let mut components = conflicts
+                    .iter()
+                    .map(|&id| name(components.get_name(id).unwrap_or("<unknown>").to_string()))
+                    .collect::<Vec<_>>();
+                components.sort();
+                ScheduleExportConflict {
+                    first,
+                    second,
+                    components,
+                }
+            })
+            .collect::<Vec<_>>();
+        conflicts.sort_by_key(|conflict| (conflict.first, conflict.second));
+
+        Ok(Self {
+            label: format!("{:?}", schedule.label()),
+            nodes,
+            hierarchy,
+            dependencies,
+            execution_order,
+            execution_edges,
+            conflicts,
+        })
+    }
+
+    /// Renders the schedule as a [Graphviz] DOT graph.
+    ///
+    /// Systems are drawn as boxes, sync points as diamonds and system sets as rounded dashed boxes.
+    /// Run conditions are listed in the node labels. Solid edges show the order the executor
+    /// respects, dashed edges connect system sets to their members and red edges connect
+    /// ambiguous systems.
+    ///
+    /// [Graphviz]: https://graphviz.org
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph {} {{", dot_string(&self.label)).unwrap();
+        writeln!(dot, "  rankdir=LR;").unwrap();
+        writeln!(dot, "  node [shape=box];").unwrap();
+        for node in &self.nodes {
+            let mut label = node.name.clone();
+            for condition in &node.conditions {
+                write!(label, "\nif {condition}").unwrap();
+            }
+            let attributes = match node.kind {
+                ScheduleExportNodeKind::System => "",
+                ScheduleExportNodeKind::SyncPoint => ", shape=diamond",
+                ScheduleExportNodeKind::Set => ", style=\"rounded,dashed\"",
+            };
+            writeln!(
+                dot,
+                "  {} [label={}{attributes}];",
+                dot_string(&node_key(node.id)),
+                dot_string(&label),
+            )
+            .unwrap();
+        }
+        for &(set, member) in &self.hierarchy {
+            writeln!(
+                dot,
+                "  {} -> {} [style=dashed, color=gray];",
+                dot_string(&node_key(set)),
+                dot_string(&node_key(member)),
+            )
+            .unwrap();
+        }
+        for &(before, after) in &self.execution_edges {
+            writeln!(
+                dot,
+                "  {} -> {};",
+                dot_string(&node_key(before)),
+                dot_string(&node_key(after)),
+            )
+            .unwrap();
+        }
+        for conflict in &self.conflicts {
+            let label = if conflict.components.is_empty() {
+                "World".to_string()
+            } else {
+                conflict.components.join("\n")
+            };
+            writeln!(
+                dot,
+                "  {} -> {} [dir=none, color=red, constraint=false, label={}];",
+                dot_string(&node_key(conflict.first)),
+                dot_string(&node_key(conflict.second)),
+                dot_string(&label),
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the schedule as JSON.
+    ///
+    /// Nodes are identified by strings of the form `"system:<index>"` and `"set:<index>"`. Edges
+    /// are `[from, to]` pairs of such ids.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\n");
+        writeln!(json, "  \"label\": {},", json_string(&self.label)).unwrap();
+
+        json.push_str("  \"nodes\": [");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let conditions = node
+                .conditions
+                .iter()
+                .map(|condition| json_string(condition))
+                .collect::<Vec<_>>();
+            write!(
+                json,
+                "{}\n    {{\"id\": {}, \"kind\": {}, \"name\": {}, \"conditions\": [{}]}}",
+                if i == 0 { "" } else { "," },
+                json_string(&node_key(node.id)),
+                json_string(node.kind.as_str()),
+                json_string(&node.name),
+                conditions.join(", "),
+            )
+            .unwrap();
+        }
+        json.push_str(if self.nodes.is_empty() {
+            "],\n"
+        } else {
+            "\n  ],\n"
+        });
+
+        write_json_edges(&mut json, "hierarchy", &self.hierarchy);
+        write_json_edges(&mut json, "dependencies", &self.dependencies);
+
+        let execution_order = self
+            .execution_order
+            .iter()
+            .map(|&id| json_string(&node_key(id)))
+            .collect::<Vec<_>>();
+        writeln!(
+            json,
+            "  \"execution_order\": [{}],",
+            execution_order.join(", ")
+        )
+        .unwrap();
+
+        write_json_edges(&mut json, "execution_edges", &self.execution_edges);
+
+        json.push_str("  \"conflicts\": [");
+        for (i, conflict) in self.conflicts.iter().enumerate() {
+            let components = conflict
+                .components
+                .iter()
+                .map(|component| json_string(component))
+                .collect::<Vec<_>>();
+            write!(
+                json,
+                "{}\n    {{\"first\": {}, \"second\": {}, \"components\": [{}]}}",
+                if i == 0 { "" } else { "," },
+                json_string(&node_key(conflict.first)),
+                json_string(&node_key(conflict.second)),
+                components.join(", "),
+            )
+            .unwrap();
+        }
+        json.push_str(if self.conflicts.is_empty() {
+            "]\n"
+        } else {
+            "\n  ]\n"
+        });
+
+        json.push_str("}\n");
+        json
+    }
+}
+
+impl Schedules {
+    /// Exports every initialized schedule, sorted by label.
+    ///
+    /// Schedules that have never been initialized or run are skipped. Use
+    /// [`World::export_schedules`] to build every schedule before exporting it.
+    pub fn export(&self, components: &Components) -> Vec<ScheduleExport> {
+        let mut exports = self
+            .iter()
+            .filter_map(|(_, schedule)| ScheduleExport::new(schedule, components).ok())
+            .collect::<Vec<_>>();
+        exports.sort_by(|a, b| a.label.cmp(&b.label));
+        exports
+    }
+}
+
+impl World {
+    /// Initializes every schedule in the [`Schedules`] resource and exports it, sorted by label.
+    ///
+    /// Returns an empty list if the world has no [`Schedules`] resource.
+    pub fn export_schedules(&mut self) -> Result<Vec<ScheduleExport>, ScheduleBuildError> {
+        let Some(schedules) = self.get_resource::<Schedules>() else {
+            return Ok(Vec::new());
+        };
+        let labels = schedules
+            .iter()
+            .map(|(_, schedule)| schedule.label())
+            .collect::<Vec<_>>();
+        let mut exports = Vec::with_capacity(labels.len());
+        for label in labels {
+            exports.extend(self.export_schedule(label)?);
+        }
+        exports.sort_by(|a, b| a.label.cmp(&b.label));
+        Ok(exports)
+    }
+
+    /// Initializes the schedule with the given `label` and exports it.
+    ///
+    /// Returns `Ok(None)` if the schedule doesn't exist.
+    pub fn export_schedule(
+        &mut self,
+        label: impl ScheduleLabel,
+    ) -> Result<Option<ScheduleExport>, ScheduleBuildError> {
+        self.try_schedule_scope(label, |world, schedule| {
+            schedule.initialize(world)?;
+            Ok(ScheduleExport::new(schedule, world.components()).ok())
+        })
+        .unwrap_or(Ok(None))
+    }
+}
+
+fn node_key(id: NodeId) -> String {
+    match id {
+        NodeId::System(index) => format!("system:{index}"),
+        NodeId::Set(index) => format!("set:{index}"),
+    }
+}
+
+fn write_json_edges(json: &mut String, key: &str, edges: &[(NodeId, NodeId)]) {
+    let edges = edges
+        .iter()
+        .map(|&(from, to)| {
+            format!(
+                "[{}, {}]",
+                json_string(&node_key(from)),
+                json_string(&node_key(to))
+            )
+        })
+        .collect::<Vec<_>>();
+    writeln!(json, "  \"{key}\": [{}],", edges.join(", ")).unwrap();
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn dot_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        prelude::*,
+        schedule::{ScheduleExport, ScheduleExportNodeKind, ScheduleLabel},
+    };
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestSchedule;
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestSet;
+
+    #[derive(Component)]
+    struct A;
+
+    fn spawn(mut commands: Commands) {
+        commands.spawn(A);
+    }
+
+    fn read(_query: Query<&A>) {}
+
+    fn write_a(_query: Query<&mut A>) {}
+
+    fn write_b(_query: Query<&mut A>) {}
+
+    fn condition() -> bool {
+        true
+    }
+
+    fn export(schedule: Schedule) -> ScheduleExport {
+        let mut world = World::new();
+        world.add_schedule(schedule);
+        world.export_schedule(TestSchedule).unwrap().unwrap()
+    }
+
+    #[test]
+    fn export_sets_conditions_and_sync_points() {
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.configure_sets(TestSet.run_if(condition));
+        schedule.add_systems((spawn, read.run_if(condition).after(spawn)).in_set(TestSet));
+        let export = export(schedule);
+
+        let find = |name: &str| {
+            export
+                .nodes
+                .iter()
+                .find(|node| node.name == name)
+                .unwrap_or_else(|| panic!("missing node {name}"))
+        };
+        let read_node = find("read");
+        assert_eq!(read_node.kind, ScheduleExportNodeKind::System);
+        assert_eq!(read_node.conditions, vec!["condition".to_string()]);
+        let set_node = find("TestSet");
+        assert_eq!(set_node.kind, ScheduleExportNodeKind::Set);
+        assert_eq!(set_node.conditions, vec!["condition".to_string()]);
+        assert!(export.hierarchy.contains(&(set_node.id, read_node.id)));
+
+        let sync_point = export
+            .nodes
+            .iter()
+            .find(|node| node.kind == ScheduleExportNodeKind::SyncPoint)
+            .expect("an apply_deferred sync point should be inserted after `spawn`");
+        let spawn_node = find("spawn");
+        assert!(export
+            .execution_edges
+            .contains(&(spawn_node.id, sync_point.id)));
+        assert!(export
+            .execution_edges
+            .contains(&(sync_point.id, read_node.id)));
+        assert_eq!(
+            export.execution_order,
+            vec![spawn_node.id, sync_point.id, read_node.id]
+        );
+    }
+
+    #[test]
+    fn export_conflicts() {
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems((write_a, write_b));
+        let export = export(schedule);
+
+        assert_eq!(export.conflicts.len(), 1);
+        assert!(export.conflicts[0].components[0].ends_with('A'));
+        assert!(export.to_dot().contains("color=red"));
+    }
+
+    #[test]
+    fn export_is_stable() {
+        let build = || {
+            let mut schedule = Schedule::new(TestSchedule);
+            schedule.add_systems((spawn, read.after(spawn), (write_a, write_b).in_set(TestSet)));
+            export(schedule)
+        };
+        let (a, b) = (build(), build());
+        assert_eq!(a.to_json(), b.to_json());
+        assert_eq!(a.to_dot(), b.to_dot());
+
+        let json = a.to_json();
+        assert!(json.starts_with("{\n  \"label\": \"TestSchedule\",\n"));
+        assert!(json.contains("\"kind\": \"sync_point\""));
+    }
+
+    #[test]
+    fn uninitialized_schedule_is_not_exported() {
+        let world = World::new();
+        let schedule = Schedule::new(TestSchedule);
+        assert!(ScheduleExport::new(&schedule, world.components()).is_err());
+    }
+}
@@ -3,6 +3,7 @@
 mod condition;
 mod config;
 mod executor;
+mod export;
 mod graph_utils;
 #[allow(clippy::module_inception)]
 mod schedule;
@@ -12,6 +13,7 @@ mod stepping;
 pub use self::condition::*;
 pub use self::config::*;
 pub use self::executor::*;
+pub use self::export::*;
 use self::graph_utils::*;
 pub use self::schedule::*;
 pub use self::set::*;
@@ -716,6 +718,135 @@ mod tests {
         }
     }
 
+    mod execution_order {
+        use std::sync::Arc;
+
+        use super::*;
+        use crate::system::Commands;
+
+        fn deterministic_schedule(order: ExecutionOrder) -> Schedule {
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+            schedule.set_build_settings(ScheduleBuildSettings {
+                execution_order: order,
+                ..Default::default()
+            });
+            schedule
+        }
+
+        #[test]
+        fn deterministic_order_is_stable() {
+            let run = || {
+                let mut world = World::default();
+                world.init_resource::<SystemOrder>();
+                let mut schedule = deterministic_schedule(ExecutionOrder::Deterministic);
+                for tag in 0..8 {
+                    schedule.add_systems(make_function_system(tag));
+                }
+                for _ in 0..10 {
+                    schedule.run(&mut world);
+                }
+                world.remove_resource::<SystemOrder>().unwrap().0
+            };
+
+            let expected = run();
+            assert_eq!(expected.len(), 80);
+            assert_eq!(expected[..8], expected[8..16]);
+            for _ in 0..10 {
+                assert_eq!(run(), expected);
+            }
+        }
+
+        #[test]
+        fn only_observable_pairs_are_ordered() {
+            #[derive(Resource)]
+            struct X;
+
+            fn read_a(_x: Res<X>) {}
+            fn read_b(_x: Res<X>) {}
+            fn spawn_a(mut commands: Commands) {
+                commands.spawn_empty();
+            }
+            fn spawn_b(mut commands: Commands) {
+                commands.spawn_empty();
+            }
+
+            let mut world = World::default();
+            world.insert_resource(X);
+
+            let mut schedule = deterministic_schedule(ExecutionOrder::Deterministic);
+            schedule.add_systems((read_a, read_b));
+            schedule.initialize(&mut world).unwrap();
+            assert_eq!(schedule.executable().system_dependencies, vec![0, 0]);
+
+            let mut schedule = deterministic_schedule(ExecutionOrder::Deterministic);
+            schedule.add_systems((spawn_a, spawn_b));
+            schedule.initialize(&mut world).unwrap();
+            assert_eq!(schedule.executable().system_dependencies, vec![0, 1]);
+        }
+
+        #[test]
+        fn record_and_replay() {
+            let mut world = World::default();
+            world.init_resource::<SystemOrder>();
+
+            let mut schedule = deterministic_schedule(ExecutionOrder::Any);
+            schedule.record_execution_order(true);
+            for tag in 0..4 {
+                schedule.add_systems(make_function_system(tag));
+            }
+            assert!(schedule.recorded_execution_order().is_none());
+            schedule.run(&mut world);
+
+            let recorded = schedule.recorded_execution_order().unwrap().to_vec();
+            assert_eq!(recorded.len(), 4);
+            let observed = std::mem::take(&mut world.resource_mut::<SystemOrder>().0);
+
+            // replay the opposite of what was observed
+            let reversed: Arc<[NodeId]> = recorded.iter().rev().copied().collect();
+            schedule.set_build_settings(ScheduleBuildSettings {
+                execution_order: ExecutionOrder::Replay(reversed.clone()),
+                ..Default::default()
+            });
+            for _ in 0..5 {
+                world.resource_mut::<SystemOrder>().0.clear();
+                schedule.run(&mut world);
+                let replayed = &world.resource::<SystemOrder>().0;
+                assert!(replayed.iter().eq(observed.iter().rev()));
+                assert_eq!(schedule.recorded_execution_order().unwrap(), &*reversed);
+            }
+
+            schedule.record_execution_order(false);
+            schedule.run(&mut world);
+            assert!(schedule.recorded_execution_order().is_none());
+        }
+
+        #[test]
+        fn replay_respects_dependencies() {
+            let mut world = World::default();
+            world.init_resource::<SystemOrder>();
+
+            let mut schedule = deterministic_schedule(ExecutionOrder::Any);
+            schedule.record_execution_order(true);
+            schedule.add_systems((make_function_system(0), make_function_system(1)).chain());
+            schedule.run(&mut world);
+
+            let reversed: Arc<[NodeId]> = schedule
+                .recorded_execution_order()
+                .unwrap()
+                .iter()
+                .rev()
+                .copied()
+                .collect();
+            schedule.set_build_settings(ScheduleBuildSettings {
+                execution_order: ExecutionOrder::Replay(reversed),
+                ..Default::default()
+            });
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<SystemOrder>().0, vec![0, 1, 0, 1]);
+        }
+    }
+
     mod system_ambiguity {
         use std::collections::BTreeSet;
 
@@ -1111,7 +1242,7 @@ mod tests {
                 let mut schedule = Schedule::new(TestSchedule);
                 schedule
                     .set_executor_kind($executor)
-                    .add_systems(|| panic!("Executor ignored Stepping"));
+                    .add_systems(|| -> () { panic!("Executor ignored Stepping") });
 
                 // Add our schedule to stepping & and enable stepping; this should
                 // prevent any systems in the schedule from running
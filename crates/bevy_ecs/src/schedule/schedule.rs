@@ -1,5 +1,6 @@
 use std::{
-    collections::BTreeSet,
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap},
     fmt::{Debug, Write},
 };
 
@@ -177,6 +178,49 @@ impl Schedules {
         self
     }
 
+    /// Removes every system in `set` from the [`Schedule`] matching the provided [`ScheduleLabel`],
+    /// and returns how many systems were removed.
+    ///
+    /// See [`Schedule::remove_systems`].
+    pub fn remove_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M>,
+    ) -> usize {
+        self.get_mut(schedule)
+            .map_or(0, |schedule| schedule.remove_systems(set))
+    }
+
+    /// Removes `set` and every system in it from the [`Schedule`] matching the provided
+    /// [`ScheduleLabel`], and returns how many systems were removed.
+    ///
+    /// See [`Schedule::remove_set`].
+    pub fn remove_set<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M>,
+    ) -> usize {
+        self.get_mut(schedule)
+            .map_or(0, |schedule| schedule.remove_set(set))
+    }
+
+    /// Replaces the only system in `set` with `system` in the [`Schedule`] matching the provided
+    /// [`ScheduleLabel`].
+    ///
+    /// See [`Schedule::replace_system`].
+    pub fn replace_system<M1, M2>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M1>,
+        system: impl IntoSystem<(), (), M2>,
+    ) -> Result<(), ScheduleEditError> {
+        let label = schedule.intern();
+        match self.get_mut(label) {
+            Some(schedule) => schedule.replace_system(set, system),
+            None => Err(ScheduleEditError::ScheduleNotFound(format!("{label:?}"))),
+        }
+    }
+
     /// Suppress warnings and errors that would result from systems in these sets having ambiguities
     /// (conflicting access but indeterminate order) with systems in `set`.
     ///
@@ -263,6 +307,7 @@ pub struct Schedule {
     executable: SystemSchedule,
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
+    record_execution_order: bool,
 }
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
@@ -287,6 +332,7 @@ impl Schedule {
             executable: SystemSchedule::new(),
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
+            record_execution_order: false,
         }
     }
 
@@ -339,9 +385,116 @@ impl Schedule {
         self
     }
 
+    /// Removes every system in `set` from the schedule, including the systems of nested sets, and
+    /// returns how many systems were removed.
+    ///
+    /// Passing a system function removes every instance of that function. The removed systems are
+    /// dropped along with their state and run conditions. Systems that were ordered relative to
+    /// a removed system keep their relative order, and `set` keeps its configuration, so systems
+    /// added to it later are ordered and run conditions are applied the same way.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// fn spawn_enemies() {}
+    ///
+    /// let mut schedule = Schedule::default();
+    /// schedule.add_systems(spawn_enemies);
+    /// assert_eq!(schedule.remove_systems(spawn_enemies), 1);
+    /// assert_eq!(schedule.systems_len(), 0);
+    /// ```
+    pub fn remove_systems<M>(&mut self, set: impl IntoSystemSet<M>) -> usize {
+        let systems = self.graph.systems_in_set(set.into_system_set().intern());
+        if !systems.is_empty() {
+            self.reclaim_systems();
+            for &id in &systems {
+                self.graph.remove_system(id);
+            }
+        }
+        systems.len()
+    }
+
+    /// Removes `set` and every system in it from the schedule, and returns how many systems were
+    /// removed.
+    ///
+    /// Unlike [`Schedule::remove_systems`], this also removes the run conditions and ordering
+    /// constraints of the set itself. Sets nested in `set` are kept, but their systems are
+    /// removed.
+    pub fn remove_set<M>(&mut self, set: impl IntoSystemSet<M>) -> usize {
+        let set = set.into_system_set().intern();
+        if !self.graph.system_set_ids.contains_key(&set) {
+            return 0;
+        }
+
+        self.reclaim_systems();
+        let systems = self.graph.systems_in_set(set);
+        for &id in &systems {
+            self.graph.remove_system(id);
+        }
+        self.graph.remove_set(set);
+        systems.len()
+    }
+
+    /// Replaces the only system in `set` with `system`.
+    ///
+    /// The new system takes the place of the old one: it keeps its run conditions, the sets it
+    /// was in and its ordering constraints, including those placed on the old system's function.
+    /// It is moved from the old system's [default sets](System::default_system_sets) to its own,
+    /// so it is referred to by its own function rather than the old one.
+    /// The old system is dropped along with its state.
+    ///
+    /// Returns an error if `set` doesn't contain exactly one system.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Gravity(f32);
+    ///
+    /// fn old_gravity(mut gravity: ResMut<Gravity>) { gravity.0 = -9.8; }
+    /// fn new_gravity(mut gravity: ResMut<Gravity>) { gravity.0 = -1.6; }
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(Gravity(0.0));
+    /// let mut schedule = Schedule::default();
+    /// schedule.add_systems(old_gravity);
+    /// schedule.run(&mut world);
+    ///
+    /// schedule.replace_system(old_gravity, new_gravity).unwrap();
+    /// schedule.run(&mut world);
+    /// assert_eq!(world.resource::<Gravity>().0, -1.6);
+    /// ```
+    pub fn replace_system<M1, M2>(
+        &mut self,
+        set: impl IntoSystemSet<M1>,
+        system: impl IntoSystem<(), (), M2>,
+    ) -> Result<(), ScheduleEditError> {
+        let set = set.into_system_set();
+        let systems = self.graph.systems_in_set(set.intern());
+        let [id] = systems[..] else {
+            return Err(if self.graph.system_set_ids.contains_key(&set.intern()) {
+                ScheduleEditError::NotASingleSystem(format!("{set:?}"), systems.len())
+            } else {
+                ScheduleEditError::SetNotFound(format!("{set:?}"))
+            });
+        };
+
+        self.reclaim_systems();
+        self.graph
+            .replace_system(id, Box::new(IntoSystem::into_system(system)));
+        Ok(())
+    }
+
+    /// Moves all systems back into the graph so it can be edited, and marks the executable
+    /// schedule for rebuilding.
+    fn reclaim_systems(&mut self) {
+        self.graph.reclaim_systems(&mut self.executable);
+        self.graph.changed = true;
+        self.executor_initialized = false;
+    }
+
     /// Changes miscellaneous build settings.
     pub fn set_build_settings(&mut self, settings: ScheduleBuildSettings) -> &mut Self {
         self.graph.settings = settings;
+        self.graph.changed = true;
         self
     }
 
@@ -364,6 +517,21 @@ impl Schedule {
         self
     }
 
+    /// Set whether the schedule records the order in which its systems start running.
+    ///
+    /// The order of the last run is available through [`Schedule::recorded_execution_order`] and
+    /// can be replayed with [`ExecutionOrder::Replay`].
+    pub fn record_execution_order(&mut self, record: bool) -> &mut Self {
+        self.record_execution_order = record;
+        self
+    }
+
+    /// Returns the systems that ran during the last run of the schedule, in the order they
+    /// started, if [recording](Schedule::record_execution_order) was enabled for that run.
+    pub fn recorded_execution_order(&self) -> Option<&[NodeId]> {
+        self.executable.recorded_order.as_deref()
+    }
+
     /// Set whether the schedule applies deferred system buffers on final time or not. This is a catch-all
     /// in case a system uses commands but was not explicitly ordered before an instance of
     /// [`apply_deferred`]. By default this
@@ -382,6 +550,22 @@ impl Schedule {
         self.initialize(world)
             .unwrap_or_else(|e| panic!("Error when initializing schedule {:?}: {e}", self.label));
 
+        if self.record_execution_order {
+            self.executable
+                .recorded_order
+                .get_or_insert_with(Vec::new)
+                .clear();
+        } else {
+            self.executable.recorded_order = None;
+        }
+
+        let record_timings = world.contains_resource::<SystemTimings>();
+        self.executable.system_spans.clear();
+        if record_timings {
+            let system_count = self.executable.systems.len();
+            self.executable.system_spans.resize(system_count, None);
+        }
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
         self.executor.run(&mut self.executable, world, None);
 
@@ -395,6 +579,17 @@ impl Schedule {
             self.executor
                 .run(&mut self.executable, world, skip_systems.as_ref());
         }
+
+        if record_timings {
+            if let Some(mut timings) = world.get_resource_mut::<SystemTimings>() {
+                timings.record(
+                    self.label,
+                    &self.graph,
+                    &self.executable,
+                    self.graph.settings.use_shortnames,
+                );
+            }
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -502,7 +697,7 @@ impl Schedule {
     /// Returns the number of systems in this schedule.
     pub fn systems_len(&self) -> usize {
         if !self.executor_initialized {
-            self.graph.systems.len()
+            self.graph.systems().count()
         } else {
             self.executable.systems.len()
         }
@@ -599,7 +794,7 @@ pub struct ScheduleGraph {
     system_set_conditions: Vec<Vec<BoxedCondition>>,
     /// Map from system set to node id
     system_set_ids: HashMap<InternedSystemSet, NodeId>,
-    /// Systems that have not been initialized yet; for system sets, we store the index of the first uninitialized condition
+    /// Systems and system sets that have not been initialized yet, along with the index of their first uninitialized condition
     /// (all the conditions after that index still need to be initialized)
     uninit: Vec<(NodeId, usize)>,
     /// Directed acyclic graph of the hierarchy (which systems/sets are children of which sets)
@@ -727,6 +922,12 @@ impl ScheduleGraph {
         &self.conflicting_systems
     }
 
+    /// Returns `true` if the node is an [`apply_deferred`] sync point that was automatically
+    /// inserted while building the schedule.
+    pub fn is_auto_sync_point(&self, id: NodeId) -> bool {
+        id.is_system() && self.auto_sync_node_ids.values().any(|&sync| sync == id)
+    }
+
     fn process_config<T: ProcessNodeConfig>(
         &mut self,
         config: NodeConfig<T>,
@@ -1050,7 +1251,7 @@ impl ScheduleGraph {
             match id {
                 NodeId::System(index) => {
                     self.systems[index].get_mut().unwrap().initialize(world);
-                    for condition in &mut self.system_conditions[index] {
+                    for condition in self.system_conditions[index].iter_mut().skip(i) {
                         condition.initialize(world);
                     }
                 }
@@ -1134,6 +1335,11 @@ impl ScheduleGraph {
         self.optionally_check_conflicts(&conflicting_systems, components, schedule_label)?;
         self.conflicting_systems = conflicting_systems;
 
+        // order conflicting systems (after reporting them, so ambiguity detection still works)
+        if self.settings.execution_order != ExecutionOrder::Any {
+            self.order_conflicting_systems(&mut dependency_flattened_dag, &set_systems);
+        }
+
         // build the schedule
         Ok(self.build_schedule_inner(dependency_flattened_dag, hier_results.reachable))
     }
@@ -1379,6 +1585,95 @@ impl ScheduleGraph {
         conflicting_systems
     }
 
+    /// Replaces the topological sort of `dependency_flattened_dag` with the order selected by
+    /// [`ScheduleBuildSettings::execution_order`] and adds an edge between every pair of systems
+    /// whose relative order could be observed, so that they always run in that order.
+    fn order_conflicting_systems(
+        &self,
+        dependency_flattened_dag: &mut Dag,
+        set_systems: &HashMap<NodeId, Vec<NodeId>>,
+    ) {
+        let topsort = &dependency_flattened_dag.topsort;
+        let graph = &mut dependency_flattened_dag.graph;
+
+        // rank systems by their recorded position, then by their position in the topsort
+        let mut rank: HashMap<NodeId, (usize, usize)> = topsort
+            .iter()
+            .enumerate()
+            .map(|(i, &id)| (id, (usize::MAX, i)))
+            .collect();
+        if let ExecutionOrder::Replay(recorded) = &self.settings.execution_order {
+            for (position, id) in recorded.iter().enumerate() {
+                if let Some((recorded_position, _)) = rank.get_mut(id) {
+                    *recorded_position = (*recorded_position).min(position);
+                }
+            }
+        }
+
+        // topsort again, picking the lowest ranked system whenever there's a choice
+        let mut remaining: HashMap<NodeId, usize> = topsort
+            .iter()
+            .map(|&id| (id, graph.neighbors_directed(id, Incoming).count()))
+            .collect();
+        let mut ready: BinaryHeap<_> = remaining
+            .iter()
+            .filter(|&(_, &count)| count == 0)
+            .map(|(&id, _)| Reverse((rank[&id], id)))
+            .collect();
+        let mut order = Vec::with_capacity(topsort.len());
+        while let Some(Reverse((_, id))) = ready.pop() {
+            order.push(id);
+            for dependent in graph.neighbors_directed(id, Outgoing) {
+                let count = remaining.get_mut(&dependent).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    ready.push(Reverse((rank[&dependent], dependent)));
+                }
+            }
+        }
+
+        // combine the access of each system with the access of the conditions that guard it
+        let mut access = order
+            .iter()
+            .map(|&id| {
+                let mut access = self.systems[id.index()]
+                    .get()
+                    .unwrap()
+                    .component_access()
+                    .clone();
+                for condition in &self.system_conditions[id.index()] {
+                    access.extend(condition.component_access());
+                }
+                (id, access)
+            })
+            .collect::<HashMap<_, _>>();
+        for (set, systems) in set_systems {
+            for condition in &self.system_set_conditions[set.index()] {
+                for system in systems {
+                    if let Some(access) = access.get_mut(system) {
+                        access.extend(condition.component_access());
+                    }
+                }
+            }
+        }
+
+        for (i, &a) in order.iter().enumerate() {
+            let system_a = self.systems[a.index()].get().unwrap();
+            for &b in &order[i + 1..] {
+                let system_b = self.systems[b.index()].get().unwrap();
+                let conflicting = system_a.is_exclusive()
+                    || system_b.is_exclusive()
+                    || (system_a.has_deferred() && system_b.has_deferred())
+                    || !access[&a].is_compatible(&access[&b]);
+                if conflicting {
+                    graph.add_edge(a, b, ());
+                }
+            }
+        }
+
+        dependency_flattened_dag.topsort = order;
+    }
+
     fn build_schedule_inner(
         &self,
         dependency_flattened_dag: Dag,
@@ -1476,6 +1771,8 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            system_spans: Vec::new(),
+            recorded_order: None,
         }
     }
 
@@ -1491,7 +1788,27 @@ impl ScheduleGraph {
             return Err(ScheduleBuildError::Uninitialized);
         }
 
-        // move systems out of old schedule
+        self.reclaim_systems(schedule);
+        *schedule = self.build_schedule(components, schedule_label, ignored_ambiguities)?;
+
+        // move systems into new schedule
+        for &id in &schedule.system_ids {
+            let system = self.systems[id.index()].inner.take().unwrap();
+            let conditions = std::mem::take(&mut self.system_conditions[id.index()]);
+            schedule.systems.push(system);
+            schedule.system_conditions.push(conditions);
+        }
+
+        for &id in &schedule.set_ids {
+            let conditions = std::mem::take(&mut self.system_set_conditions[id.index()]);
+            schedule.set_conditions.push(conditions);
+        }
+
+        Ok(())
+    }
+
+    /// Moves all systems and conditions out of `schedule` back into the graph.
+    fn reclaim_systems(&mut self, schedule: &mut SystemSchedule) {
         for ((id, system), conditions) in schedule
             .system_ids
             .drain(..)
@@ -1509,23 +1826,136 @@ impl ScheduleGraph {
         {
             self.system_set_conditions[id.index()] = conditions;
         }
+    }
 
-        *schedule = self.build_schedule(components, schedule_label, ignored_ambiguities)?;
+    /// Returns the ids of all systems in `set`, including the systems of nested sets.
+    fn systems_in_set(&self, set: InternedSystemSet) -> Vec<NodeId> {
+        let Some(&set_id) = self.system_set_ids.get(&set) else {
+            return Vec::new();
+        };
 
-        // move systems into new schedule
-        for &id in &schedule.system_ids {
-            let system = self.systems[id.index()].inner.take().unwrap();
-            let conditions = std::mem::take(&mut self.system_conditions[id.index()]);
-            schedule.systems.push(system);
-            schedule.system_conditions.push(conditions);
+        let mut systems = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![set_id];
+        while let Some(id) = stack.pop() {
+            for child in self.hierarchy.graph.neighbors_directed(id, Outgoing) {
+                if !visited.insert(child) {
+                    continue;
+                }
+                match child {
+                    NodeId::System(_) => systems.push(child),
+                    NodeId::Set(_) => stack.push(child),
+                }
+            }
         }
+        systems.sort();
+        systems
+    }
 
-        for &id in &schedule.set_ids {
-            let conditions = std::mem::take(&mut self.system_set_conditions[id.index()]);
-            schedule.set_conditions.push(conditions);
+    /// Removes a system or system set from the graphs.
+    ///
+    /// The predecessors and parents of the node are connected to its successors and children, so
+    /// the remaining nodes keep their relative order and hierarchy.
+    fn remove_node(&mut self, id: NodeId) {
+        for graph in [&mut self.dependency.graph, &mut self.hierarchy.graph] {
+            let incoming = graph.neighbors_directed(id, Incoming).collect::<Vec<_>>();
+            let outgoing = graph.neighbors_directed(id, Outgoing).collect::<Vec<_>>();
+            for &a in &incoming {
+                for &b in &outgoing {
+                    graph.add_edge(a, b, ());
+                }
+            }
+            graph.remove_node(id);
         }
 
-        Ok(())
+        // a bridged edge only skips the sync point if both edges it replaces did
+        let incoming = self.no_sync_edges.iter().filter(|&&(_, b)| b == id);
+        let outgoing = self.no_sync_edges.iter().filter(|&&(a, _)| a == id);
+        let bridged = incoming
+            .flat_map(|&(a, _)| outgoing.clone().map(move |&(_, b)| (a, b)))
+            .collect::<Vec<_>>();
+        self.no_sync_edges.retain(|&(a, b)| a != id && b != id);
+        self.no_sync_edges.extend(bridged);
+
+        self.ambiguous_with.remove_node(id);
+        self.ambiguous_with_all.remove(&id);
+        self.uninit.retain(|&(uninit_id, _)| uninit_id != id);
+        self.changed = true;
+    }
+
+    /// Removes a system, dropping it along with its conditions.
+    fn remove_system(&mut self, id: NodeId) {
+        self.remove_node(id);
+        self.systems[id.index()].inner = None;
+        self.system_conditions[id.index()] = Vec::new();
+    }
+
+    /// Removes a system set and its conditions. Systems in the set are kept.
+    fn remove_set(&mut self, set: InternedSystemSet) {
+        if let Some(id) = self.system_set_ids.remove(&set) {
+            self.remove_node(id);
+            self.system_set_conditions[id.index()] = Vec::new();
+        }
+    }
+
+    /// Replaces the system with the given id, keeping its conditions and position in the graphs.
+    fn replace_system(&mut self, id: NodeId, system: BoxedSystem) {
+        // the system leaves the default sets of the old system, keeping the constraints
+        // placed on them
+        let old_sets = self.systems[id.index()]
+            .inner
+            .as_ref()
+            .map(|old| old.default_system_sets())
+            .unwrap_or_default();
+        for set in old_sets {
+            let Some(&set_id) = self.system_set_ids.get(&set) else {
+                continue;
+            };
+            self.hierarchy.graph.remove_edge(set_id, id);
+            let before = self
+                .dependency
+                .graph
+                .neighbors_directed(set_id, Incoming)
+                .collect::<Vec<_>>();
+            let after = self
+                .dependency
+                .graph
+                .neighbors_directed(set_id, Outgoing)
+                .collect::<Vec<_>>();
+            for other in before {
+                self.dependency.graph.add_edge(other, id, ());
+            }
+            for other in after {
+                self.dependency.graph.add_edge(id, other, ());
+            }
+            let ambiguous = self.ambiguous_with.neighbors(set_id).collect::<Vec<_>>();
+            for other in ambiguous {
+                self.ambiguous_with.add_edge(id, other, ());
+            }
+        }
+
+        // the new system can be referred to through its own default sets
+        for set in system.default_system_sets() {
+            let set_id = match self.system_set_ids.get(&set) {
+                Some(&set_id) => set_id,
+                None => self.add_set(set),
+            };
+            self.hierarchy.graph.add_edge(set_id, id, ());
+            self.dependency.graph.add_node(set_id);
+        }
+        self.systems[id.index()].inner = Some(system);
+
+        // only the system needs to be initialized, unless its conditions weren't either
+        let first_uninit_condition = match self
+            .uninit
+            .iter()
+            .position(|&(uninit_id, _)| uninit_id == id)
+        {
+            Some(index) => self.uninit.remove(index).1,
+            None => self.system_conditions[id.index()].len(),
+        };
+        self.uninit.push((id, first_uninit_condition));
+        self.changed = true;
     }
 }
 
@@ -1964,6 +2394,21 @@ pub enum ScheduleBuildError {
     Uninitialized,
 }
 
+/// Errors returned when editing the systems of a [`Schedule`].
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum ScheduleEditError {
+    /// The schedule doesn't exist.
+    #[error("Schedule `{0}` does not exist.")]
+    ScheduleNotFound(String),
+    /// The system set doesn't exist in the schedule.
+    #[error("System set `{0}` does not exist in this schedule.")]
+    SetNotFound(String),
+    /// The system set was expected to contain a single system.
+    #[error("System set `{0}` contains {1} systems, but exactly one is required.")]
+    NotASingleSystem(String, usize),
+}
+
 /// Specifies how schedule construction should respond to detecting a certain kind of issue.
 #[derive(Debug, Clone, PartialEq)]
 pub enum LogLevel {
@@ -2007,6 +2452,10 @@ pub struct ScheduleBuildSettings {
     ///
     /// Defaults to `true`.
     pub report_sets: bool,
+    /// Determines the order in which systems that are not ordered relative to each other run.
+    ///
+    /// Defaults to [`ExecutionOrder::Any`].
+    pub execution_order: ExecutionOrder,
 }
 
 impl Default for ScheduleBuildSettings {
@@ -2025,6 +2474,7 @@ impl ScheduleBuildSettings {
             auto_insert_apply_deferred: true,
             use_shortnames: true,
             report_sets: true,
+            execution_order: ExecutionOrder::Any,
         }
     }
 }
@@ -2069,7 +2519,7 @@ mod tests {
 
         schedule.configure_sets(Set.run_if(|| false));
         schedule.add_systems(
-            (|| panic!("This system must not run"))
+            (|| -> () { panic!("This system must not run") })
                 .ambiguous_with(|| ())
                 .in_set(Set),
         );
@@ -2632,4 +3082,171 @@ mod tests {
             .expect("CheckSystemRan Resource Should Exist");
         assert_eq!(value.0, 2);
     }
+
+    mod edit_systems {
+        use std::sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        };
+
+        use crate::{
+            self as bevy_ecs,
+            prelude::*,
+            schedule::{ExecutorKind, ScheduleEditError},
+        };
+
+        #[derive(Resource, Default)]
+        struct Order(Vec<&'static str>);
+
+        fn a(mut order: ResMut<Order>) {
+            order.0.push("a");
+        }
+
+        fn b(mut order: ResMut<Order>) {
+            order.0.push("b");
+        }
+
+        fn c(mut order: ResMut<Order>) {
+            order.0.push("c");
+        }
+
+        fn d(mut order: ResMut<Order>) {
+            order.0.push("d");
+        }
+
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Set;
+
+        fn run(schedule: &mut Schedule, world: &mut World) -> Vec<&'static str> {
+            world.init_resource::<Order>();
+            schedule.run(world);
+            world.remove_resource::<Order>().unwrap().0
+        }
+
+        fn schedule() -> (Schedule, World) {
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+            (schedule, World::new())
+        }
+
+        #[test]
+        fn remove_system_keeps_order_of_others() {
+            let (mut schedule, mut world) = schedule();
+            schedule.add_systems((c, b.before(c), a.before(b)));
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "b", "c"]);
+
+            assert_eq!(schedule.remove_systems(b), 1);
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "c"]);
+            assert_eq!(schedule.systems_len(), 2);
+
+            // `b`'s type set still orders newly added instances
+            schedule.add_systems(b);
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "b", "c"]);
+        }
+
+        #[test]
+        fn remove_before_initialization() {
+            let (mut schedule, mut world) = schedule();
+            schedule.add_systems((a, b).chain());
+            assert_eq!(schedule.remove_systems(a), 1);
+            assert_eq!(schedule.remove_systems(a), 0);
+            assert_eq!(run(&mut schedule, &mut world), vec!["b"]);
+        }
+
+        #[test]
+        fn remove_set() {
+            let (mut schedule, mut world) = schedule();
+            schedule.configure_sets(Set.run_if(|| false));
+            schedule.add_systems(((a, b).in_set(Set), c));
+            assert_eq!(run(&mut schedule, &mut world), vec!["c"]);
+
+            assert_eq!(schedule.remove_systems(Set), 2);
+            assert_eq!(run(&mut schedule, &mut world), vec!["c"]);
+
+            // the set keeps its condition until it is removed
+            schedule.add_systems(d.in_set(Set));
+            assert_eq!(run(&mut schedule, &mut world), vec!["c"]);
+
+            assert_eq!(schedule.remove_set(Set), 1);
+            assert_eq!(schedule.remove_set(Set), 0);
+            schedule.add_systems(d.in_set(Set));
+            let mut order = run(&mut schedule, &mut world);
+            order.sort();
+            assert_eq!(order, vec!["c", "d"]);
+        }
+
+        #[test]
+        fn replace_system_keeps_constraints() {
+            let (mut schedule, mut world) = schedule();
+            schedule.add_systems((a, b.run_if(|| true), c).chain());
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "b", "c"]);
+
+            schedule.replace_system(b, d).unwrap();
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "d", "c"]);
+
+            // the replacement can be referred to by its own function
+            schedule.replace_system(d, b).unwrap();
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "b", "c"]);
+        }
+
+        #[test]
+        fn replace_system_moves_to_new_type_set() {
+            let (mut schedule, mut world) = schedule();
+            schedule.add_systems((b, c.after(b), d.before(b)));
+            assert_eq!(run(&mut schedule, &mut world), vec!["d", "b", "c"]);
+
+            schedule.replace_system(b, a).unwrap();
+            // constraints placed on the old system's function still apply
+            assert_eq!(run(&mut schedule, &mut world), vec!["d", "a", "c"]);
+
+            // the replacement isn't part of the old system's function anymore
+            assert_eq!(schedule.remove_systems(b), 0);
+            assert_eq!(schedule.remove_systems(a), 1);
+            assert_eq!(run(&mut schedule, &mut world), vec!["d", "c"]);
+        }
+
+        #[test]
+        fn replace_system_errors() {
+            let (mut schedule, mut world) = schedule();
+            schedule.add_systems((a, b).in_set(Set));
+            assert!(matches!(
+                schedule.replace_system(Set, c),
+                Err(ScheduleEditError::NotASingleSystem(_, 2))
+            ));
+            assert!(matches!(
+                schedule.replace_system(c, d),
+                Err(ScheduleEditError::SetNotFound(_))
+            ));
+        }
+
+        #[test]
+        fn removed_system_state_is_dropped() {
+            struct DropFlag(Arc<AtomicBool>);
+
+            impl Drop for DropFlag {
+                fn drop(&mut self) {
+                    self.0.store(true, Ordering::Relaxed);
+                }
+            }
+
+            let dropped = Arc::new(AtomicBool::new(false));
+            let flag = DropFlag(dropped.clone());
+
+            #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+            struct Stateful;
+
+            let (mut schedule, mut world) = schedule();
+            schedule.add_systems(
+                (move || {
+                    let _ = &flag;
+                })
+                .in_set(Stateful),
+            );
+            run(&mut schedule, &mut world);
+            assert!(!dropped.load(Ordering::Relaxed));
+
+            schedule.remove_systems(Stateful);
+            assert!(dropped.load(Ordering::Relaxed));
+        }
+    }
 }
@@ -8,6 +8,8 @@ use std::{
 use bevy_ptr::{OwningPtr, Ptr, PtrMut};
 use bevy_utils::OnDrop;
 
+use super::MemoryUsage;
+
 /// A flat, type-erased data storage type
 ///
 /// Used to densely store homogeneous ECS data. A blob is usually just an arbitrary block of contiguous memory without any identity, and
@@ -99,6 +101,15 @@ impl BlobVec {
         self.capacity
     }
 
+    /// Returns the number of bytes used by elements and allocated for the vector.
+    #[inline]
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage {
+            used: self.len * self.item_layout.size(),
+            allocated: self.capacity * self.item_layout.size(),
+        }
+    }
+
     /// Returns the [`Layout`] of the element type stored in the vector.
     #[inline]
     pub fn layout(&self) -> Layout {
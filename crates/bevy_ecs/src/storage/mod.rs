@@ -29,6 +29,66 @@ pub use resource::*;
 pub use sparse_set::*;
 pub use table::*;
 
+use std::{
+    iter::Sum,
+    mem::size_of,
+    ops::{Add, AddAssign},
+};
+
+/// The number of bytes a storage uses and has allocated.
+///
+/// Only the memory owned directly by the storage is counted: heap allocations owned by the
+/// stored values themselves, like the buffer of a `Vec` component, are not included.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MemoryUsage {
+    /// The number of bytes occupied by stored values.
+    pub used: usize,
+    /// The number of bytes allocated, including unused capacity.
+    pub allocated: usize,
+}
+
+impl MemoryUsage {
+    /// Returns the number of allocated bytes that are not used.
+    #[inline]
+    pub const fn wasted(&self) -> usize {
+        self.allocated.saturating_sub(self.used)
+    }
+
+    /// Returns the memory usage of the buffer of `vec`.
+    #[inline]
+    pub(crate) fn of_vec<T>(vec: &Vec<T>) -> Self {
+        Self {
+            used: vec.len() * size_of::<T>(),
+            allocated: vec.capacity() * size_of::<T>(),
+        }
+    }
+}
+
+impl Add for MemoryUsage {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            used: self.used + rhs.used,
+            allocated: self.allocated + rhs.allocated,
+        }
+    }
+}
+
+impl AddAssign for MemoryUsage {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sum for MemoryUsage {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
 /// The raw data stores of a [`World`](crate::world::World)
 #[derive(Default)]
 pub struct Storages {
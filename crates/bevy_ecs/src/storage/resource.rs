@@ -1,7 +1,7 @@
 use crate::archetype::ArchetypeComponentId;
 use crate::change_detection::{MutUntyped, TicksMut};
 use crate::component::{ComponentId, ComponentTicks, Components, Tick, TickCells};
-use crate::storage::{blob_vec::BlobVec, SparseSet};
+use crate::storage::{blob_vec::BlobVec, MemoryUsage, SparseSet};
 use bevy_ptr::{OwningPtr, Ptr, UnsafeCellDeref};
 use std::{cell::UnsafeCell, mem::ManuallyDrop, thread::ThreadId};
 
@@ -64,6 +64,12 @@ impl<const SEND: bool> ResourceData<SEND> {
         }
     }
 
+    /// Returns the number of bytes used and allocated for the resource's value.
+    #[inline]
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.data.memory_usage()
+    }
+
     /// Returns true if the resource is populated.
     #[inline]
     pub fn is_present(&self) -> bool {
@@ -271,6 +277,13 @@ impl<const SEND: bool> Resources<SEND> {
         self.resources.iter().map(|(id, data)| (*id, data))
     }
 
+    /// Iterate mutably over all resources that have been initialized, i.e. given a [`ComponentId`]
+    pub(crate) fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (ComponentId, &mut ResourceData<SEND>)> {
+        self.resources.iter_mut().map(|(id, data)| (*id, data))
+    }
+
     /// Returns true if there are no resources stored in the [`World`],
     /// false otherwise.
     ///
@@ -1,7 +1,7 @@
 use crate::{
     component::{ComponentId, ComponentInfo, ComponentTicks, Tick, TickCells},
     entity::Entity,
-    storage::{Column, TableRow},
+    storage::{Column, MemoryUsage, TableRow},
 };
 use bevy_ptr::{OwningPtr, Ptr};
 use nonmax::NonMaxUsize;
@@ -9,7 +9,7 @@ use std::{cell::UnsafeCell, hash::Hash, marker::PhantomData};
 
 type EntityIndex = u32;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct SparseArray<I, V = I> {
     values: Vec<Option<V>>,
     marker: PhantomData<I>,
@@ -103,6 +103,11 @@ impl<I: SparseSetIndex, V> SparseArray<I, V> {
         self.values.clear();
     }
 
+    /// Returns the number of bytes used and allocated by the sparse array.
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.values)
+    }
+
     /// Converts the [`SparseArray`] into an immutable variant.
     pub(crate) fn into_immutable(self) -> ImmutableSparseArray<I, V> {
         ImmutableSparseArray {
@@ -158,6 +163,22 @@ impl ComponentSparseSet {
         self.dense.len() == 0
     }
 
+    /// Returns the number of bytes used and allocated by the sparse set, including change ticks
+    /// and the mapping from entities to component values.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.dense.memory_usage() + MemoryUsage::of_vec(&self.entities) + self.sparse.memory_usage()
+    }
+
+    /// Returns the number of bytes each component value in the sparse set occupies, including
+    /// change ticks and its entry in the dense entity list, but not the sparse mapping.
+    pub fn row_size(&self) -> usize {
+        #[cfg(not(debug_assertions))]
+        let entity_size = std::mem::size_of::<EntityIndex>();
+        #[cfg(debug_assertions)]
+        let entity_size = std::mem::size_of::<Entity>();
+        self.dense.row_size() + entity_size
+    }
+
     /// Inserts the `entity` key and component `value` pair into this sparse
     /// set.
     ///
@@ -324,6 +345,22 @@ impl ComponentSparseSet {
         }
     }
 
+    /// Returns a copy of this sparse set, cloning every component value with the component's
+    /// [`ComponentCloneFn`](crate::component::ComponentCloneFn).
+    ///
+    /// Returns `None` if the sparse set is not empty and the component cannot be cloned.
+    ///
+    /// # Safety
+    /// `component_info` must describe the component type stored in this sparse set.
+    pub(crate) unsafe fn try_clone(&self, component_info: &ComponentInfo) -> Option<Self> {
+        Some(Self {
+            // SAFETY: The caller ensures `component_info` describes the stored component type.
+            dense: unsafe { self.dense.try_clone(component_info) }?,
+            entities: self.entities.clone(),
+            sparse: self.sparse.clone(),
+        })
+    }
+
     pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         self.dense.check_change_ticks(change_tick);
     }
@@ -607,6 +644,14 @@ impl SparseSets {
         self.sets.get_mut(component_id)
     }
 
+    /// An Iterator visiting all ([`ComponentId`], [`ComponentSparseSet`]) pairs mutably.
+    /// NOTE: Order is not guaranteed.
+    pub(crate) fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (ComponentId, &mut ComponentSparseSet)> {
+        self.sets.iter_mut().map(|(id, data)| (*id, data))
+    }
+
     /// Clear entities stored in each [`ComponentSparseSet`]
     pub(crate) fn clear_entities(&mut self) {
         for set in self.sets.values_mut() {
@@ -2,7 +2,7 @@ use crate::{
     component::{ComponentId, ComponentInfo, ComponentTicks, Components, Tick, TickCells},
     entity::Entity,
     query::DebugCheckedUnwrap,
-    storage::{blob_vec::BlobVec, ImmutableSparseSet, SparseSet},
+    storage::{blob_vec::BlobVec, ImmutableSparseSet, MemoryUsage, SparseSet},
 };
 use bevy_ptr::{OwningPtr, Ptr, PtrMut, UnsafeCellDeref};
 use bevy_utils::HashMap;
@@ -172,6 +172,20 @@ impl Column {
         self.data.layout()
     }
 
+    /// Returns the number of bytes used and allocated by the column, including change ticks.
+    #[inline]
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.data.memory_usage()
+            + MemoryUsage::of_vec(&self.added_ticks)
+            + MemoryUsage::of_vec(&self.changed_ticks)
+    }
+
+    /// Returns the number of bytes each row of the column occupies, including change ticks.
+    #[inline]
+    pub fn row_size(&self) -> usize {
+        self.data.layout().size() + 2 * std::mem::size_of::<Tick>()
+    }
+
     /// Writes component data to the column at given row.
     /// Assumes the slot is uninitialized, drop is not called.
     /// To overwrite existing initialized value, use `replace` instead.
@@ -492,6 +506,36 @@ impl Column {
         self.changed_ticks.clear();
     }
 
+    /// Returns a copy of this column, cloning every value with the component's [`ComponentCloneFn`].
+    ///
+    /// Returns `None` if the column is not empty and the component cannot be cloned.
+    ///
+    /// # Safety
+    /// `component_info` must describe the component type stored in this column.
+    ///
+    /// [`ComponentCloneFn`]: crate::component::ComponentCloneFn
+    pub(crate) unsafe fn try_clone(&self, component_info: &ComponentInfo) -> Option<Column> {
+        let mut column = Column::with_capacity(component_info, self.len());
+        if self.is_empty() {
+            return Some(column);
+        }
+        let clone_fn = component_info.clone_fn()?;
+        for row in 0..self.len() {
+            let row = TableRow::from_usize(row);
+            // SAFETY:
+            // - `row` is in bounds.
+            // - The caller ensures `component_info` describes the type stored in this column,
+            //   so `clone_fn` is valid for its values and `column` stores the same type.
+            unsafe {
+                let ticks = self.get_ticks_unchecked(row);
+                clone_fn(self.get_data_unchecked(row), &mut |value| {
+                    column.push(value, ticks);
+                });
+            }
+        }
+        Some(column)
+    }
+
     #[inline]
     pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         for component_ticks in &mut self.added_ticks {
@@ -763,6 +807,16 @@ impl Table {
         self.entities.capacity()
     }
 
+    /// Returns the number of bytes used and allocated by the table's columns and entity list.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.entities) + self.columns.values().map(Column::memory_usage).sum()
+    }
+
+    /// Returns an iterator over the [`ComponentId`]s and [`Column`]s of the table.
+    pub fn columns(&self) -> impl Iterator<Item = (ComponentId, &Column)> {
+        self.columns.iter().map(|(id, column)| (*id, column))
+    }
+
     /// Checks if the [`Table`] is empty or not.
     ///
     /// Returns `true` if the table contains no entities, `false` otherwise.
@@ -782,6 +836,26 @@ impl Table {
         self.columns.values()
     }
 
+    /// Returns a copy of this table, cloning every component value with its [`ComponentCloneFn`].
+    ///
+    /// Returns the [`ComponentId`] of the first non-empty column whose component cannot be cloned.
+    ///
+    /// [`ComponentCloneFn`]: crate::component::ComponentCloneFn
+    pub(crate) fn try_clone(&self, components: &Components) -> Result<Table, ComponentId> {
+        let mut columns = SparseSet::with_capacity(self.columns.len());
+        for (&component_id, column) in self.columns.iter() {
+            // SAFETY: Columns are only created for registered components.
+            let component_info = unsafe { components.get_info_unchecked(component_id) };
+            // SAFETY: Columns are keyed by the id of the component they store.
+            let column = unsafe { column.try_clone(component_info) }.ok_or(component_id)?;
+            columns.insert(component_id, column);
+        }
+        Ok(Table {
+            columns: columns.into_immutable(),
+            entities: self.entities.clone(),
+        })
+    }
+
     /// Clears all of the stored components in the [`Table`].
     pub(crate) fn clear(&mut self) {
         self.entities.clear();
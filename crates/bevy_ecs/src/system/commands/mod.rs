@@ -1,19 +1,25 @@
 mod parallel_scope;
 
+use std::{borrow::Cow, sync::Arc};
+
 use super::{Deferred, IntoObserverSystem, IntoSystem, RegisterSystem, Resource};
 use crate::{
     self as bevy_ecs,
     bundle::Bundle,
-    component::{ComponentId, ComponentInfo},
-    entity::{Entities, Entity},
+    component::{ComponentId, ComponentInfo, Tick},
+    entity::{Entities, Entity, EntityCloneBuilder},
+    error::{self, CommandErrorHandler, SystemErrorContext, SystemErrorHandlerFn},
     event::Event,
     observer::{Observer, TriggerEvent, TriggerTargets},
-    system::{RunSystemWithInput, SystemId},
+    system::{
+        RunSystemCachedWith, RunSystemWithInput, RunSystemWithOutput, SystemId, SystemTicket,
+    },
     world::command_queue::RawCommandQueue,
     world::{Command, CommandQueue, EntityWorldMut, FromWorld, World},
 };
 use bevy_utils::tracing::{error, info};
 pub use parallel_scope::*;
+use thiserror::Error;
 
 /// A [`Command`] queue to perform structural changes to the [`World`].
 ///
@@ -69,6 +75,8 @@ pub use parallel_scope::*;
 pub struct Commands<'w, 's> {
     queue: InternalQueue<'s>,
     entities: &'w Entities,
+    /// Describes the system these commands were queued by, if any.
+    error_context: Option<&'s Arc<SystemErrorContext>>,
 }
 
 const _: () = {
@@ -76,6 +84,7 @@ const _: () = {
     #[doc(hidden)]
     pub struct FetchState {
         state: <__StructFieldsAlias<'static, 'static> as bevy_ecs::system::SystemParam>::State,
+        error_context: Arc<SystemErrorContext>,
     }
     // SAFETY: Only reads Entities
     unsafe impl bevy_ecs::system::SystemParam for Commands<'_, '_> {
@@ -90,6 +99,10 @@ const _: () = {
                     world,
                     system_meta,
                 ),
+                error_context: Arc::new(SystemErrorContext {
+                    name: system_meta.name.clone(),
+                    last_run: Tick::new(0),
+                }),
             }
         }
         unsafe fn new_archetype(
@@ -134,10 +147,19 @@ const _: () = {
             world: bevy_ecs::world::unsafe_world_cell::UnsafeWorldCell<'w>,
             change_tick: bevy_ecs::component::Tick,
         ) -> Self::Item<'w, 's> {
-            let(f0,f1,) =  <(Deferred<'s,CommandQueue> , &'w Entities,)as bevy_ecs::system::SystemParam> ::get_param(&mut state.state,system_meta,world,change_tick);
+            let FetchState {
+                state,
+                error_context,
+            } = state;
+            let(f0,f1,) =  <(Deferred<'s,CommandQueue> , &'w Entities,)as bevy_ecs::system::SystemParam> ::get_param(state,system_meta,world,change_tick);
+            if error_context.last_run != system_meta.last_run {
+                // Only clones the context if commands queued by the previous run are still pending.
+                Arc::make_mut(error_context).last_run = system_meta.last_run;
+            }
             Commands {
                 queue: InternalQueue::CommandQueue(f0),
                 entities: f1,
+                error_context: Some(error_context),
             }
         }
     }
@@ -174,6 +196,7 @@ impl<'w, 's> Commands<'w, 's> {
         Self {
             queue: InternalQueue::CommandQueue(Deferred(queue)),
             entities,
+            error_context: None,
         }
     }
 
@@ -191,6 +214,7 @@ impl<'w, 's> Commands<'w, 's> {
         Self {
             queue: InternalQueue::RawCommandQueue(queue),
             entities,
+            error_context: None,
         }
     }
 
@@ -221,6 +245,7 @@ impl<'w, 's> Commands<'w, 's> {
                 }
             },
             entities: self.entities,
+            error_context: self.error_context,
         }
     }
 
@@ -274,6 +299,7 @@ impl<'w, 's> Commands<'w, 's> {
         EntityCommands {
             entity,
             commands: self.reborrow(),
+            error_handler: None,
         }
     }
 
@@ -298,6 +324,7 @@ impl<'w, 's> Commands<'w, 's> {
         EntityCommands {
             entity,
             commands: self.reborrow(),
+            error_handler: None,
         }
     }
 
@@ -446,6 +473,7 @@ impl<'w, 's> Commands<'w, 's> {
         self.entities.contains(entity).then_some(EntityCommands {
             entity,
             commands: self.reborrow(),
+            error_handler: None,
         })
     }
 
@@ -627,9 +655,9 @@ impl<'w, 's> Commands<'w, 's> {
     ///
     /// Calls [`World::run_system`](World::run_system).
     ///
-    /// There is no way to get the output of a system when run as a command, because the
-    /// execution of the system happens later. To get the output of a system, use
-    /// [`World::run_system`] or [`World::run_system_with_input`] instead of running the system as a command.
+    /// The output of the system is discarded, because the execution of the system happens later.
+    /// To get the output of a system, use [`World::run_system`] or [`World::run_system_with_input`] instead,
+    /// or [`Commands::run_system_with_output`] to receive it through a [`SystemTicket`].
     pub fn run_system(&mut self, id: SystemId) {
         self.run_system_with_input(id, ());
     }
@@ -640,13 +668,100 @@ impl<'w, 's> Commands<'w, 's> {
     ///
     /// Calls [`World::run_system_with_input`](World::run_system_with_input).
     ///
-    /// There is no way to get the output of a system when run as a command, because the
-    /// execution of the system happens later. To get the output of a system, use
-    /// [`World::run_system`] or [`World::run_system_with_input`] instead of running the system as a command.
+    /// The output of the system is discarded, because the execution of the system happens later.
+    /// To get the output of a system, use [`World::run_system`] or [`World::run_system_with_input`] instead,
+    /// or [`Commands::run_system_with_output`] to receive it through a [`SystemTicket`].
     pub fn run_system_with_input<I: 'static + Send>(&mut self, id: SystemId<I>, input: I) {
         self.push(RunSystemWithInput::new_with_input(id, input));
     }
 
+    /// Runs a cached system, registering it if necessary.
+    ///
+    /// Calls [`World::run_system_cached`](World::run_system_cached). The output of the system is discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `system` is not a zero-sized type, see [`World::register_system_cached`].
+    pub fn run_system_cached<M: 'static, S: IntoSystem<(), (), M> + Send + 'static>(
+        &mut self,
+        system: S,
+    ) {
+        self.run_system_cached_with(system, ());
+    }
+
+    /// Runs a cached system with the provided input, registering it if necessary.
+    ///
+    /// Calls [`World::run_system_cached_with`](World::run_system_cached_with). The output of the system is discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `system` is not a zero-sized type, see [`World::register_system_cached`].
+    pub fn run_system_cached_with<I, M, S>(&mut self, system: S, input: I)
+    where
+        I: Send + 'static,
+        M: 'static,
+        S: IntoSystem<I, (), M> + Send + 'static,
+    {
+        self.push(RunSystemCachedWith::new(system, input));
+    }
+
+    /// Runs the system corresponding to the given [`SystemId`], and returns a [`SystemTicket`]
+    /// that resolves to the output of the system once the command has been applied.
+    ///
+    /// Calls [`World::run_system`](World::run_system).
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::CommandQueue;
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(42));
+    /// let get_score = world.register_system(|score: Res<Score>| score.0);
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// let ticket = commands.run_system_with_output(get_score);
+    /// assert!(ticket.try_take().is_none());
+    ///
+    /// queue.apply(&mut world);
+    /// assert_eq!(ticket.try_take().unwrap().unwrap(), 42);
+    /// ```
+    pub fn run_system_with_output<O: 'static + Send>(
+        &mut self,
+        id: SystemId<(), O>,
+    ) -> SystemTicket<(), O> {
+        self.run_system_with_input_and_output(id, ())
+    }
+
+    /// Runs the system corresponding to the given [`SystemId`] with the provided input, and returns
+    /// a [`SystemTicket`] that resolves to the output of the system once the command has been applied.
+    ///
+    /// Calls [`World::run_system_with_input`](World::run_system_with_input).
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::CommandQueue;
+    /// let mut world = World::new();
+    /// let double = world.register_system(|In(value): In<u32>| value * 2);
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// let ticket = commands.run_system_with_input_and_output(double, 21);
+    ///
+    /// queue.apply(&mut world);
+    /// assert_eq!(ticket.try_take().unwrap().unwrap(), 42);
+    /// ```
+    pub fn run_system_with_input_and_output<I: 'static + Send, O: 'static + Send>(
+        &mut self,
+        id: SystemId<I, O>,
+        input: I,
+    ) -> SystemTicket<I, O> {
+        let (command, ticket) = RunSystemWithOutput::new_with_input(id, input);
+        self.push(command);
+        ticket
+    }
+
     /// Registers a system and returns a [`SystemId`] so it can later be called by [`World::run_system`].
     ///
     /// It's possible to register the same systems more than once, they'll be stored separately.
@@ -747,6 +862,75 @@ impl<'w, 's> Commands<'w, 's> {
         self.push(command);
     }
 
+    /// Pushes a [`FallibleCommand`] to the command queue.
+    ///
+    /// If the command returns an error when it is applied, the error is passed to the world's
+    /// [`CommandErrorHandler`], along with the name of the system that queued the command.
+    /// Without that resource, the error [panics](error::panic).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::error::{self, CommandErrorHandler};
+    /// #[derive(Resource)]
+    /// struct Score(u32);
+    ///
+    /// fn double_score(mut commands: Commands) {
+    ///     commands.add_fallible(|world: &mut World| -> error::Result {
+    ///         world.get_resource_mut::<Score>().ok_or("there is no score")?.0 *= 2;
+    ///         Ok(())
+    ///     });
+    /// }
+    /// # let mut world = World::new();
+    /// # world.insert_resource(CommandErrorHandler(error::ignore));
+    /// # let mut schedule = Schedule::default();
+    /// # schedule.add_systems(double_score);
+    /// # schedule.run(&mut world);
+    /// ```
+    pub fn add_fallible<C: FallibleCommand>(&mut self, command: C) {
+        self.push_fallible(command, None);
+    }
+
+    /// Pushes a [`FallibleCommand`] to the command queue, which passes any error it returns to
+    /// `handler` instead of the world's [`CommandErrorHandler`].
+    ///
+    /// Any [`SystemErrorHandlerFn`] can be used, like [`error::warn`] or [`error::ignore`].
+    pub fn add_fallible_with<C: FallibleCommand>(
+        &mut self,
+        command: C,
+        handler: SystemErrorHandlerFn,
+    ) {
+        self.push_fallible(command, Some(handler));
+    }
+
+    fn push_fallible<C: FallibleCommand>(
+        &mut self,
+        command: C,
+        handler: Option<SystemErrorHandlerFn>,
+    ) {
+        let context = self.error_context.cloned();
+        self.push(move |world: &mut World| {
+            if let Err(error) = command.try_apply(world) {
+                let handler = handler.unwrap_or_else(|| {
+                    world
+                        .get_resource::<CommandErrorHandler>()
+                        .copied()
+                        .unwrap_or_default()
+                        .0
+                });
+                let context = context.map_or_else(
+                    || SystemErrorContext {
+                        name: Cow::Borrowed("<unknown>"),
+                        last_run: world.last_change_tick(),
+                    },
+                    Arc::unwrap_or_clone,
+                );
+                handler(error, context);
+            }
+        });
+    }
+
     /// Sends a "global" [`Trigger`] without any targets. This will run any [`Observer`] of the `event` that
     /// isn't scoped to specific targets.
     ///
@@ -848,10 +1032,73 @@ pub trait EntityCommand<Marker = ()>: Send + 'static {
     }
 }
 
+/// A [`Command`] that can fail, returning an [`Error`](error::Error) instead of panicking.
+///
+/// Queue it with [`Commands::add_fallible`]. Errors are handled by the world's
+/// [`CommandErrorHandler`] or by the handler given to [`Commands::add_fallible_with`].
+///
+/// Closures with the signature `FnOnce(&mut World) -> error::Result` implement this trait.
+pub trait FallibleCommand: Send + 'static {
+    /// Applies this command, returning an error if it could not be applied.
+    fn try_apply(self, world: &mut World) -> error::Result;
+}
+
+impl<F> FallibleCommand for F
+where
+    F: FnOnce(&mut World) -> error::Result + Send + 'static,
+{
+    fn try_apply(self, world: &mut World) -> error::Result {
+        self(world)
+    }
+}
+
+/// An [`EntityCommand`] that can fail, returning an [`Error`](error::Error) instead of panicking.
+///
+/// Queue it with [`EntityCommands::add_fallible`]. Errors are handled by the handler set with
+/// [`EntityCommands::on_error`], or by the world's [`CommandErrorHandler`].
+///
+/// Closures with the signature `FnOnce(Entity, &mut World) -> error::Result` implement this trait.
+pub trait FallibleEntityCommand: Send + 'static {
+    /// Applies this command to the given [`Entity`], returning an error if it could not be applied.
+    fn try_apply(self, entity: Entity, world: &mut World) -> error::Result;
+}
+
+impl<F> FallibleEntityCommand for F
+where
+    F: FnOnce(Entity, &mut World) -> error::Result + Send + 'static,
+{
+    fn try_apply(self, entity: Entity, world: &mut World) -> error::Result {
+        self(entity, world)
+    }
+}
+
+/// An error returned by the built-in [`EntityCommands`] when their entity doesn't exist.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EntityCommandError {
+    /// [`EntityCommands::insert`] was applied to an entity that doesn't exist.
+    #[error("error[B0003]: Could not insert a bundle (of type `{bundle}`) for entity {entity:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003")]
+    InsertIntoMissingEntity {
+        /// The entity the bundle should have been inserted into.
+        entity: Entity,
+        /// The type name of the bundle.
+        bundle: &'static str,
+    },
+    /// Another command was applied to an entity that doesn't exist.
+    #[error("Could not {command} entity {entity:?} because it doesn't exist in this World.")]
+    NoSuchEntity {
+        /// The entity the command was queued for.
+        entity: Entity,
+        /// What the command tried to do.
+        command: &'static str,
+    },
+}
+
 /// A list of commands that will be run to modify an [entity](crate::entity).
 pub struct EntityCommands<'a> {
     pub(crate) entity: Entity,
     pub(crate) commands: Commands<'a, 'a>,
+    /// Handles errors of fallible commands queued through these commands.
+    pub(crate) error_handler: Option<SystemErrorHandlerFn>,
 }
 
 impl EntityCommands<'_> {
@@ -879,6 +1126,7 @@ impl EntityCommands<'_> {
         EntityCommands {
             entity: self.entity,
             commands: self.commands.reborrow(),
+            error_handler: self.error_handler,
         }
     }
 
@@ -886,11 +1134,13 @@ impl EntityCommands<'_> {
     ///
     /// This will overwrite any previous value(s) of the same component type.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// If the associated entity does not exist when the command is applied, an
+    /// [`EntityCommandError`] is passed to the handler set with [`Self::on_error`], or to the
+    /// world's [`CommandErrorHandler`], which panics by default.
     ///
-    /// To avoid a panic in this case, use the command [`Self::try_insert`] instead.
+    /// To silently do nothing in this case, use the command [`Self::try_insert`] instead.
     ///
     /// # Example
     ///
@@ -934,7 +1184,7 @@ impl EntityCommands<'_> {
     /// # bevy_ecs::system::assert_is_system(add_combat_stats_system);
     /// ```
     pub fn insert(&mut self, bundle: impl Bundle) -> &mut Self {
-        self.add(insert(bundle))
+        self.add_fallible_or(insert(bundle), None)
     }
 
     /// Tries to add a [`Bundle`] of components to the entity.
@@ -991,6 +1241,8 @@ impl EntityCommands<'_> {
 
     /// Removes a [`Bundle`] of components from the entity.
     ///
+    /// Does nothing if the entity does not exist, unless a handler was set with [`Self::on_error`].
+    ///
     /// # Example
     ///
     /// ```
@@ -1028,17 +1280,21 @@ impl EntityCommands<'_> {
     where
         T: Bundle,
     {
-        self.add(remove::<T>)
+        self.add_fallible_or(remove::<T>, Some(error::ignore))
     }
 
     /// Removes a component from the entity.
+    ///
+    /// Does nothing if the entity does not exist, unless a handler was set with [`Self::on_error`].
     pub fn remove_by_id(&mut self, component_id: ComponentId) -> &mut Self {
-        self.add(remove_by_id(component_id))
+        self.add_fallible_or(remove_by_id(component_id), Some(error::ignore))
     }
 
     /// Removes all components associated with the entity.
+    ///
+    /// Does nothing if the entity does not exist, unless a handler was set with [`Self::on_error`].
     pub fn clear(&mut self) -> &mut Self {
-        self.add(clear())
+        self.add_fallible_or(clear(), Some(error::ignore))
     }
 
     /// Despawns the entity.
@@ -1093,10 +1349,67 @@ impl EntityCommands<'_> {
         self
     }
 
+    /// Pushes a [`FallibleEntityCommand`] to the queue, which will get executed for the current
+    /// [`Entity`].
+    ///
+    /// Errors are passed to the handler set with [`Self::on_error`], or to the world's
+    /// [`CommandErrorHandler`].
+    pub fn add_fallible(&mut self, command: impl FallibleEntityCommand) -> &mut Self {
+        self.add_fallible_or(command, None)
+    }
+
+    /// Sets the handler for errors of the fallible commands queued through these
+    /// [`EntityCommands`] from now on, like [`insert`](Self::insert) for an entity that was
+    /// despawned before the command was applied.
+    ///
+    /// By default, errors are passed to the world's [`CommandErrorHandler`], except for commands
+    /// that are documented to do nothing on error. Any [`SystemErrorHandlerFn`] can be used; the
+    /// [`SystemErrorContext`] it receives names the system that queued the command.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::error;
+    /// #[derive(Component)]
+    /// struct Stunned;
+    ///
+    /// fn stun(mut commands: Commands, target: Query<Entity, With<Name>>) {
+    ///     for entity in &target {
+    ///         // Log a warning instead of panicking if the entity gets despawned first.
+    ///         commands.entity(entity).on_error(error::warn).insert(Stunned);
+    ///     }
+    /// }
+    /// # #[derive(Component)]
+    /// # struct Name;
+    /// # bevy_ecs::system::assert_is_system(stun);
+    /// ```
+    pub fn on_error(&mut self, handler: SystemErrorHandlerFn) -> &mut Self {
+        self.error_handler = Some(handler);
+        self
+    }
+
+    /// Pushes `command`, handling its errors with the handler set by [`Self::on_error`] or else
+    /// with `default`, falling back to the world's [`CommandErrorHandler`].
+    fn add_fallible_or(
+        &mut self,
+        command: impl FallibleEntityCommand,
+        default: Option<SystemErrorHandlerFn>,
+    ) -> &mut Self {
+        let entity = self.entity;
+        self.commands.push_fallible(
+            move |world: &mut World| command.try_apply(entity, world),
+            self.error_handler.or(default),
+        );
+        self
+    }
+
     /// Removes all components except the given [`Bundle`] from the entity.
     ///
     /// This can also be used to remove all the components from the entity by passing it an empty Bundle.
     ///
+    /// Does nothing if the entity does not exist, unless a handler was set with [`Self::on_error`].
+    ///
     /// # Example
     ///
     /// ```
@@ -1134,7 +1447,7 @@ impl EntityCommands<'_> {
     where
         T: Bundle,
     {
-        self.add(retain::<T>)
+        self.add_fallible_or(retain::<T>, Some(error::ignore))
     }
 
     /// Logs the components of the entity at the info level.
@@ -1146,6 +1459,53 @@ impl EntityCommands<'_> {
         self.add(log_components);
     }
 
+    /// Spawns a clone of this entity and returns the [`EntityCommands`] of the clone.
+    ///
+    /// Components are cloned according to their [`ComponentCloneHandler`](crate::component::ComponentCloneHandler).
+    /// See [`EntityCloneBuilder`] for more info.
+    ///
+    /// # Panics
+    ///
+    /// The command will panic when applied if the associated entity does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, Clone)]
+    /// struct Health(u32);
+    ///
+    /// fn duplicate_system(mut commands: Commands, query: Query<Entity, With<Health>>) {
+    ///     for entity in &query {
+    ///         commands.entity(entity).clone_and_spawn();
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(duplicate_system);
+    /// ```
+    pub fn clone_and_spawn(&mut self) -> EntityCommands<'_> {
+        self.clone_and_spawn_with(|_| {})
+    }
+
+    /// Spawns a clone of this entity, configuring the [`EntityCloneBuilder`] with `f`,
+    /// and returns the [`EntityCommands`] of the clone.
+    ///
+    /// # Panics
+    ///
+    /// The command will panic when applied if the associated entity does not exist.
+    pub fn clone_and_spawn_with(
+        &mut self,
+        f: impl FnOnce(&mut EntityCloneBuilder) + Send + 'static,
+    ) -> EntityCommands<'_> {
+        let source = self.entity;
+        let target = self.commands.spawn_empty().id();
+        self.commands.add(move |world: &mut World| {
+            let mut builder = EntityCloneBuilder::new(world);
+            f(&mut builder);
+            builder.clone_entity(source, target);
+        });
+        self.commands.entity(target)
+    }
+
     /// Returns the underlying [`Commands`].
     pub fn commands(&mut self) -> Commands {
         self.commands.reborrow()
@@ -1232,14 +1592,18 @@ fn despawn(entity: Entity, world: &mut World) {
     world.despawn(entity);
 }
 
-/// An [`EntityCommand`] that adds the components in a [`Bundle`] to an entity.
-fn insert<T: Bundle>(bundle: T) -> impl EntityCommand {
-    move |entity: Entity, world: &mut World| {
-        if let Some(mut entity) = world.get_entity_mut(entity) {
-            entity.insert(bundle);
-        } else {
-            panic!("error[B0003]: Could not insert a bundle (of type `{}`) for entity {:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003", std::any::type_name::<T>(), entity);
-        }
+/// A [`FallibleEntityCommand`] that adds the components in a [`Bundle`] to an entity.
+fn insert<T: Bundle>(bundle: T) -> impl FallibleEntityCommand {
+    move |entity: Entity, world: &mut World| -> error::Result {
+        let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+            return Err(EntityCommandError::InsertIntoMissingEntity {
+                entity,
+                bundle: std::any::type_name::<T>(),
+            }
+            .into());
+        };
+        entity_mut.insert(bundle);
+        Ok(())
     }
 }
 
@@ -1252,43 +1616,50 @@ fn try_insert(bundle: impl Bundle) -> impl EntityCommand {
     }
 }
 
-/// An [`EntityCommand`] that removes components from an entity.
+/// Returns the [`EntityWorldMut`] of `entity`, or an error describing `command` if it doesn't exist.
+fn entity_for<'w>(
+    world: &'w mut World,
+    entity: Entity,
+    command: &'static str,
+) -> Result<EntityWorldMut<'w>, EntityCommandError> {
+    world
+        .get_entity_mut(entity)
+        .ok_or(EntityCommandError::NoSuchEntity { entity, command })
+}
+
+/// A [`FallibleEntityCommand`] that removes components from an entity.
 /// For a [`Bundle`] type `T`, this will remove any components in the bundle.
 /// Any components in the bundle that aren't found on the entity will be ignored.
-fn remove<T: Bundle>(entity: Entity, world: &mut World) {
-    if let Some(mut entity) = world.get_entity_mut(entity) {
-        entity.remove::<T>();
-    }
+fn remove<T: Bundle>(entity: Entity, world: &mut World) -> error::Result {
+    entity_for(world, entity, "remove components from")?.remove::<T>();
+    Ok(())
 }
 
-/// An [`EntityCommand`] that removes components with a provided [`ComponentId`] from an entity.
+/// A [`FallibleEntityCommand`] that removes components with a provided [`ComponentId`] from an entity.
 /// # Panics
 ///
 /// Panics if the provided [`ComponentId`] does not exist in the [`World`].
-fn remove_by_id(component_id: ComponentId) -> impl EntityCommand {
-    move |entity: Entity, world: &mut World| {
-        if let Some(mut entity) = world.get_entity_mut(entity) {
-            entity.remove_by_id(component_id);
-        }
+fn remove_by_id(component_id: ComponentId) -> impl FallibleEntityCommand {
+    move |entity: Entity, world: &mut World| -> error::Result {
+        entity_for(world, entity, "remove a component from")?.remove_by_id(component_id);
+        Ok(())
     }
 }
 
-/// An [`EntityCommand`] that removes all components associated with a provided entity.
-fn clear() -> impl EntityCommand {
-    move |entity: Entity, world: &mut World| {
-        if let Some(mut entity) = world.get_entity_mut(entity) {
-            entity.clear();
-        }
+/// A [`FallibleEntityCommand`] that removes all components associated with a provided entity.
+fn clear() -> impl FallibleEntityCommand {
+    move |entity: Entity, world: &mut World| -> error::Result {
+        entity_for(world, entity, "clear")?.clear();
+        Ok(())
     }
 }
 
-/// An [`EntityCommand`] that removes components from an entity.
+/// A [`FallibleEntityCommand`] that removes components from an entity.
 /// For a [`Bundle`] type `T`, this will remove all components except those in the bundle.
 /// Any components in the bundle that aren't found on the entity will be ignored.
-fn retain<T: Bundle>(entity: Entity, world: &mut World) {
-    if let Some(mut entity_mut) = world.get_entity_mut(entity) {
-        entity_mut.retain::<T>();
-    }
+fn retain<T: Bundle>(entity: Entity, world: &mut World) -> error::Result {
+    entity_for(world, entity, "retain components of")?.retain::<T>();
+    Ok(())
 }
 
 /// A [`Command`] that inserts a [`Resource`] into the world using a value
@@ -1334,13 +1705,16 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         component::Component,
-        system::{Commands, Resource},
+        entity::Entity,
+        error::{self, CommandErrorHandler, SystemErrorContext},
+        schedule::Schedule,
+        system::{Commands, EntityCommandError, Query, Resource, SystemChangeTick},
         world::{CommandQueue, World},
     };
     use std::{
         any::TypeId,
         sync::{
-            atomic::{AtomicUsize, Ordering},
+            atomic::{AtomicU32, AtomicUsize, Ordering},
             Arc,
         },
     };
@@ -1564,4 +1938,108 @@ mod tests {
         assert!(world.contains_resource::<W<i32>>());
         assert!(world.contains_resource::<W<f64>>());
     }
+
+    fn despawn_and_insert(mut commands: Commands, query: Query<Entity>) {
+        for entity in &query {
+            commands.entity(entity).despawn();
+            commands.entity(entity).insert(W(1u8));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "error[B0003]")]
+    fn insert_into_despawned_entity_panics() {
+        let mut world = World::default();
+        world.spawn_empty();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(despawn_and_insert);
+        schedule.run(&mut world);
+    }
+
+    #[test]
+    fn command_errors_name_the_queueing_system() {
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+        fn count_errors(error: error::Error, context: SystemErrorContext) {
+            assert!(context.name.ends_with("despawn_and_insert"));
+            assert!(matches!(
+                error.downcast_ref(),
+                Some(EntityCommandError::InsertIntoMissingEntity { .. })
+            ));
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::default();
+        world.insert_resource(CommandErrorHandler(count_errors));
+        world.spawn_empty();
+        world.spawn_empty();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(despawn_and_insert);
+        schedule.run(&mut world);
+
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 2);
+        assert_eq!(world.entities().len(), 0);
+    }
+
+    #[test]
+    fn command_errors_report_the_previous_run_of_the_system() {
+        static LAST_RUN: AtomicU32 = AtomicU32::new(0);
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+        fn fail(mut commands: Commands, ticks: SystemChangeTick) {
+            LAST_RUN.store(ticks.last_run().get(), Ordering::Relaxed);
+            commands.add_fallible(|_: &mut World| -> error::Result { Err("failed".into()) });
+        }
+
+        fn check_last_run(_: error::Error, context: SystemErrorContext) {
+            assert_eq!(context.last_run.get(), LAST_RUN.load(Ordering::Relaxed));
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::default();
+        world.insert_resource(CommandErrorHandler(check_last_run));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(fail);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 2);
+        assert_ne!(LAST_RUN.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn per_command_error_handlers() {
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+        fn count_errors(_: error::Error, _: SystemErrorContext) {
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::default();
+        let entity = world.spawn_empty().id();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.entity(entity).despawn();
+        // ignored by default
+        commands.entity(entity).remove::<W<u8>>().clear();
+        // the panicking default is overridden
+        commands
+            .entity(entity)
+            .on_error(count_errors)
+            .insert(W(1u8))
+            .remove::<W<u8>>();
+        commands
+            .entity(entity)
+            .on_error(error::ignore)
+            .insert(W(1u8));
+        commands.add_fallible_with(
+            |_: &mut World| -> error::Result { Err("failed".into()) },
+            count_errors,
+        );
+        commands.add_fallible(|_: &mut World| -> error::Result { Ok(()) });
+        queue.apply(&mut world);
+
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 3);
+    }
 }
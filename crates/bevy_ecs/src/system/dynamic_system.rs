@@ -0,0 +1,581 @@
+use std::borrow::Cow;
+
+use bevy_ptr::Ptr;
+
+use crate::{
+    self as bevy_ecs,
+    archetype::{ArchetypeComponentId, ArchetypeGeneration},
+    change_detection::{MutUntyped, TicksMut},
+    component::{ComponentId, Tick},
+    query::{Access, QueryBuilder, QueryState},
+    schedule::{InternedSystemSet, SystemSet},
+    system::{check_system_change_tick, Commands, System, SystemBuffer, SystemMeta},
+    world::{
+        unsafe_world_cell::UnsafeWorldCell, CommandQueue, DeferredWorld, FilteredEntityMut, World,
+        WorldId,
+    },
+};
+
+use super::Query;
+
+/// The function run by a [`DynamicSystem`].
+pub type DynamicSystemFn = Box<dyn FnMut(DynamicSystemContext) + Send + Sync>;
+
+type DynamicQueryBuilderFn =
+    Box<dyn FnOnce(&mut QueryBuilder<FilteredEntityMut<'static>>) + Send + Sync>;
+
+/// A [`System`] whose data access is declared at runtime, for example by a scripting language.
+///
+/// A dynamic system is made of a function that receives a [`DynamicSystemContext`] and of a list
+/// of queries and resources described by [`ComponentId`]s, so it can work with components
+/// registered at runtime through
+/// [`World::init_component_with_descriptor`](World::init_component_with_descriptor).
+/// The declared accesses are registered with the scheduler like those of any other system, so
+/// dynamic systems run in parallel with every system they don't conflict with.
+///
+/// Queries return [`FilteredEntityMut`]s, whose components can be read and written through raw
+/// pointers with [`get_by_id`](FilteredEntityMut::get_by_id) and
+/// [`get_mut_by_id`](FilteredEntityMut::get_mut_by_id), or through reflection by passing those
+/// pointers to `ReflectFromPtr`.
+///
+/// Like any other system, a dynamic system can be added to a
+/// [`Schedule`](crate::schedule::Schedule) while the app is running, for example from an exclusive
+/// system through the [`Schedules`](crate::schedule::Schedules) resource. Every dynamic system is
+/// part of a [`DynamicSystemSet`] named after it, which can be used to order other systems
+/// relative to it.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::{DynamicSystem, RunSystemOnce};
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// #[derive(Resource)]
+/// struct Regeneration(f32);
+///
+/// let mut world = World::new();
+/// world.spawn(Health(10.0));
+/// world.insert_resource(Regeneration(2.5));
+///
+/// // Typically these ids would come from a script.
+/// let health = world.init_component::<Health>();
+/// let regeneration = world.components().resource_id::<Regeneration>().unwrap();
+///
+/// let system = DynamicSystem::new("regenerate", move |mut context| {
+///     let regeneration = context.resource(regeneration).unwrap();
+///     // SAFETY: `regeneration` is the id of `Regeneration`.
+///     let amount = unsafe { regeneration.deref::<Regeneration>() }.0;
+///     for mut entity in &mut context.query(0) {
+///         let mut value = entity.get_mut_by_id(health).unwrap();
+///         // SAFETY: `health` is the id of `Health`.
+///         unsafe { value.as_mut().deref_mut::<Health>() }.0 += amount;
+///     }
+/// })
+/// .with_query(move |query| {
+///     query.mut_id(health);
+/// })
+/// .with_resource(regeneration);
+///
+/// world.run_system_once(system);
+/// assert_eq!(world.query::<&Health>().single(&world).0, 12.5);
+/// ```
+pub struct DynamicSystem {
+    func: DynamicSystemFn,
+    query_builders: Vec<DynamicQueryBuilderFn>,
+    queries: Vec<QueryState<FilteredEntityMut<'static>>>,
+    resources: Vec<(ComponentId, bool)>,
+    commands: CommandQueue,
+    system_meta: SystemMeta,
+    world_id: Option<WorldId>,
+    archetype_generation: ArchetypeGeneration,
+}
+
+impl DynamicSystem {
+    /// Creates a dynamic system called `name` that runs `func`, without any data access.
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        func: impl FnMut(DynamicSystemContext) + Send + Sync + 'static,
+    ) -> Self {
+        let mut system_meta = SystemMeta::new::<Self>();
+        system_meta.name = name.into();
+        system_meta.set_has_deferred();
+        Self {
+            func: Box::new(func),
+            query_builders: Vec::new(),
+            queries: Vec::new(),
+            resources: Vec::new(),
+            commands: CommandQueue::default(),
+            system_meta,
+            world_id: None,
+            archetype_generation: ArchetypeGeneration::initial(),
+        }
+    }
+
+    /// Adds a query built by `build` when the system is initialized.
+    ///
+    /// Queries are available through [`DynamicSystemContext::query`], in the order they were
+    /// added. The builder is usually filled in with [`QueryBuilder::ref_id`],
+    /// [`QueryBuilder::mut_id`], [`QueryBuilder::with_id`] and [`QueryBuilder::without_id`].
+    ///
+    /// # Panics
+    ///
+    /// Initializing the system panics if the query conflicts with a previously added query.
+    pub fn with_query(
+        mut self,
+        build: impl FnOnce(&mut QueryBuilder<FilteredEntityMut<'static>>) + Send + Sync + 'static,
+    ) -> Self {
+        self.query_builders.push(Box::new(build));
+        self
+    }
+
+    /// Adds read access to the resource with the given id.
+    pub fn with_resource(mut self, id: ComponentId) -> Self {
+        self.resources.push((id, false));
+        self
+    }
+
+    /// Adds write access to the resource with the given id.
+    pub fn with_resource_mut(mut self, id: ComponentId) -> Self {
+        self.resources.push((id, true));
+        self
+    }
+
+    /// Returns the [`DynamicSystemSet`] that contains this system.
+    pub fn system_set(&self) -> DynamicSystemSet {
+        DynamicSystemSet(self.system_meta.name.clone())
+    }
+
+    fn init_resources(&mut self, world: &mut World) {
+        for &(id, mutable) in &self.resources {
+            let name = world
+                .components()
+                .get_name(id)
+                .unwrap_or_else(|| panic!("{id:?} is not a registered resource"))
+                .to_string();
+            let combined_access = self.system_meta.component_access_set.combined_access();
+            if combined_access.has_write(id) || (mutable && combined_access.has_read(id)) {
+                panic!(
+                    "error[B0002]: Resource {name} in dynamic system {} conflicts with a previous access of the same resource.",
+                    self.system_meta.name,
+                );
+            }
+
+            world.initialize_resource_internal(id);
+            let archetype_component_id = world.get_resource_archetype_component_id(id).unwrap();
+            if mutable {
+                self.system_meta
+                    .component_access_set
+                    .add_unfiltered_write(id);
+                self.system_meta
+                    .archetype_component_access
+                    .add_write(archetype_component_id);
+            } else {
+                self.system_meta
+                    .component_access_set
+                    .add_unfiltered_read(id);
+                self.system_meta
+                    .archetype_component_access
+                    .add_read(archetype_component_id);
+            }
+        }
+    }
+
+    fn init_queries(&mut self, world: &mut World) {
+        for build in self.query_builders.drain(..) {
+            let mut builder = QueryBuilder::new(world);
+            build(&mut builder);
+            let state = builder.build();
+
+            let conflicts = self
+                .system_meta
+                .component_access_set
+                .get_conflicts_single(&state.component_access);
+            if !conflicts.is_empty() {
+                let accesses = conflicts
+                    .into_iter()
+                    .map(|id| world.components().get_info(id).unwrap().name())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                panic!(
+                    "error[B0001]: Query {} in dynamic system {} accesses component(s) {accesses} in a way that conflicts with a previous query.",
+                    self.queries.len(),
+                    self.system_meta.name,
+                );
+            }
+            self.system_meta
+                .component_access_set
+                .add(state.component_access.clone());
+            self.queries.push(state);
+        }
+    }
+}
+
+impl System for DynamicSystem {
+    type In = ();
+    type Out = ();
+
+    #[inline]
+    fn name(&self) -> Cow<'static, str> {
+        self.system_meta.name.clone()
+    }
+
+    #[inline]
+    fn component_access(&self) -> &Access<ComponentId> {
+        self.system_meta.component_access_set.combined_access()
+    }
+
+    #[inline]
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        &self.system_meta.archetype_component_access
+    }
+
+    #[inline]
+    fn is_send(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn is_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn has_deferred(&self) -> bool {
+        true
+    }
+
+    unsafe fn run_unsafe(&mut self, _input: (), world: UnsafeWorldCell) {
+        #[cfg(feature = "trace")]
+        let _span_guard = self.system_meta.system_span.enter();
+
+        let change_tick = world.increment_change_tick();
+        let context = DynamicSystemContext {
+            world,
+            queries: &self.queries,
+            resources: &self.resources,
+            commands: &mut self.commands,
+            name: &self.system_meta.name,
+            last_run: self.system_meta.last_run,
+            this_run: change_tick,
+        };
+        (self.func)(context);
+        self.system_meta.last_run = change_tick;
+    }
+
+    #[inline]
+    fn apply_deferred(&mut self, world: &mut World) {
+        SystemBuffer::apply(&mut self.commands, &self.system_meta, world);
+    }
+
+    #[inline]
+    fn queue_deferred(&mut self, world: DeferredWorld) {
+        SystemBuffer::queue(&mut self.commands, &self.system_meta, world);
+    }
+
+    fn initialize(&mut self, world: &mut World) {
+        if let Some(id) = self.world_id {
+            assert_eq!(
+                id,
+                world.id(),
+                "System built with a different world than the one it was added to.",
+            );
+        } else {
+            self.world_id = Some(world.id());
+            self.init_resources(world);
+            self.init_queries(world);
+        }
+        self.system_meta.last_run = world.change_tick().relative_to(Tick::MAX);
+    }
+
+    fn update_archetype_component_access(&mut self, world: UnsafeWorldCell) {
+        assert_eq!(self.world_id, Some(world.id()), "Encountered a mismatched World. A System cannot be used with Worlds other than the one it was initialized with.");
+        let archetypes = world.archetypes();
+        let old_generation =
+            std::mem::replace(&mut self.archetype_generation, archetypes.generation());
+
+        for archetype in &archetypes[old_generation..] {
+            for state in &mut self.queries {
+                // SAFETY: The assertion above ensures that the queries were initialized from `world`.
+                unsafe {
+                    state
+                        .new_archetype(archetype, &mut self.system_meta.archetype_component_access);
+                };
+            }
+        }
+    }
+
+    #[inline]
+    fn check_change_tick(&mut self, change_tick: Tick) {
+        check_system_change_tick(
+            &mut self.system_meta.last_run,
+            change_tick,
+            self.system_meta.name.as_ref(),
+        );
+    }
+
+    fn default_system_sets(&self) -> Vec<InternedSystemSet> {
+        vec![self.system_set().intern()]
+    }
+
+    fn get_last_run(&self) -> Tick {
+        self.system_meta.last_run
+    }
+
+    fn set_last_run(&mut self, last_run: Tick) {
+        self.system_meta.last_run = last_run;
+    }
+}
+
+/// The [`SystemSet`] containing every [`DynamicSystem`] with the given name.
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynamicSystemSet(pub Cow<'static, str>);
+
+/// The data accessible to a running [`DynamicSystem`].
+///
+/// Only the queries and resources declared on the system can be accessed.
+pub struct DynamicSystemContext<'w, 's> {
+    world: UnsafeWorldCell<'w>,
+    queries: &'s [QueryState<FilteredEntityMut<'static>>],
+    resources: &'s [(ComponentId, bool)],
+    commands: &'s mut CommandQueue,
+    name: &'s str,
+    last_run: Tick,
+    this_run: Tick,
+}
+
+impl<'w, 's> DynamicSystemContext<'w, 's> {
+    /// Returns the query added by the `index`-th call to [`DynamicSystem::with_query`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system has fewer than `index + 1` queries.
+    pub fn query(&mut self, index: usize) -> Query<'_, 's, FilteredEntityMut<'static>> {
+        let state = self.queries.get(index).unwrap_or_else(|| {
+            panic!(
+                "Dynamic system {} has no query with index {index}",
+                self.name
+            )
+        });
+        // SAFETY:
+        // - The query's accesses were registered when the system was initialized, so the
+        //   caller of `run_unsafe` ensures that `world` has permission to access them.
+        // - The query borrows `self` mutably, so it can't alias other queries or resources
+        //   accessed through this context.
+        unsafe { Query::new(self.world, state, self.last_run, self.this_run) }
+    }
+
+    /// Returns the number of queries of the system.
+    pub fn query_count(&self) -> usize {
+        self.queries.len()
+    }
+
+    /// Returns a pointer to the resource with the given id, or `None` if it doesn't exist.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system didn't declare access to the resource.
+    pub fn resource(&self, id: ComponentId) -> Option<Ptr<'_>> {
+        self.assert_resource_access(id, false);
+        // SAFETY: The read access was registered when the system was initialized and nothing
+        // can access the resource mutably while `self` is borrowed.
+        unsafe { self.world.get_resource_by_id(id) }
+    }
+
+    /// Returns a mutable pointer to the resource with the given id, or `None` if it doesn't
+    /// exist.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system didn't declare write access to the resource.
+    pub fn resource_mut(&mut self, id: ComponentId) -> Option<MutUntyped<'_>> {
+        self.assert_resource_access(id, true);
+        // SAFETY: The write access was registered when the system was initialized and nothing
+        // else can access the resource while `self` is borrowed mutably.
+        let (ptr, ticks) = unsafe { self.world.get_resource_with_ticks(id) }?;
+        Some(MutUntyped {
+            // SAFETY: See above.
+            value: unsafe { ptr.assert_unique() },
+            // SAFETY: See above.
+            ticks: unsafe { TicksMut::from_tick_cells(ticks, self.last_run, self.this_run) },
+        })
+    }
+
+    /// Returns [`Commands`] whose effects are applied at the system's next sync point.
+    pub fn commands(&mut self) -> Commands<'w, '_> {
+        Commands::new_from_entities(self.commands, self.world.entities())
+    }
+
+    /// Returns the tick of the system's previous run.
+    pub fn last_run(&self) -> Tick {
+        self.last_run
+    }
+
+    /// Returns the tick of the system's current run.
+    pub fn this_run(&self) -> Tick {
+        self.this_run
+    }
+
+    fn assert_resource_access(&self, id: ComponentId, mutable: bool) {
+        let declared = self
+            .resources
+            .iter()
+            .any(|&(declared, declared_mut)| declared == id && (declared_mut || !mutable));
+        assert!(
+            declared,
+            "Dynamic system {} did not declare {} access to resource {id:?}",
+            self.name,
+            if mutable { "write" } else { "read" },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        prelude::*,
+        schedule::{ExecutorKind, Schedule},
+        system::{DynamicSystem, RunSystemOnce},
+    };
+
+    #[derive(Component)]
+    struct A(u32);
+
+    #[derive(Component)]
+    struct B;
+
+    #[derive(Resource)]
+    struct R(u32);
+
+    #[test]
+    fn reads_and_writes_components() {
+        let mut world = World::new();
+        let a = world.init_component::<A>();
+        let b = world.init_component::<B>();
+        world.spawn((A(1), B));
+        world.spawn(A(10));
+
+        let system = DynamicSystem::new("double", move |mut context| {
+            for mut entity in &mut context.query(0) {
+                let mut value = entity.get_mut_by_id(a).unwrap();
+                // SAFETY: `a` is the id of `A`.
+                unsafe { value.as_mut().deref_mut::<A>() }.0 *= 2;
+            }
+        })
+        .with_query(move |query| {
+            query.mut_id(a).with_id(b);
+        });
+        world.run_system_once(system);
+
+        let mut values = world
+            .query::<&A>()
+            .iter(&world)
+            .map(|a| a.0)
+            .collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(values, vec![2, 10]);
+    }
+
+    #[test]
+    fn accesses_resources_and_commands() {
+        let mut world = World::new();
+        world.insert_resource(R(3));
+        let r = world.components().resource_id::<R>().unwrap();
+
+        let system = DynamicSystem::new("spawn", move |mut context| {
+            let count = {
+                let mut r = context.resource_mut(r).unwrap();
+                // SAFETY: `r` is the id of `R`.
+                let r = unsafe { r.as_mut().deref_mut::<R>() };
+                r.0 -= 1;
+                r.0
+            };
+            for _ in 0..count {
+                context.commands().spawn(B);
+            }
+        })
+        .with_resource_mut(r);
+        world.run_system_once(system);
+
+        assert_eq!(world.resource::<R>().0, 2);
+        assert_eq!(world.query::<&B>().iter(&world).count(), 2);
+    }
+
+    #[test]
+    fn declared_access_is_tracked() {
+        let mut world = World::new();
+        let a = world.init_component::<A>();
+
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        schedule.add_systems(
+            DynamicSystem::new("write", |_| {}).with_query(move |query| {
+                query.mut_id(a);
+            }),
+        );
+        schedule.add_systems(|_: Query<&A>| {});
+        schedule.initialize(&mut world).unwrap();
+
+        let ambiguities = schedule.graph().conflicting_systems();
+        assert_eq!(ambiguities.len(), 1);
+        assert_eq!(ambiguities[0].2, vec![a]);
+    }
+
+    #[test]
+    fn added_to_running_schedule() {
+        let mut world = World::new();
+        world.insert_resource(R(0));
+        let r = world.components().resource_id::<R>().unwrap();
+
+        fn double(mut r: ResMut<R>) {
+            r.0 *= 2;
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(double);
+        schedule.run(&mut world);
+
+        let system = DynamicSystem::new("increment", move |mut context| {
+            let mut r = context.resource_mut(r).unwrap();
+            // SAFETY: `r` is the id of `R`.
+            unsafe { r.as_mut().deref_mut::<R>() }.0 += 10;
+        })
+        .with_resource_mut(r);
+        let set = system.system_set();
+        schedule.add_systems(system);
+        schedule.configure_sets(set.before(double));
+        schedule.run(&mut world);
+
+        assert_eq!(world.resource::<R>().0, 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "did not declare write access")]
+    fn undeclared_resource_access_panics() {
+        let mut world = World::new();
+        world.insert_resource(R(0));
+        let r = world.components().resource_id::<R>().unwrap();
+
+        let system = DynamicSystem::new("read", move |mut context| {
+            context.resource_mut(r);
+        })
+        .with_resource(r);
+        world.run_system_once(system);
+    }
+
+    #[test]
+    #[should_panic(expected = "conflicts with a previous query")]
+    fn conflicting_queries_panic() {
+        let mut world = World::new();
+        let a = world.init_component::<A>();
+
+        let system = DynamicSystem::new("conflict", |_| {})
+            .with_query(move |query| {
+                query.mut_id(a);
+            })
+            .with_query(move |query| {
+                query.ref_id(a);
+            });
+        world.run_system_once(system);
+    }
+}
@@ -0,0 +1,352 @@
+use std::borrow::Cow;
+
+use crate::{
+    archetype::ArchetypeComponentId,
+    component::{ComponentId, Tick},
+    error::{self, Error, SystemErrorContext, SystemErrorHandler},
+    query::Access,
+    schedule::InternedSystemSet,
+    system::{IntoSystem, System},
+    world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
+};
+
+/// Converts a system returning a [`Result`] into a [`FallibleSystem`].
+///
+/// Systems returning a [`Result`] can also be added to schedules directly,
+/// which wraps them the same way.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::error;
+/// # #[derive(Resource)]
+/// # struct Score(u32);
+/// fn increment_score(mut score: Option<ResMut<Score>>) -> error::Result {
+///     score.as_mut().ok_or("missing score")?.0 += 1;
+///     Ok(())
+/// }
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(increment_score);
+///
+/// // The wrapped system can also be run on its own.
+/// let mut world = World::new();
+/// world.insert_resource(Score(0));
+/// let mut system = increment_score.handle_errors();
+/// system.initialize(&mut world);
+/// system.run((), &mut world);
+/// ```
+pub trait IntoFallibleSystem<E, Marker>: IntoSystem<(), Result<(), E>, Marker> {
+    /// Wraps this system so that any error it returns is passed to the world's [`SystemErrorHandler`].
+    fn handle_errors(self) -> FallibleSystem<Self::System>;
+}
+
+impl<F, E, Marker> IntoFallibleSystem<E, Marker> for F
+where
+    F: IntoSystem<(), Result<(), E>, Marker>,
+    E: Into<Error> + 'static,
+{
+    fn handle_errors(self) -> FallibleSystem<Self::System> {
+        FallibleSystem::new(IntoSystem::into_system(self))
+    }
+}
+
+/// A [`System`] that runs a fallible system `S` and passes any error it returns
+/// to the world's [`SystemErrorHandler`].
+///
+/// This is how systems returning [`Result`](crate::error::Result) are added to schedules,
+/// either directly or through [`IntoFallibleSystem::handle_errors`].
+/// In addition to the accesses of `S`, this system reads the [`SystemErrorHandler`] resource.
+pub struct FallibleSystem<S> {
+    system: S,
+    handler_id: Option<ComponentId>,
+    component_access: Access<ComponentId>,
+    archetype_component_access: Access<ArchetypeComponentId>,
+}
+
+impl<S, E> FallibleSystem<S>
+where
+    S: System<In = (), Out = Result<(), E>>,
+    E: Into<Error>,
+{
+    /// Wraps the fallible `system`.
+    pub fn new(system: S) -> Self {
+        Self {
+            system,
+            handler_id: None,
+            component_access: Access::default(),
+            archetype_component_access: Access::default(),
+        }
+    }
+
+    /// Passes the `error` returned by the run of the system that followed `last_run` to `handler`.
+    fn handle(&self, error: E, last_run: Tick, handler: Option<&SystemErrorHandler>) {
+        let handler = handler.map_or(error::panic as error::SystemErrorHandlerFn, |handler| {
+            handler.0
+        });
+        handler(
+            error.into(),
+            SystemErrorContext {
+                name: self.system.name(),
+                last_run,
+            },
+        );
+    }
+}
+
+impl<S, E> System for FallibleSystem<S>
+where
+    S: System<In = (), Out = Result<(), E>>,
+    E: Into<Error> + 'static,
+{
+    type In = ();
+    type Out = ();
+
+    fn name(&self) -> Cow<'static, str> {
+        self.system.name()
+    }
+
+    fn component_access(&self) -> &Access<ComponentId> {
+        &self.component_access
+    }
+
+    #[inline]
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        &self.archetype_component_access
+    }
+
+    fn is_send(&self) -> bool {
+        self.system.is_send()
+    }
+
+    fn is_exclusive(&self) -> bool {
+        self.system.is_exclusive()
+    }
+
+    fn has_deferred(&self) -> bool {
+        self.system.has_deferred()
+    }
+
+    #[inline]
+    unsafe fn run_unsafe(&mut self, input: (), world: UnsafeWorldCell) {
+        let last_run = self.system.get_last_run();
+        // SAFETY: `system.run_unsafe` has the same invariants as `self.run_unsafe`.
+        if let Err(error) = unsafe { self.system.run_unsafe(input, world) } {
+            let handler_id = self
+                .handler_id
+                .expect("System's state was not found. Did you forget to initialize this system before running it?");
+            // SAFETY: Read access to the handler resource was registered in `initialize`.
+            let handler = unsafe { world.get_resource_by_id(handler_id) }
+                // SAFETY: `handler_id` was registered for the `SystemErrorHandler` type.
+                .map(|ptr| unsafe { ptr.deref::<SystemErrorHandler>() });
+            self.handle(error, last_run, handler);
+        }
+    }
+
+    fn run(&mut self, input: (), world: &mut World) {
+        let last_run = self.system.get_last_run();
+        if let Err(error) = self.system.run(input, world) {
+            self.handle(error, last_run, world.get_resource::<SystemErrorHandler>());
+        }
+    }
+
+    #[inline]
+    fn apply_deferred(&mut self, world: &mut World) {
+        self.system.apply_deferred(world);
+    }
+
+    #[inline]
+    fn queue_deferred(&mut self, world: DeferredWorld) {
+        self.system.queue_deferred(world);
+    }
+
+    fn initialize(&mut self, world: &mut World) {
+        self.system.initialize(world);
+
+        let handler_id = world.components.init_resource::<SystemErrorHandler>();
+        world.initialize_resource_internal(handler_id);
+        self.handler_id = Some(handler_id);
+
+        self.component_access = self.system.component_access().clone();
+        assert!(
+            !self.component_access.has_write(handler_id),
+            "Fallible system {} cannot have mutable access to the `SystemErrorHandler` resource.",
+            self.system.name(),
+        );
+        self.component_access.add_read(handler_id);
+
+        let archetype_component_id = world
+            .get_resource_archetype_component_id(handler_id)
+            .unwrap();
+        self.archetype_component_access
+            .extend(self.system.archetype_component_access());
+        self.archetype_component_access
+            .add_read(archetype_component_id);
+    }
+
+    #[inline]
+    fn update_archetype_component_access(&mut self, world: UnsafeWorldCell) {
+        self.system.update_archetype_component_access(world);
+        self.archetype_component_access
+            .extend(self.system.archetype_component_access());
+    }
+
+    fn check_change_tick(&mut self, change_tick: Tick) {
+        self.system.check_change_tick(change_tick);
+    }
+
+    fn default_system_sets(&self) -> Vec<InternedSystemSet> {
+        self.system.default_system_sets()
+    }
+
+    fn get_last_run(&self) -> Tick {
+        self.system.get_last_run()
+    }
+
+    fn set_last_run(&mut self, last_run: Tick) {
+        self.system.set_last_run(last_run);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+    use crate as bevy_ecs;
+    use crate::{
+        error::{self, SystemErrorContext, SystemErrorHandler},
+        prelude::*,
+        system::SystemChangeTick,
+    };
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
+    fn query_single(query: Query<&A>) -> error::Result {
+        query.get_single()?;
+        Ok(())
+    }
+
+    #[test]
+    fn fallible_systems_call_the_error_handler() {
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+        fn count_errors(_: error::Error, context: SystemErrorContext) {
+            assert!(context.name.ends_with("query_single"));
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::new();
+        world.insert_resource(SystemErrorHandler(count_errors));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(query_single);
+
+        schedule.run(&mut world);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+
+        world.spawn(A);
+        schedule.run(&mut world);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn fallible_systems_report_their_previous_run() {
+        static LAST_RUN: AtomicU32 = AtomicU32::new(0);
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+        fn fail(ticks: SystemChangeTick) -> error::Result {
+            LAST_RUN.store(ticks.last_run().get(), Ordering::Relaxed);
+            Err("failed".into())
+        }
+
+        fn check_last_run(_: error::Error, context: SystemErrorContext) {
+            assert_eq!(context.last_run.get(), LAST_RUN.load(Ordering::Relaxed));
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::new();
+        world.insert_resource(SystemErrorHandler(check_last_run));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(fail);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 2);
+        assert_ne!(LAST_RUN.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn fallible_systems_apply_commands() {
+        fn spawn_and_fail(mut commands: Commands) -> Result<(), std::fmt::Error> {
+            commands.init_resource::<Counter>();
+            Err(std::fmt::Error)
+        }
+
+        let mut world = World::new();
+        world.insert_resource(SystemErrorHandler(error::ignore));
+        let mut schedule = Schedule::default();
+        schedule.add_systems((spawn_and_fail, query_single).chain());
+        schedule.run(&mut world);
+        assert!(world.contains_resource::<Counter>());
+    }
+
+    #[test]
+    #[should_panic = "Encountered an error in system"]
+    fn fallible_systems_panic_by_default() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(query_single);
+        schedule.run(&mut world);
+    }
+
+    #[test]
+    fn exclusive_fallible_systems_call_the_error_handler() {
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+        fn count_errors(_: error::Error, context: SystemErrorContext) {
+            assert!(context.name.ends_with("remove_counter"));
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn remove_counter(world: &mut World) -> error::Result {
+            world
+                .remove_resource::<Counter>()
+                .ok_or("missing counter")?;
+            Ok(())
+        }
+
+        let mut world = World::new();
+        world.insert_resource(SystemErrorHandler(count_errors));
+        world.init_resource::<Counter>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(remove_counter);
+
+        schedule.run(&mut world);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 0);
+
+        schedule.run(&mut world);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+
+        let mut system = remove_counter.handle_errors();
+        system.initialize(&mut world);
+        system.run((), &mut world);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fallible_systems_cannot_write_the_handler() {
+        fn replace_handler(mut handler: ResMut<SystemErrorHandler>) -> error::Result {
+            handler.0 = error::ignore;
+            Ok(())
+        }
+
+        let mut world = World::new();
+        world.init_resource::<SystemErrorHandler>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(replace_handler);
+        schedule.run(&mut world);
+    }
+}
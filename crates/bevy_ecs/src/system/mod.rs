@@ -105,8 +105,10 @@ mod adapter_system;
 mod builder;
 mod combinator;
 mod commands;
+mod dynamic_system;
 mod exclusive_function_system;
 mod exclusive_system_param;
+mod fallible_system;
 mod function_system;
 mod observer_system;
 mod query;
@@ -122,8 +124,10 @@ pub use adapter_system::*;
 pub use builder::*;
 pub use combinator::*;
 pub use commands::*;
+pub use dynamic_system::*;
 pub use exclusive_function_system::*;
 pub use exclusive_system_param::*;
+pub use fallible_system::*;
 pub use function_system::*;
 pub use observer_system::*;
 pub use query::*;
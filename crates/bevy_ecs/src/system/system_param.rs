@@ -3,7 +3,7 @@ use crate::{
     archetype::{Archetype, Archetypes},
     bundle::Bundles,
     change_detection::{Ticks, TicksMut},
-    component::{ComponentId, ComponentTicks, Components, Tick},
+    component::{ComponentCloneFn, ComponentId, ComponentTicks, Components, Tick},
     entity::Entities,
     prelude::QueryBuilder,
     query::{
@@ -485,7 +485,15 @@ impl_param_set!();
     label = "invalid `Resource`",
     note = "consider annotating `{Self}` with `#[derive(Resource)]`"
 )]
-pub trait Resource: Send + Sync + 'static {}
+pub trait Resource: Send + Sync + 'static {
+    /// Returns the [`ComponentCloneFn`] used to clone this resource, if it can be cloned.
+    ///
+    /// `#[derive(Resource)]` returns [`component_clone_via_clone`](crate::component::component_clone_via_clone)
+    /// for types that implement [`Clone`], and `None` otherwise.
+    fn clone_fn() -> Option<ComponentCloneFn> {
+        None
+    }
+}
 
 // SAFETY: Res only reads a single World resource
 unsafe impl<'a, T: Resource> ReadOnlySystemParam for Res<'a, T> {}
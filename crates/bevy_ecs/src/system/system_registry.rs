@@ -1,8 +1,16 @@
 use crate::entity::Entity;
-use crate::system::{BoxedSystem, IntoSystem};
+use crate::system::{BoxedSystem, IntoSystem, System};
 use crate::world::{Command, World};
 use crate::{self as bevy_ecs};
-use bevy_ecs_macros::Component;
+use bevy_ecs_macros::{Component, Resource};
+use concurrent_queue::ConcurrentQueue;
+use std::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll, Waker},
+};
 use thiserror::Error;
 
 /// A small wrapper for [`BoxedSystem`] that also keeps track whether or not the system has been initialized.
@@ -12,6 +20,13 @@ struct RegisteredSystem<I, O> {
     system: BoxedSystem<I, O>,
 }
 
+/// The [`SystemId`] of a system registered with [`World::register_system_cached`],
+/// stored as a resource keyed by the type of the system.
+///
+/// This can be used to check whether a system is cached, see [`World::cached_system_id`].
+#[derive(Resource)]
+pub struct CachedSystemId<S: System>(pub SystemId<S::In, S::Out>);
+
 /// A system that has been removed from the registry.
 /// It contains the system and whether or not it has been initialized.
 ///
@@ -316,6 +331,101 @@ impl World {
         }
         Ok(result)
     }
+
+    /// Registers a system, or returns its cached [`SystemId`] if it was already registered this way.
+    ///
+    /// The [`SystemId`] is cached in a [`CachedSystemId`] resource keyed by the type of the system,
+    /// so only zero-sized systems, such as function items and closures that don't capture anything, can be cached.
+    ///
+    /// The cached system keeps its state between runs, like any other registered system.
+    /// Use [`World::unregister_system_cached`] to evict it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `S` is not a zero-sized type, such as a capturing closure or a function pointer.
+    pub fn register_system_cached<I: 'static, O: 'static, M, S: IntoSystem<I, O, M> + 'static>(
+        &mut self,
+        system: S,
+    ) -> SystemId<I, O> {
+        assert_is_cacheable::<S>();
+
+        if let Some(id) = self.cached_system_id(&system) {
+            if self
+                .get_entity(id.entity)
+                .is_some_and(|entity| entity.contains::<RegisteredSystem<I, O>>())
+            {
+                return id;
+            }
+        }
+
+        let id = self.register_system(system);
+        self.insert_resource(CachedSystemId::<S::System>(id));
+        id
+    }
+
+    /// Returns the [`SystemId`] cached for `system` by [`World::register_system_cached`], if any.
+    pub fn cached_system_id<I: 'static, O: 'static, M, S: IntoSystem<I, O, M> + 'static>(
+        &self,
+        _system: &S,
+    ) -> Option<SystemId<I, O>> {
+        self.get_resource::<CachedSystemId<S::System>>()
+            .map(|cached| cached.0)
+    }
+
+    /// Removes a system cached with [`World::register_system_cached`] and returns it.
+    ///
+    /// Returns [`RegisteredSystemError::SystemNotCached`] if `system` is not cached.
+    pub fn unregister_system_cached<I: 'static, O: 'static, M, S: IntoSystem<I, O, M> + 'static>(
+        &mut self,
+        _system: S,
+    ) -> Result<RemovedSystem<I, O>, RegisteredSystemError<I, O>> {
+        let id = self
+            .remove_resource::<CachedSystemId<S::System>>()
+            .ok_or(RegisteredSystemError::SystemNotCached)?;
+        self.remove_system(id.0)
+    }
+
+    /// Runs a cached system, registering it if necessary.
+    ///
+    /// See [`World::register_system_cached`] for more information.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// fn count(mut counter: Local<u32>) -> u32 {
+    ///     *counter += 1;
+    ///     *counter
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// assert_eq!(world.run_system_cached(count).unwrap(), 1);
+    /// assert_eq!(world.run_system_cached(count).unwrap(), 2);
+    /// ```
+    pub fn run_system_cached<O: 'static, M, S: IntoSystem<(), O, M> + 'static>(
+        &mut self,
+        system: S,
+    ) -> Result<O, RegisteredSystemError<(), O>> {
+        self.run_system_cached_with(system, ())
+    }
+
+    /// Runs a cached system with the provided input, registering it if necessary.
+    ///
+    /// See [`World::register_system_cached`] for more information.
+    pub fn run_system_cached_with<I: 'static, O: 'static, M, S: IntoSystem<I, O, M> + 'static>(
+        &mut self,
+        system: S,
+        input: I,
+    ) -> Result<O, RegisteredSystemError<I, O>> {
+        let id = self.register_system_cached(system);
+        self.run_system_with_input(id, input)
+    }
+}
+
+fn assert_is_cacheable<S>() {
+    assert!(
+        std::mem::size_of::<S>() == 0,
+        "Non-ZST systems (e.g. capturing closures, function pointers) cannot be cached: {}",
+        std::any::type_name::<S>()
+    );
 }
 
 /// The [`Command`] type for [`World::run_system`] or [`World::run_system_with_input`].
@@ -326,9 +436,9 @@ impl World {
 /// If the system needs an [`In<_>`](crate::system::In) input value to run, it must
 /// be provided as part of the command.
 ///
-/// There is no way to get the output of a system when run as a command, because the
-/// execution of the system happens later. To get the output of a system, use
-/// [`World::run_system`] or [`World::run_system_with_input`] instead of running the system as a command.
+/// The output of a system run as this command is discarded, because the execution of the system happens later.
+/// To get the output of a system, use [`World::run_system`] or [`World::run_system_with_input`], or
+/// [`RunSystemWithOutput`] to receive it through a [`SystemTicket`].
 #[derive(Debug, Clone)]
 pub struct RunSystemWithInput<I: 'static> {
     system_id: SystemId<I>,
@@ -343,9 +453,9 @@ pub struct RunSystemWithInput<I: 'static> {
 /// If the system needs an [`In<_>`](crate::system::In) input value to run, use the
 /// [`RunSystemWithInput`] type instead.
 ///
-/// There is no way to get the output of a system when run as a command, because the
-/// execution of the system happens later. To get the output of a system, use
-/// [`World::run_system`] or [`World::run_system_with_input`] instead of running the system as a command.
+/// The output of a system run as this command is discarded, because the execution of the system happens later.
+/// To get the output of a system, use [`World::run_system`] or [`World::run_system_with_input`], or
+/// [`RunSystemWithOutput`] to receive it through a [`SystemTicket`].
 pub type RunSystem = RunSystemWithInput<()>;
 
 impl RunSystem {
@@ -370,6 +480,57 @@ impl<I: 'static + Send> Command for RunSystemWithInput<I> {
     }
 }
 
+/// The [`Command`] type for [`World::run_system_cached_with`].
+///
+/// This command runs systems in an exclusive and single threaded way.
+/// Running slow systems can become a bottleneck.
+pub struct RunSystemCachedWith<S, I, O, M>
+where
+    S: IntoSystem<I, O, M> + Send + 'static,
+    I: Send + 'static,
+    O: 'static,
+    M: 'static,
+{
+    system: S,
+    input: I,
+    _marker: PhantomData<fn() -> (O, M)>,
+}
+
+impl<S, I, O, M> RunSystemCachedWith<S, I, O, M>
+where
+    S: IntoSystem<I, O, M> + Send + 'static,
+    I: Send + 'static,
+    O: 'static,
+    M: 'static,
+{
+    /// Creates a new [`Command`] struct, which can be added to [`Commands`](crate::system::Commands)
+    /// in order to run the specified cached system with the provided [`In<_>`](crate::system::In) input value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `S` is not a zero-sized type, see [`World::register_system_cached`].
+    pub fn new(system: S, input: I) -> Self {
+        assert_is_cacheable::<S>();
+        Self {
+            system,
+            input,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, I, O, M> Command for RunSystemCachedWith<S, I, O, M>
+where
+    S: IntoSystem<I, O, M> + Send + 'static,
+    I: Send + 'static,
+    O: 'static,
+    M: 'static,
+{
+    fn apply(self, world: &mut World) {
+        let _ = world.run_system_cached_with(self.system, self.input);
+    }
+}
+
 /// The [`Command`] type for registering one shot systems from [Commands](crate::system::Commands).
 ///
 /// This command needs an already boxed system to register, and an already spawned entity
@@ -399,6 +560,238 @@ impl<I: 'static + Send, O: 'static + Send> Command for RegisterSystem<I, O> {
     }
 }
 
+type SystemTicketResult<I, O> = Result<O, RegisteredSystemError<I, O>>;
+
+struct SystemTicketState<I, O> {
+    result: Option<SystemTicketResult<I, O>>,
+    waker: Option<Waker>,
+}
+
+/// A handle to the output of a one-shot system that was queued to run later,
+/// returned by [`Commands::run_system_with_output`](crate::system::Commands::run_system_with_output)
+/// and [`SystemRequestSender::run_system`].
+///
+/// The ticket resolves once the system has run. Its output can either be polled with [`SystemTicket::try_take`],
+/// or awaited, as [`SystemTicket`] implements [`Future`].
+/// If the queued command is dropped without being applied, for example because its command queue was dropped,
+/// the ticket resolves to [`RegisteredSystemError::SystemNotRun`].
+pub struct SystemTicket<I = (), O = ()> {
+    state: Arc<Mutex<SystemTicketState<I, O>>>,
+}
+
+impl<I, O> SystemTicket<I, O> {
+    fn new() -> (Self, SystemTicketCompleter<I, O>) {
+        let state = Arc::new(Mutex::new(SystemTicketState {
+            result: None,
+            waker: None,
+        }));
+        (
+            Self {
+                state: state.clone(),
+            },
+            SystemTicketCompleter {
+                state,
+                completed: false,
+            },
+        )
+    }
+
+    /// Returns `true` if the system has run and its output has not been taken yet.
+    pub fn is_ready(&self) -> bool {
+        self.lock().result.is_some()
+    }
+
+    /// Takes the output of the system, if it has run.
+    ///
+    /// Returns `None` if the system has not run yet, or if its output was already taken.
+    pub fn try_take(&self) -> Option<SystemTicketResult<I, O>> {
+        self.lock().result.take()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SystemTicketState<I, O>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<I, O> Future for SystemTicket<I, O> {
+    type Output = SystemTicketResult<I, O>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.lock();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// The sending side of a [`SystemTicket`].
+///
+/// Dropping it before [`complete`](Self::complete) is called resolves the ticket to an error.
+struct SystemTicketCompleter<I, O> {
+    state: Arc<Mutex<SystemTicketState<I, O>>>,
+    completed: bool,
+}
+
+impl<I, O> SystemTicketCompleter<I, O> {
+    fn complete(mut self, result: SystemTicketResult<I, O>) {
+        self.resolve(result);
+    }
+
+    fn resolve(&mut self, result: SystemTicketResult<I, O>) {
+        self.completed = true;
+        let waker = {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            state.result = Some(result);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<I, O> Drop for SystemTicketCompleter<I, O> {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.resolve(Err(RegisteredSystemError::SystemNotRun));
+        }
+    }
+}
+
+/// The [`Command`] type for [`Commands::run_system_with_output`](crate::system::Commands::run_system_with_output)
+/// and [`Commands::run_system_with_input_and_output`](crate::system::Commands::run_system_with_input_and_output).
+///
+/// Unlike [`RunSystemWithInput`], the output of the system is sent to the [`SystemTicket`] created alongside the command.
+pub struct RunSystemWithOutput<I: 'static, O: 'static> {
+    system_id: SystemId<I, O>,
+    input: I,
+    completer: SystemTicketCompleter<I, O>,
+}
+
+impl<I: 'static, O: 'static> RunSystemWithOutput<I, O> {
+    /// Creates a new [`Command`] struct, which can be added to [`Commands`](crate::system::Commands)
+    /// in order to run the specified system with the provided [`In<_>`](crate::system::In) input value,
+    /// and the [`SystemTicket`] that will hold its output.
+    pub fn new_with_input(system_id: SystemId<I, O>, input: I) -> (Self, SystemTicket<I, O>) {
+        let (ticket, completer) = SystemTicket::new();
+        (
+            Self {
+                system_id,
+                input,
+                completer,
+            },
+            ticket,
+        )
+    }
+}
+
+impl<I: 'static + Send, O: 'static + Send> Command for RunSystemWithOutput<I, O> {
+    fn apply(self, world: &mut World) {
+        let result = world.run_system_with_input(self.system_id, self.input);
+        self.completer.complete(result);
+    }
+}
+
+type SystemRequest = Box<dyn FnOnce(&mut World) + Send>;
+
+/// The queue of one-shot systems requested through [`SystemRequestSender`]s, applied by [`apply_system_requests`].
+#[derive(Resource)]
+pub struct SystemRequests {
+    queue: Arc<ConcurrentQueue<SystemRequest>>,
+}
+
+impl Default for SystemRequests {
+    fn default() -> Self {
+        Self {
+            queue: Arc::new(ConcurrentQueue::unbounded()),
+        }
+    }
+}
+
+/// A cloneable handle that queues one-shot systems to run on a [`World`] from outside of it,
+/// such as from async tasks, and returns [`SystemTicket`]s to await their output.
+///
+/// Requested systems run when [`apply_system_requests`] runs on the world,
+/// which apps do once per frame when the `SystemRequestsPlugin` is added.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::apply_system_requests;
+/// # use bevy_tasks::block_on;
+/// #[derive(Resource)]
+/// struct Score(u32);
+///
+/// let mut world = World::new();
+/// world.insert_resource(Score(5));
+/// let get_score = world.register_system(|score: Res<Score>| score.0);
+///
+/// let sender = world.system_request_sender();
+/// let ticket = sender.run_system(get_score);
+///
+/// // Usually run once per frame by the app.
+/// apply_system_requests(&mut world);
+/// assert_eq!(block_on(ticket).unwrap(), 5);
+/// ```
+#[derive(Clone)]
+pub struct SystemRequestSender {
+    queue: Arc<ConcurrentQueue<SystemRequest>>,
+}
+
+impl SystemRequestSender {
+    /// Requests to run the system corresponding to the given [`SystemId`], and returns a [`SystemTicket`]
+    /// that resolves to its output once the request has been applied.
+    pub fn run_system<O: 'static + Send>(&self, id: SystemId<(), O>) -> SystemTicket<(), O> {
+        self.run_system_with_input(id, ())
+    }
+
+    /// Requests to run the system corresponding to the given [`SystemId`] with the provided input, and returns a
+    /// [`SystemTicket`] that resolves to its output once the request has been applied.
+    pub fn run_system_with_input<I: 'static + Send, O: 'static + Send>(
+        &self,
+        id: SystemId<I, O>,
+        input: I,
+    ) -> SystemTicket<I, O> {
+        let (command, ticket) = RunSystemWithOutput::new_with_input(id, input);
+        // The queue is unbounded and never closed, so pushing cannot fail.
+        let _ = self
+            .queue
+            .push(Box::new(move |world: &mut World| command.apply(world)));
+        ticket
+    }
+}
+
+impl World {
+    /// Returns a [`SystemRequestSender`] that queues one-shot systems to run on this world,
+    /// initializing the [`SystemRequests`] resource if needed.
+    pub fn system_request_sender(&mut self) -> SystemRequestSender {
+        SystemRequestSender {
+            queue: self
+                .get_resource_or_insert_with(SystemRequests::default)
+                .queue
+                .clone(),
+        }
+    }
+}
+
+/// Runs the one-shot systems requested through [`SystemRequestSender`]s, in the order they were requested.
+///
+/// Systems requested while applying are run as well.
+pub fn apply_system_requests(world: &mut World) {
+    let Some(queue) = world
+        .get_resource::<SystemRequests>()
+        .map(|requests| requests.queue.clone())
+    else {
+        return;
+    };
+    while let Ok(request) = queue.pop() {
+        request(world);
+    }
+}
+
 /// An operation with stored systems failed.
 #[derive(Error)]
 pub enum RegisteredSystemError<I = (), O = ()> {
@@ -413,6 +806,12 @@ pub enum RegisteredSystemError<I = (), O = ()> {
     /// A system tried to remove itself.
     #[error("System {0:?} tried to remove itself")]
     SelfRemove(SystemId<I, O>),
+    /// A cached system was removed, but it was not cached.
+    #[error("System was not cached")]
+    SystemNotCached,
+    /// A system was queued to run, but its command was dropped before being applied.
+    #[error("System was dropped before it could run")]
+    SystemNotRun,
 }
 
 impl<I, O> std::fmt::Debug for RegisteredSystemError<I, O> {
@@ -423,6 +822,8 @@ impl<I, O> std::fmt::Debug for RegisteredSystemError<I, O> {
             }
             Self::Recursive(arg0) => f.debug_tuple("Recursive").field(arg0).finish(),
             Self::SelfRemove(arg0) => f.debug_tuple("SelfRemove").field(arg0).finish(),
+            Self::SystemNotCached => write!(f, "SystemNotCached"),
+            Self::SystemNotRun => write!(f, "SystemNotRun"),
         }
     }
 }
@@ -621,4 +1022,133 @@ mod tests {
         let _ = world.run_system(nested_id);
         assert_eq!(*world.resource::<Counter>(), Counter(5));
     }
+
+    #[test]
+    fn run_system_with_output_command() {
+        use crate::system::{RegisteredSystemError, SystemId};
+        use crate::world::CommandQueue;
+
+        let mut world = World::new();
+        world.insert_resource(Counter(3));
+        let get_counter = world.register_system(|counter: Res<Counter>| counter.0);
+
+        let not_a_system = world.spawn_empty().id();
+        world.despawn(not_a_system);
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let ticket = commands.run_system_with_output(get_counter);
+        let missing =
+            commands.run_system_with_output(SystemId::<(), u8>::from_entity(not_a_system));
+        assert!(!ticket.is_ready());
+
+        queue.apply(&mut world);
+        assert!(ticket.is_ready());
+        assert_eq!(ticket.try_take().unwrap().unwrap(), 3);
+        assert!(ticket.try_take().is_none());
+        assert!(matches!(
+            missing.try_take(),
+            Some(Err(RegisteredSystemError::SystemIdNotRegistered(_)))
+        ));
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let dropped = commands.run_system_with_output(get_counter);
+        drop(queue);
+        assert!(matches!(
+            dropped.try_take(),
+            Some(Err(RegisteredSystemError::SystemNotRun))
+        ));
+    }
+
+    #[test]
+    fn system_requests_from_other_thread() {
+        use crate::system::apply_system_requests;
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+        let increment_by =
+            world.register_system(|In(amt): In<u8>, mut counter: ResMut<Counter>| {
+                counter.0 += amt;
+                counter.0
+            });
+
+        let sender = world.system_request_sender();
+        let task = std::thread::spawn(move || {
+            let first = bevy_tasks::block_on(sender.run_system_with_input(increment_by, 2));
+            let second = bevy_tasks::block_on(sender.run_system_with_input(increment_by, 3));
+            (first.unwrap(), second.unwrap())
+        });
+
+        while !task.is_finished() {
+            apply_system_requests(&mut world);
+            std::thread::yield_now();
+        }
+        assert_eq!(task.join().unwrap(), (2, 5));
+        assert_eq!(*world.resource::<Counter>(), Counter(5));
+    }
+
+    #[test]
+    fn cached_system() {
+        use crate::system::RegisteredSystemError;
+
+        fn four() -> i32 {
+            4
+        }
+
+        fn count(In(step): In<u32>, mut counter: Local<u32>) -> u32 {
+            *counter += step;
+            *counter
+        }
+
+        let mut world = World::new();
+        assert!(world.cached_system_id(&four).is_none());
+
+        let id = world.register_system_cached(four);
+        assert_eq!(world.register_system_cached(four), id);
+        assert_eq!(world.cached_system_id(&four), Some(id));
+        assert_eq!(world.run_system_cached(four).unwrap(), 4);
+
+        assert_eq!(world.run_system_cached_with(count, 2).unwrap(), 2);
+        assert_eq!(world.run_system_cached_with(count, 3).unwrap(), 5);
+
+        assert!(world.unregister_system_cached(count).is_ok());
+        assert!(matches!(
+            world.unregister_system_cached(count),
+            Err(RegisteredSystemError::SystemNotCached)
+        ));
+        assert_eq!(world.run_system_cached_with(count, 3).unwrap(), 3);
+
+        // A removed cached system is registered again.
+        world.remove_system(id).unwrap();
+        assert_ne!(world.register_system_cached(four), id);
+    }
+
+    #[test]
+    fn cached_system_commands() {
+        fn increment(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        fn add(In(amount): In<u8>, mut counter: ResMut<Counter>) {
+            counter.0 += amount;
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+        let mut commands = world.commands();
+        commands.run_system_cached(increment);
+        commands.run_system_cached(increment);
+        commands.run_system_cached_with(add, 3);
+        world.flush();
+        assert_eq!(*world.resource::<Counter>(), Counter(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be cached")]
+    fn cached_system_non_zst() {
+        let value = 1;
+        let mut world = World::new();
+        let _ = world.run_system_cached(move || value);
+    }
 }
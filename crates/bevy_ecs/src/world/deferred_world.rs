@@ -6,7 +6,7 @@ use crate::{
     component::ComponentId,
     entity::Entity,
     event::{Event, EventId, Events, SendBatchIds},
-    observer::{Observers, TriggerTargets},
+    observer::{ObserverPhase, Observers, TriggerState, TriggerTargets},
     prelude::{Component, QueryState},
     query::{QueryData, QueryFilter},
     system::{Commands, Query, Resource},
@@ -378,11 +378,13 @@ impl<'w> DeferredWorld<'w> {
             entity,
             components,
             &mut (),
-            &mut false,
+            ObserverPhase::Bubble,
+            &mut TriggerState::default(),
         );
     }
 
-    /// Triggers all event observers for [`ComponentId`] in target.
+    /// Triggers all event observers for [`ComponentId`] in target, running the capture phase from the root
+    /// of the traversal path down to `entity`, then bubbling up from `entity` while the event propagates.
     ///
     /// # Safety
     /// Caller must ensure `E` is accessible as the type represented by `event`
@@ -393,10 +395,40 @@ impl<'w> DeferredWorld<'w> {
         mut entity: Entity,
         components: &[ComponentId],
         data: &mut E,
-        mut propagate: bool,
-    ) where
+        propagate: bool,
+    ) -> TriggerState
+    where
         C: Traversal,
     {
+        let mut state = TriggerState::default();
+        // SAFETY: Observer metadata is only read here
+        if unsafe { self.world.observers() }.has_capture_observers(event) {
+            let mut path = vec![entity];
+            if entity != Entity::PLACEHOLDER {
+                while let Some(traverse_to) =
+                    self.get::<C>(*path.last().unwrap()).and_then(C::traverse)
+                {
+                    path.push(traverse_to);
+                }
+            }
+            state.propagate = true;
+            for &current in path.iter().rev() {
+                Observers::invoke::<_>(
+                    self.reborrow(),
+                    event,
+                    current,
+                    components.iter().copied(),
+                    data,
+                    ObserverPhase::Capture,
+                    &mut state,
+                );
+                if !state.propagate {
+                    return state;
+                }
+            }
+        }
+
+        state.propagate = propagate;
         loop {
             Observers::invoke::<_>(
                 self.reborrow(),
@@ -404,9 +436,10 @@ impl<'w> DeferredWorld<'w> {
                 entity,
                 components.iter().copied(),
                 data,
-                &mut propagate,
+                ObserverPhase::Bubble,
+                &mut state,
             );
-            if !propagate {
+            if !state.propagate {
                 break;
             }
             if let Some(traverse_to) = self.get::<C>(entity).and_then(C::traverse) {
@@ -415,6 +448,7 @@ impl<'w> DeferredWorld<'w> {
                 break;
             }
         }
+        state
     }
 
     /// Sends a "global" [`Trigger`](crate::observer::Trigger) without any targets.
@@ -1,8 +1,8 @@
-//! Contains error types returned by bevy's schedule.
+//! Contains error types returned by bevy's schedule and world.
 
 use thiserror::Error;
 
-use crate::schedule::InternedScheduleLabel;
+use crate::{component::ComponentId, schedule::InternedScheduleLabel, world::WorldId};
 
 /// The error type returned by [`World::try_run_schedule`] if the provided schedule does not exist.
 ///
@@ -10,3 +10,27 @@ use crate::schedule::InternedScheduleLabel;
 #[derive(Error, Debug)]
 #[error("The schedule with the label {0:?} was not found.")]
 pub struct TryRunScheduleError(pub InternedScheduleLabel);
+
+/// The error type returned by [`World::snapshot`] and [`World::restore`].
+///
+/// [`World::snapshot`]: crate::world::World::snapshot
+/// [`World::restore`]: crate::world::World::restore
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum WorldSnapshotError {
+    /// A component or resource stored in the world cannot be cloned.
+    #[error("The component or resource {name} cannot be cloned, so it cannot be part of a world snapshot. Implement `Clone` for it, or remove it from the world first.")]
+    Uncloneable {
+        /// The id of the component or resource.
+        id: ComponentId,
+        /// The name of the component or resource.
+        name: String,
+    },
+    /// The snapshot was taken from a different world.
+    #[error("The snapshot was taken from world {snapshot:?} and cannot be restored into world {world:?}.")]
+    WorldMismatch {
+        /// The id of the world the snapshot was taken from.
+        snapshot: WorldId,
+        /// The id of the world the snapshot was restored into.
+        world: WorldId,
+    },
+}
@@ -0,0 +1,311 @@
+use bevy_utils::HashMap;
+
+use crate::{
+    archetype::ArchetypeId,
+    component::{ComponentId, StorageType},
+    entity::Entity,
+    storage::{MemoryUsage, TableId},
+    world::World,
+};
+
+/// A report of the memory used by a [`World`], created by [`World::memory_usage`].
+///
+/// Every [`MemoryUsage`] only counts the memory owned directly by the ECS storages: heap
+/// allocations owned by components and resources themselves, like the buffer of a `Vec`
+/// component, are not included. The difference between [`MemoryUsage::allocated`] and
+/// [`MemoryUsage::used`] is capacity that was reserved but is not used, for example because
+/// entities were despawned or moved to another archetype.
+#[derive(Clone, Debug, Default)]
+pub struct WorldMemoryUsage {
+    /// Memory used by each component type, sorted by allocated bytes, largest first.
+    pub components: Vec<ComponentMemoryUsage>,
+    /// Memory used by each [`Table`](crate::storage::Table), including its entity list.
+    pub tables: Vec<TableMemoryUsage>,
+    /// Memory used by the entities of each [`Archetype`](crate::archetype::Archetype).
+    pub archetypes: Vec<ArchetypeMemoryUsage>,
+    /// Memory used by each resource, sorted by allocated bytes, largest first.
+    pub resources: Vec<ResourceMemoryUsage>,
+    /// Memory used for the metadata of every entity id.
+    pub entities: MemoryUsage,
+}
+
+/// The memory used by all values of a single component type.
+#[derive(Clone, Debug)]
+pub struct ComponentMemoryUsage {
+    /// The id of the component.
+    pub id: ComponentId,
+    /// The name of the component.
+    pub name: String,
+    /// Where the component is stored.
+    pub storage_type: StorageType,
+    /// The memory used by the component's columns in all tables, or by its sparse set,
+    /// including change ticks.
+    pub usage: MemoryUsage,
+}
+
+/// The memory used by a single [`Table`](crate::storage::Table).
+#[derive(Clone, Debug)]
+pub struct TableMemoryUsage {
+    /// The id of the table.
+    pub id: TableId,
+    /// The number of entities stored in the table.
+    pub entity_count: usize,
+    /// The memory used by all columns of the table and its entity list.
+    pub usage: MemoryUsage,
+}
+
+/// The memory used by the entities of a single [`Archetype`](crate::archetype::Archetype).
+#[derive(Clone, Debug)]
+pub struct ArchetypeMemoryUsage {
+    /// The id of the archetype.
+    pub id: ArchetypeId,
+    /// The table that stores the archetype's [`Table`](StorageType::Table) components.
+    pub table_id: TableId,
+    /// The number of entities in the archetype.
+    pub entity_count: usize,
+    /// The memory used by the archetype's own list of entities.
+    pub entity_list: MemoryUsage,
+    /// The memory used by the archetype's entity list and by the components of its entities,
+    /// both in its table and in sparse sets.
+    ///
+    /// Unused capacity of tables is reported in [`WorldMemoryUsage::tables`], since a table can be
+    /// shared by multiple archetypes.
+    pub usage: MemoryUsage,
+}
+
+/// The memory used by a single resource.
+#[derive(Clone, Debug)]
+pub struct ResourceMemoryUsage {
+    /// The id of the resource.
+    pub id: ComponentId,
+    /// The name of the resource.
+    pub name: String,
+    /// Whether this is a `Send` resource or a `!Send` resource.
+    pub is_send: bool,
+    /// The memory used by the resource's value.
+    pub usage: MemoryUsage,
+}
+
+impl WorldMemoryUsage {
+    /// Returns the memory used by the whole world.
+    pub fn total(&self) -> MemoryUsage {
+        self.storage(StorageType::Table)
+            + self.storage(StorageType::SparseSet)
+            + self
+                .archetypes
+                .iter()
+                .map(|archetype| archetype.entity_list)
+                .sum()
+            + self.resources()
+            + self.entities
+    }
+
+    /// Returns the memory used by all tables, or all sparse sets.
+    pub fn storage(&self, storage_type: StorageType) -> MemoryUsage {
+        match storage_type {
+            StorageType::Table => self.tables.iter().map(|table| table.usage).sum(),
+            StorageType::SparseSet => self
+                .components
+                .iter()
+                .filter(|component| component.storage_type == StorageType::SparseSet)
+                .map(|component| component.usage)
+                .sum(),
+        }
+    }
+
+    /// Returns the memory used by all resources.
+    pub fn resources(&self) -> MemoryUsage {
+        self.resources.iter().map(|resource| resource.usage).sum()
+    }
+}
+
+impl World {
+    /// Returns a report of the memory used by this world's components, tables, archetypes,
+    /// resources and entities.
+    ///
+    /// This walks every storage of the world, so it takes time proportional to the number of
+    /// tables, archetypes, components and resources, but not to the number of entities.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Position([f32; 3]);
+    ///
+    /// let mut world = World::new();
+    /// world.spawn_batch((0..100).map(|_| Position([0.0; 3])));
+    ///
+    /// let usage = world.memory_usage();
+    /// let position = &usage.components[0];
+    /// assert!(position.name.ends_with("Position"));
+    /// assert!(position.usage.used >= 100 * std::mem::size_of::<Position>());
+    /// assert!(usage.total().allocated >= position.usage.allocated);
+    /// ```
+    pub fn memory_usage(&self) -> WorldMemoryUsage {
+        let component_infos = self.components();
+        let storages = self.storages();
+
+        let mut component_usage: HashMap<ComponentId, MemoryUsage> = HashMap::new();
+        let tables = storages
+            .tables
+            .iter()
+            .enumerate()
+            .map(|(index, table)| {
+                for (id, column) in table.columns() {
+                    *component_usage.entry(id).or_default() += column.memory_usage();
+                }
+                TableMemoryUsage {
+                    id: TableId::from_usize(index),
+                    entity_count: table.entity_count(),
+                    usage: table.memory_usage(),
+                }
+            })
+            .collect();
+        for (id, sparse_set) in storages.sparse_sets.iter() {
+            *component_usage.entry(id).or_default() += sparse_set.memory_usage();
+        }
+
+        let mut components = component_usage
+            .into_iter()
+            .map(|(id, usage)| {
+                let info = component_infos.get_info(id).unwrap();
+                ComponentMemoryUsage {
+                    id,
+                    name: info.name().to_string(),
+                    storage_type: info.storage_type(),
+                    usage,
+                }
+            })
+            .collect::<Vec<_>>();
+        components.sort_by_key(|component| std::cmp::Reverse(component.usage.allocated));
+
+        let archetypes = self
+            .archetypes()
+            .iter()
+            .map(|archetype| {
+                let table = &storages.tables[archetype.table_id()];
+                // each entity also takes a slot in its table's entity list
+                let mut row_size = std::mem::size_of::<Entity>();
+                for id in archetype.table_components() {
+                    row_size += table.get_column(id).unwrap().row_size();
+                }
+                for id in archetype.sparse_set_components() {
+                    row_size += storages.sparse_sets.get(id).unwrap().row_size();
+                }
+                let data = archetype.len() * row_size;
+                let entity_list = archetype.memory_usage();
+                ArchetypeMemoryUsage {
+                    id: archetype.id(),
+                    table_id: archetype.table_id(),
+                    entity_count: archetype.len(),
+                    usage: MemoryUsage {
+                        used: entity_list.used + data,
+                        allocated: entity_list.allocated + data,
+                    },
+                    entity_list,
+                }
+            })
+            .collect();
+
+        let send = storages
+            .resources
+            .iter()
+            .map(|(id, data)| (id, true, data.memory_usage()));
+        let non_send = storages
+            .non_send_resources
+            .iter()
+            .map(|(id, data)| (id, false, data.memory_usage()));
+        let mut resources = send
+            .chain(non_send)
+            .map(|(id, is_send, usage)| ResourceMemoryUsage {
+                id,
+                name: component_infos.get_name(id).unwrap_or_default().to_string(),
+                is_send,
+                usage,
+            })
+            .collect::<Vec<_>>();
+        resources.sort_by_key(|resource| std::cmp::Reverse(resource.usage.allocated));
+
+        WorldMemoryUsage {
+            components,
+            tables,
+            archetypes,
+            resources,
+            entities: self.entities().memory_usage(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{component::StorageType, prelude::*, storage::MemoryUsage};
+
+    #[derive(Component)]
+    struct A(u64);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct B(u32);
+
+    #[derive(Resource)]
+    struct R([u8; 64]);
+
+    #[test]
+    fn reports_components_by_storage() {
+        let mut world = World::new();
+        world.spawn_batch((0..10).map(|i| (A(i), B(i as u32))));
+
+        let usage = world.memory_usage();
+        let a = usage
+            .components
+            .iter()
+            .find(|component| component.name.ends_with("A"))
+            .unwrap();
+        assert_eq!(a.storage_type, StorageType::Table);
+        assert!(a.usage.used >= 10 * std::mem::size_of::<A>());
+        assert!(a.usage.allocated >= a.usage.used);
+
+        let b = usage
+            .components
+            .iter()
+            .find(|component| component.name.ends_with("B"))
+            .unwrap();
+        assert_eq!(b.storage_type, StorageType::SparseSet);
+        assert!(usage.storage(StorageType::SparseSet).allocated >= b.usage.allocated);
+
+        let archetype = usage
+            .archetypes
+            .iter()
+            .find(|archetype| archetype.entity_count == 10)
+            .unwrap();
+        assert!(archetype.usage.used >= 10 * (std::mem::size_of::<A>() + std::mem::size_of::<B>()));
+    }
+
+    #[test]
+    fn despawning_leaves_wasted_capacity() {
+        let mut world = World::new();
+        let entities = world.spawn_batch((0..100).map(A)).collect::<Vec<_>>();
+        let before = world.memory_usage().storage(StorageType::Table);
+
+        for entity in entities {
+            world.despawn(entity);
+        }
+        let after = world.memory_usage().storage(StorageType::Table);
+        assert_eq!(after.allocated, before.allocated);
+        assert!(after.used < before.used);
+        assert!(after.wasted() > 0);
+    }
+
+    #[test]
+    fn reports_resources() {
+        let mut world = World::new();
+        assert_eq!(world.memory_usage().resources(), MemoryUsage::default());
+
+        world.insert_resource(R([0; 64]));
+        let usage = world.memory_usage();
+        assert_eq!(usage.resources.len(), 1);
+        assert!(usage.resources[0].is_send);
+        assert!(usage.resources[0].usage.used >= 64);
+        assert!(usage.total().used >= usage.resources().used + usage.entities.used);
+    }
+}
@@ -6,6 +6,8 @@ mod deferred_world;
 mod entity_ref;
 pub mod error;
 mod identifier;
+mod memory_usage;
+mod snapshot;
 mod spawn_batch;
 pub mod unsafe_world_cell;
 
@@ -20,6 +22,11 @@ pub use entity_ref::{
     OccupiedEntry, VacantEntry,
 };
 pub use identifier::WorldId;
+pub use memory_usage::{
+    ArchetypeMemoryUsage, ComponentMemoryUsage, ResourceMemoryUsage, TableMemoryUsage,
+    WorldMemoryUsage,
+};
+pub use snapshot::WorldSnapshot;
 pub use spawn_batch::*;
 
 use crate::{
@@ -31,6 +38,7 @@ use crate::{
         Components, Tick,
     },
     entity::{AllocAtWithoutReplacement, Entities, Entity, EntityHashSet, EntityLocation},
+    entity_disabling::{DefaultQueryFilters, Disabled},
     event::{Event, EventId, Events, SendBatchIds},
     observer::Observers,
     query::{DebugCheckedUnwrap, QueryData, QueryEntityError, QueryFilter, QueryState},
@@ -120,6 +128,7 @@ pub struct World {
     pub(crate) last_check_tick: Tick,
     pub(crate) last_trigger_id: u32,
     pub(crate) command_queue: RawCommandQueue,
+    pub(crate) default_query_filters: DefaultQueryFilters,
 }
 
 impl Default for World {
@@ -140,6 +149,7 @@ impl Default for World {
             last_check_tick: Tick::new(0),
             last_trigger_id: 0,
             command_queue: RawCommandQueue::new(),
+            default_query_filters: DefaultQueryFilters::default(),
         };
         world.bootstrap();
         world
@@ -166,6 +176,7 @@ impl World {
         assert_eq!(ON_INSERT, self.init_component::<OnInsert>());
         assert_eq!(ON_REPLACE, self.init_component::<OnReplace>());
         assert_eq!(ON_REMOVE, self.init_component::<OnRemove>());
+        self.register_disabling_component::<Disabled>();
     }
     /// Creates a new empty [`World`].
     ///
@@ -0,0 +1,383 @@
+use crate::{
+    archetype::{Archetype, ArchetypeEntity},
+    component::{ComponentId, ComponentInfo, Components, Tick},
+    entity::Entities,
+    storage::{Column, ComponentSparseSet, Storages, Table, TableId, TableRow},
+    world::{error::WorldSnapshotError, World, WorldId},
+};
+use std::sync::atomic::Ordering;
+
+/// An opaque, in-memory checkpoint of a [`World`], created by [`World::snapshot`]
+/// and restored with [`World::restore`].
+///
+/// A snapshot holds a copy of every entity, component value and resource in the world,
+/// including their change ticks and the state of the entity allocator, so that restoring it
+/// returns the world to exactly the state it was in when the snapshot was taken:
+/// entities keep their ids and generations, and change detection sees the same ticks.
+///
+/// Component and resource values are copied with their [`ComponentCloneFn`](crate::component::ComponentCloneFn),
+/// which `#[derive(Component)]` and `#[derive(Resource)]` provide for types that implement [`Clone`].
+/// `!Send` resources are not part of the snapshot and are left untouched by [`World::restore`].
+pub struct WorldSnapshot {
+    world_id: WorldId,
+    entities: Entities,
+    archetypes: Vec<Vec<ArchetypeEntity>>,
+    tables: Vec<Table>,
+    sparse_sets: Vec<(ComponentId, ComponentSparseSet)>,
+    resources: Vec<(ComponentId, Column)>,
+    change_tick: u32,
+    last_change_tick: Tick,
+    last_check_tick: Tick,
+    last_trigger_id: u32,
+}
+
+// SAFETY: A snapshot only stores copies of `Send + Sync` components and resources,
+// and can only be accessed through `&World` and `&mut World` methods.
+unsafe impl Send for WorldSnapshot {}
+// SAFETY: A snapshot only stores copies of `Send + Sync` components and resources,
+// and can only be accessed through `&World` and `&mut World` methods.
+unsafe impl Sync for WorldSnapshot {}
+
+impl WorldSnapshot {
+    /// Returns the id of the [`World`] this snapshot was taken from.
+    #[inline]
+    pub fn world_id(&self) -> WorldId {
+        self.world_id
+    }
+
+    /// Returns the number of entities stored in this snapshot.
+    #[inline]
+    pub fn entity_count(&self) -> u32 {
+        self.entities.len()
+    }
+}
+
+impl World {
+    /// Takes a [`WorldSnapshot`] of every entity, component and resource in this world,
+    /// which can later be passed to [`World::restore`] to roll the world back.
+    ///
+    /// Any commands queued in the world are applied first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WorldSnapshotError::Uncloneable`] if a component or resource stored in the world
+    /// cannot be cloned, such as the [`Observer`](crate::observer::Observer) component.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Position(f32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Position(0.0)).id();
+    /// let snapshot = world.snapshot().unwrap();
+    ///
+    /// world.entity_mut(entity).insert(Position(10.0));
+    /// world.spawn(Position(5.0));
+    ///
+    /// world.restore(&snapshot).unwrap();
+    /// assert_eq!(world.get::<Position>(entity), Some(&Position(0.0)));
+    /// assert_eq!(world.entities().len(), 1);
+    /// ```
+    pub fn snapshot(&mut self) -> Result<WorldSnapshot, WorldSnapshotError> {
+        self.flush();
+        self.validate_snapshot()?;
+
+        let components = &self.components;
+        let storages = &self.storages;
+
+        let tables = storages
+            .tables
+            .iter()
+            .map(|table| table.try_clone(components).expect(VALIDATED))
+            .collect();
+        let sparse_sets = storages
+            .sparse_sets
+            .iter()
+            .map(|(id, set)| {
+                // SAFETY: Sparse sets are keyed by the id of the component they store.
+                let set = unsafe { set.try_clone(component_info(components, id)) };
+                (id, set.expect(VALIDATED))
+            })
+            .collect();
+        let resources = storages
+            .resources
+            .iter()
+            .filter_map(|(id, data)| {
+                let (value, ticks) = data.get_data().zip(data.get_ticks())?;
+                let component_info = component_info(components, id);
+                let clone_fn = component_info.clone_fn().expect(VALIDATED);
+                let mut column = Column::with_capacity(component_info, 1);
+                // SAFETY: `value` is a resource of the type described by `component_info`,
+                // so `clone_fn` is valid for it and `column` stores the same type.
+                unsafe {
+                    clone_fn(value, &mut |value| column.push(value, ticks));
+                }
+                Some((id, column))
+            })
+            .collect();
+
+        Ok(WorldSnapshot {
+            world_id: self.id(),
+            entities: self.entities.clone(),
+            archetypes: self
+                .archetypes
+                .iter()
+                .map(|archetype| archetype.entities().to_vec())
+                .collect(),
+            tables,
+            sparse_sets,
+            resources,
+            change_tick: self.change_tick.load(Ordering::Relaxed),
+            last_change_tick: self.last_change_tick,
+            last_check_tick: self.last_check_tick,
+            last_trigger_id: self.last_trigger_id,
+        })
+    }
+
+    /// Restores this world to the state captured by `snapshot`.
+    ///
+    /// Entities spawned since the snapshot was taken are removed, despawned entities are brought back
+    /// with the same ids, and every component value, resource and change tick is reset.
+    /// Component hooks and observers are not triggered. The snapshot is left intact,
+    /// so it can be restored any number of times.
+    ///
+    /// Components and resources registered since the snapshot was taken stay registered, but hold no values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WorldSnapshotError::WorldMismatch`] if `snapshot` was taken from another world,
+    /// and [`WorldSnapshotError::Uncloneable`] if the world currently stores a component or resource
+    /// that cannot be cloned, since that data could not have been part of any snapshot.
+    /// Apart from applying queued commands, the world is not modified if an error is returned.
+    pub fn restore(&mut self, snapshot: &WorldSnapshot) -> Result<(), WorldSnapshotError> {
+        if snapshot.world_id != self.id() {
+            return Err(WorldSnapshotError::WorldMismatch {
+                snapshot: snapshot.world_id,
+                world: self.id(),
+            });
+        }
+        self.flush();
+        self.validate_snapshot()?;
+
+        let World {
+            entities,
+            components,
+            archetypes,
+            storages,
+            ..
+        } = self;
+        let Storages {
+            tables,
+            sparse_sets,
+            resources,
+            ..
+        } = storages;
+
+        *entities = snapshot.entities.clone();
+
+        for (index, archetype) in archetypes.archetypes.iter_mut().enumerate() {
+            match snapshot.archetypes.get(index) {
+                Some(entities) => archetype.set_entities(entities.clone()),
+                None => archetype.clear_entities(),
+            }
+        }
+
+        for index in 0..tables.len() {
+            let table = &mut tables[TableId::from_usize(index)];
+            match snapshot.tables.get(index) {
+                Some(snapshot) => {
+                    *table = snapshot.try_clone(components).expect(VALIDATED);
+                }
+                None => table.clear(),
+            }
+        }
+
+        for (id, set) in sparse_sets.iter_mut() {
+            match snapshot
+                .sparse_sets
+                .iter()
+                .find(|(snapshot_id, _)| *snapshot_id == id)
+            {
+                Some((_, snapshot)) => {
+                    // SAFETY: Sparse sets are keyed by the id of the component they store.
+                    *set = unsafe { snapshot.try_clone(component_info(components, id)) }
+                        .expect(VALIDATED);
+                }
+                None => set.clear(),
+            }
+        }
+
+        for (_, data) in resources.iter_mut() {
+            data.remove_and_drop();
+        }
+        for (id, column) in &snapshot.resources {
+            let clone_fn = component_info(components, *id).clone_fn().expect(VALIDATED);
+            let data = resources
+                .initialize_with(*id, components, || archetypes.new_archetype_component_id());
+            // SAFETY:
+            // - The column stores a single value of the resource `id`, which `clone_fn` was registered for.
+            // - `clone_fn` passes a value of the same type to `insert_with_ticks`.
+            unsafe {
+                let row = TableRow::from_usize(0);
+                let ticks = column.get_ticks_unchecked(row);
+                clone_fn(column.get_data_unchecked(row), &mut |value| {
+                    data.insert_with_ticks(value, ticks);
+                });
+            }
+        }
+
+        *self.change_tick.get_mut() = snapshot.change_tick;
+        self.last_change_tick = snapshot.last_change_tick;
+        self.last_check_tick = snapshot.last_check_tick;
+        self.last_trigger_id = snapshot.last_trigger_id;
+        Ok(())
+    }
+
+    /// Returns an error if any value stored in the world cannot be cloned.
+    fn validate_snapshot(&self) -> Result<(), WorldSnapshotError> {
+        let components = self
+            .archetypes
+            .iter()
+            .filter(|archetype| !archetype.is_empty())
+            .flat_map(Archetype::components);
+        let resources = self
+            .storages
+            .resources
+            .iter()
+            .filter(|(_, data)| data.is_present())
+            .map(|(id, _)| id);
+
+        match components
+            .chain(resources)
+            .find(|&id| component_info(&self.components, id).clone_fn().is_none())
+        {
+            Some(id) => Err(WorldSnapshotError::Uncloneable {
+                id,
+                name: component_info(&self.components, id).name().to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+const VALIDATED: &str = "stored values were checked to be cloneable by `validate_snapshot`";
+
+fn component_info(components: &Components, id: ComponentId) -> &ComponentInfo {
+    components
+        .get_info(id)
+        .expect("stored components and resources are registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        change_detection::DetectChanges,
+        prelude::*,
+        world::{error::WorldSnapshotError, World},
+    };
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct A(u32);
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    #[component(storage = "SparseSet")]
+    struct B(String);
+
+    #[derive(Component)]
+    struct NotClone;
+
+    #[derive(Resource, Clone, Debug, PartialEq)]
+    struct R(u32);
+
+    #[derive(Resource, Clone, Debug, PartialEq)]
+    struct Added;
+
+    #[test]
+    fn restore_entities_and_components() {
+        let mut world = World::new();
+        let e1 = world.spawn(A(1)).id();
+        let e2 = world.spawn((A(2), B("two".into()))).id();
+        let snapshot = world.snapshot().unwrap();
+        let next = world.spawn_empty().id();
+
+        world.entity_mut(e1).insert(B("one".into()));
+        world.get_mut::<A>(e2).unwrap().0 = 20;
+        world.despawn(e2);
+        let e3 = world.spawn(A(3)).id();
+
+        world.restore(&snapshot).unwrap();
+        assert_eq!(world.entities().len(), 2);
+        assert_eq!(world.get::<A>(e1), Some(&A(1)));
+        assert_eq!(world.get::<B>(e1), None);
+        assert_eq!(world.get::<A>(e2), Some(&A(2)));
+        assert_eq!(world.get::<B>(e2), Some(&B("two".into())));
+        assert!(world.get_entity(e3).is_none());
+        assert_eq!(world.query::<&A>().iter(&world).count(), 2);
+        assert_eq!(world.query::<&B>().iter(&world).count(), 1);
+
+        // The allocator is restored too, so the same id is handed out again.
+        assert_eq!(world.spawn_empty().id(), next);
+
+        // A snapshot can be restored more than once.
+        world.restore(&snapshot).unwrap();
+        assert_eq!(world.entities().len(), 2);
+    }
+
+    #[test]
+    fn restore_resources_and_ticks() {
+        let mut world = World::new();
+        world.insert_resource(R(1));
+        let entity = world.spawn(A(1)).id();
+        world.increment_change_tick();
+        let change_tick = world.read_change_tick();
+        let component_tick = world.entity(entity).get_ref::<A>().unwrap().last_changed();
+        let resource_tick = world.resource_ref::<R>().last_changed();
+        let snapshot = world.snapshot().unwrap();
+
+        world.resource_mut::<R>().0 = 2;
+        world.insert_resource(Added);
+        world.get_mut::<A>(entity).unwrap().0 = 2;
+        world.increment_change_tick();
+
+        world.restore(&snapshot).unwrap();
+        assert_eq!(world.read_change_tick(), change_tick);
+        assert_eq!(world.resource::<R>(), &R(1));
+        assert!(!world.contains_resource::<Added>());
+
+        let component = world.entity(entity).get_ref::<A>().unwrap();
+        assert_eq!(component.last_changed(), component_tick);
+        assert_ne!(world.resource_ref::<R>().last_changed(), change_tick);
+        assert_eq!(world.resource_ref::<R>().last_changed(), resource_tick);
+    }
+
+    #[test]
+    fn uncloneable_components() {
+        let mut world = World::new();
+        let entity = world.spawn((A(1), NotClone)).id();
+        let error = world.snapshot().err().unwrap();
+        assert!(matches!(
+            error,
+            WorldSnapshotError::Uncloneable { ref name, .. } if name.ends_with("NotClone")
+        ));
+
+        world.entity_mut(entity).remove::<NotClone>();
+        let snapshot = world.snapshot().unwrap();
+        world.entity_mut(entity).insert(NotClone);
+        assert!(world.restore(&snapshot).is_err());
+        assert!(world.entity(entity).contains::<NotClone>());
+    }
+
+    #[test]
+    fn restore_into_other_world() {
+        let mut world = World::new();
+        let snapshot = world.snapshot().unwrap();
+        let mut other = World::new();
+        assert!(matches!(
+            other.restore(&snapshot),
+            Err(WorldSnapshotError::WorldMismatch { .. })
+        ));
+    }
+}
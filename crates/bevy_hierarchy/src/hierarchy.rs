@@ -1,6 +1,10 @@
-use crate::components::{Children, Parent};
+use crate::{
+    child_builder::BuildChildren,
+    components::{Children, Parent},
+};
 use bevy_ecs::{
-    entity::Entity,
+    component::ComponentCloneHandler,
+    entity::{ComponentCloneCtx, Entity, EntityCloneBuilder},
     system::EntityCommands,
     world::{Command, EntityWorldMut, World},
 };
@@ -137,18 +141,74 @@ impl<'w> DespawnRecursiveExt for EntityWorldMut<'w> {
     }
 }
 
+/// Trait that holds functions for cloning entity hierarchies with an [`EntityCloneBuilder`].
+pub trait CloneEntityHierarchyExt {
+    /// Sets the option to recursively clone the children of the entity, adding the clones as children of the clone.
+    fn recursive(&mut self, recursive: bool) -> &mut Self;
+
+    /// Sets the option to add the clone as a child of the source entity's parent.
+    fn as_child(&mut self, as_child: bool) -> &mut Self;
+}
+
+impl CloneEntityHierarchyExt for EntityCloneBuilder<'_> {
+    fn recursive(&mut self, recursive: bool) -> &mut Self {
+        if recursive {
+            self.override_component_clone_handler::<Children>(ComponentCloneHandler::Custom(
+                component_clone_children,
+            ))
+        } else {
+            self.remove_component_clone_handler_override::<Children>()
+        }
+    }
+
+    fn as_child(&mut self, as_child: bool) -> &mut Self {
+        if as_child {
+            self.override_component_clone_handler::<Parent>(ComponentCloneHandler::Custom(
+                component_clone_parent,
+            ))
+        } else {
+            self.remove_component_clone_handler_override::<Parent>()
+        }
+    }
+}
+
+/// Clones each child of the source entity, and adds the clones as children of the target entity.
+fn component_clone_children(ctx: &mut ComponentCloneCtx) {
+    let Some(children) = ctx.read_source_component::<Children>() else {
+        return;
+    };
+    let parent = ctx.target();
+    for &child in children.iter() {
+        let child_clone = ctx.queue_entity_clone(child);
+        ctx.commands().entity(parent).add_child(child_clone);
+    }
+}
+
+/// Adds the target entity as a child of the source entity's parent,
+/// unless the parent is cloned as well, in which case the parent's clone adopts it.
+fn component_clone_parent(ctx: &mut ComponentCloneCtx) {
+    let Some(parent) = ctx.read_source_component::<Parent>().map(Parent::get) else {
+        return;
+    };
+    if ctx.mapped_entity(parent).is_none() {
+        let child = ctx.target();
+        ctx.commands().entity(parent).add_child(child);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bevy_ecs::{
         component::Component,
+        entity::EntityCloneBuilder,
         system::Commands,
         world::{CommandQueue, World},
     };
 
-    use super::DespawnRecursiveExt;
+    use super::{CloneEntityHierarchyExt, DespawnRecursiveExt};
     use crate::{
         child_builder::{BuildChildren, ChildBuild},
-        components::Children,
+        components::{Children, Parent},
     };
 
     #[derive(Component, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Debug)]
@@ -281,4 +341,44 @@ mod tests {
         // The original child should be despawned.
         assert!(world.get_entity(child).is_none());
     }
+
+    #[test]
+    fn clone_entity_recursive() {
+        let mut world = World::default();
+        let root = world.spawn(N("root".into())).id();
+        let parent = world.spawn(N("parent".into())).set_parent(root).id();
+        let child = world.spawn(N("child".into())).set_parent(parent).id();
+
+        let clone = world.spawn_empty().id();
+        let mut builder = EntityCloneBuilder::new(&mut world);
+        builder.recursive(true).as_child(true);
+        builder.clone_entity(parent, clone);
+
+        assert_eq!(world.get::<N>(clone), Some(&N("parent".into())));
+        assert_eq!(world.get::<Parent>(clone).map(Parent::get), Some(root));
+        assert_eq!(world.get::<Children>(root).unwrap().len(), 2);
+
+        let clone_children = world.get::<Children>(clone).unwrap();
+        assert_eq!(clone_children.len(), 1);
+        let child_clone = clone_children[0];
+        assert_ne!(child_clone, child);
+        assert_eq!(world.get::<N>(child_clone), Some(&N("child".into())));
+        assert_eq!(
+            world.get::<Parent>(child_clone).map(Parent::get),
+            Some(clone)
+        );
+        assert_eq!(&**world.get::<Children>(parent).unwrap(), &[child]);
+    }
+
+    #[test]
+    fn clone_entity_without_hierarchy() {
+        let mut world = World::default();
+        let parent = world.spawn_empty().id();
+        let child = world.spawn(N("child".into())).set_parent(parent).id();
+
+        let clone = world.clone_entity(child);
+        assert_eq!(world.get::<N>(clone), Some(&N("child".into())));
+        assert!(world.get::<Parent>(clone).is_none());
+        assert_eq!(&**world.get::<Children>(parent).unwrap(), &[child]);
+    }
 }
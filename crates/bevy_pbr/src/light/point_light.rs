@@ -1,3 +1,5 @@
+use bevy_reflect::attributes::{ColorPicker, DisplayName, NumericRange};
+
 use super::*;
 
 /// A light that emits light in all directions from a central point.
@@ -21,17 +23,22 @@ use super::*;
 #[reflect(Component, Default)]
 pub struct PointLight {
     /// The color of this light source.
+    #[reflect(@ColorPicker::RGB)]
     pub color: Color,
     /// Luminous power in lumens, representing the amount of light emitted by this source in all directions.
+    #[reflect(@NumericRange::at_least(0.0).with_step(1000.0))]
+    #[reflect(@DisplayName::new("Intensity (lm)"))]
     pub intensity: f32,
     /// Cut-off for the light's area-of-effect. Fragments outside this range will not be affected by
     /// this light at all, so it's important to tune this together with `intensity` to prevent hard
     /// lighting cut-offs.
+    #[reflect(@NumericRange::at_least(0.0).with_step(0.1))]
     pub range: f32,
     /// Simulates a light source coming from a spherical volume with the given radius. Only affects
     /// the size of specular highlights created by this light. Because of this, large values may not
     /// produce the intended result -- for example, light radius does not affect shadow softness or
     /// diffuse lighting.
+    #[reflect(@NumericRange::at_least(0.0).with_step(0.01))]
     pub radius: f32,
     /// Whether this light casts shadows.
     pub shadows_enabled: bool,
@@ -40,10 +47,12 @@ pub struct PointLight {
     /// Too high of a depth bias can lead to shadows detaching from their casters, or
     /// "peter-panning". This bias can be tuned together with `shadow_normal_bias` to correct shadow
     /// artifacts for a given scene.
+    #[reflect(@NumericRange::at_least(0.0).with_step(0.01))]
     pub shadow_depth_bias: f32,
     /// A bias applied along the direction of the fragment's surface normal. It is scaled to the
     /// shadow map's texel size so that it can be small close to the camera and gets larger further
     /// away.
+    #[reflect(@NumericRange::at_least(0.0).with_step(0.01))]
     pub shadow_normal_bias: f32,
 }
 
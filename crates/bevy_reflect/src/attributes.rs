@@ -1,7 +1,9 @@
+use crate as bevy_reflect;
 use crate::Reflect;
 use bevy_utils::TypeIdMap;
 use core::fmt::{Debug, Formatter};
 use std::any::TypeId;
+use std::borrow::Cow;
 
 /// A collection of custom attributes for a type, field, or variant.
 ///
@@ -116,6 +118,172 @@ impl Debug for CustomAttribute {
     }
 }
 
+/// A standard attribute describing the valid range of a numeric field.
+///
+/// Bounds and step are stored as `f64` so that editors can handle every numeric type the same way.
+/// Each of them is optional: a range with no bounds only provides a step for drag widgets.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{attributes::NumericRange, Reflect, Typed, TypeInfo};
+/// #[derive(Reflect)]
+/// struct Volume {
+///   #[reflect(@NumericRange::between(0.0, 1.0).with_step(0.05))]
+///   level: f32
+/// }
+///
+/// let TypeInfo::Struct(info) = <Volume as Typed>::type_info() else {
+///   panic!("expected struct info");
+/// };
+///
+/// let range = info.field("level").unwrap().get_attribute::<NumericRange>().unwrap();
+/// assert_eq!(Some(0.05), range.step);
+/// assert_eq!(1.0, range.clamp(1.5));
+/// ```
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Default)]
+pub struct NumericRange {
+    /// The inclusive lower bound, if any.
+    pub min: Option<f64>,
+    /// The inclusive upper bound, if any.
+    pub max: Option<f64>,
+    /// The increment editors should use when dragging or stepping the value, if any.
+    pub step: Option<f64>,
+}
+
+impl NumericRange {
+    /// A range with no bounds and no step.
+    pub const UNBOUNDED: Self = Self {
+        min: None,
+        max: None,
+        step: None,
+    };
+
+    /// Creates a range bounded on both ends.
+    pub const fn between(min: f64, max: f64) -> Self {
+        Self {
+            min: Some(min),
+            max: Some(max),
+            step: None,
+        }
+    }
+
+    /// Creates a range with only a lower bound.
+    pub const fn at_least(min: f64) -> Self {
+        Self {
+            min: Some(min),
+            max: None,
+            step: None,
+        }
+    }
+
+    /// Creates a range with only an upper bound.
+    pub const fn at_most(max: f64) -> Self {
+        Self {
+            min: None,
+            max: Some(max),
+            step: None,
+        }
+    }
+
+    /// Sets the step of this range.
+    pub const fn with_step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Returns `true` if `value` lies within the bounds of this range.
+    pub fn contains(&self, value: f64) -> bool {
+        self.min.map_or(true, |min| value >= min) && self.max.map_or(true, |max| value <= max)
+    }
+
+    /// Restricts `value` to the bounds of this range.
+    pub fn clamp(&self, value: f64) -> f64 {
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+}
+
+/// A standard attribute providing a human-readable name for a type, field, or variant.
+///
+/// Editors should prefer this over the field or type name when labeling it.
+#[derive(Reflect, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DisplayName(pub Cow<'static, str>);
+
+impl DisplayName {
+    /// Creates a new display name.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+
+    /// Returns the display name as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A standard attribute providing a short help text for a type, field, or variant.
+///
+/// Unlike doc comments, tooltips are always available, regardless of the `documentation` feature.
+#[derive(Reflect, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tooltip(pub Cow<'static, str>);
+
+impl Tooltip {
+    /// Creates a new tooltip.
+    pub fn new(text: impl Into<Cow<'static, str>>) -> Self {
+        Self(text.into())
+    }
+
+    /// Returns the tooltip text as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A standard attribute marking a field or variant that should not be shown in inspectors.
+///
+/// The item is still reflected, serialized, and accessible through reflection.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Hidden;
+
+/// A standard attribute marking a field that inspectors should display but not let users edit.
+///
+/// This is purely a hint: it does not prevent mutation through reflection.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ReadOnly;
+
+/// A standard attribute asking editors to display a field with a color picker.
+///
+/// This is most useful for fields that store color components directly, such as `[f32; 4]`,
+/// but can also refine the widget used for color types.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ColorPicker {
+    /// Whether the picker should allow editing the alpha channel.
+    pub alpha: bool,
+    /// Whether the picker should allow component values above `1.0`.
+    pub hdr: bool,
+}
+
+impl ColorPicker {
+    /// A picker for opaque colors.
+    pub const RGB: Self = Self {
+        alpha: false,
+        hdr: false,
+    };
+
+    /// A picker for colors with an alpha channel.
+    pub const RGBA: Self = Self {
+        alpha: true,
+        hdr: false,
+    };
+
+    /// Allows component values above `1.0`.
+    pub const fn with_hdr(mut self) -> Self {
+        self.hdr = true;
+        self
+    }
+}
+
 /// Implements methods for accessing custom attributes.
 ///
 /// Implements the following methods:
@@ -176,6 +344,7 @@ pub(crate) use impl_custom_attribute_methods;
 
 #[cfg(test)]
 mod tests {
+    use super::Tooltip as StandardTooltip;
     use super::*;
     use crate as bevy_reflect;
     use crate::type_info::Typed;
@@ -427,6 +596,72 @@ mod tests {
         assert!(field.has_attribute::<Required>());
     }
 
+    #[test]
+    fn should_derive_standard_attributes() {
+        #[derive(Reflect)]
+        struct Light {
+            #[reflect(@ColorPicker::RGB.with_hdr())]
+            color: [f32; 3],
+            #[reflect(@NumericRange::at_least(0.0).with_step(10.0))]
+            #[reflect(@DisplayName::new("Intensity (lm)"))]
+            #[reflect(@StandardTooltip::new("Luminous power in lumens"))]
+            intensity: f32,
+            #[reflect(@ReadOnly)]
+            id: u32,
+            #[reflect(@Hidden)]
+            dirty: bool,
+        }
+
+        let TypeInfo::Struct(info) = Light::type_info() else {
+            panic!("expected struct info");
+        };
+
+        let color = info.field("color").unwrap();
+        assert_eq!(
+            Some(&ColorPicker {
+                alpha: false,
+                hdr: true
+            }),
+            color.get_attribute::<ColorPicker>()
+        );
+
+        let intensity = info.field("intensity").unwrap();
+        let range = intensity.get_attribute::<NumericRange>().unwrap();
+        assert_eq!(Some(0.0), range.min);
+        assert_eq!(None, range.max);
+        assert_eq!(Some(10.0), range.step);
+        assert_eq!(
+            "Intensity (lm)",
+            intensity.get_attribute::<DisplayName>().unwrap().as_str()
+        );
+        assert_eq!(
+            "Luminous power in lumens",
+            intensity
+                .get_attribute::<StandardTooltip>()
+                .unwrap()
+                .as_str()
+        );
+
+        assert!(info.field("id").unwrap().has_attribute::<ReadOnly>());
+        assert!(info.field("dirty").unwrap().has_attribute::<Hidden>());
+        assert!(!info.field("id").unwrap().has_attribute::<Hidden>());
+    }
+
+    #[test]
+    fn should_clamp_numeric_range() {
+        let range = NumericRange::between(-1.0, 1.0);
+        assert!(range.contains(0.5));
+        assert!(!range.contains(1.5));
+        assert_eq!(-1.0, range.clamp(-3.0));
+        assert_eq!(1.0, range.clamp(3.0));
+
+        let range = NumericRange::at_most(10.0);
+        assert_eq!(-100.0, range.clamp(-100.0));
+        assert_eq!(10.0, range.clamp(100.0));
+
+        assert_eq!(42.0, NumericRange::UNBOUNDED.clamp(42.0));
+    }
+
     #[test]
     fn should_accept_last_attribute() {
         #[derive(Reflect)]
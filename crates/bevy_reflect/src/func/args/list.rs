@@ -64,6 +64,11 @@ impl<'a> ArgList<'a> {
         self.0.pop()
     }
 
+    /// Returns an iterator over the arguments in the list.
+    pub fn iter(&self) -> impl Iterator<Item = &Arg<'a>> {
+        self.0.iter()
+    }
+
     /// Returns the number of arguments in the list.
     pub fn len(&self) -> usize {
         self.0.len()
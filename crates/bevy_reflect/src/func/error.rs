@@ -1,9 +1,11 @@
 use crate::func::args::ArgError;
+use alloc::borrow::Cow;
 use thiserror::Error;
 
-/// An error that occurs when calling a [`DynamicFunction`].
+/// An error that occurs when calling a [`DynamicFunction`] or a function in a [`FunctionRegistry`].
 ///
 /// [`DynamicFunction`]: crate::func::DynamicFunction
+/// [`FunctionRegistry`]: crate::func::FunctionRegistry
 #[derive(Debug, Error, PartialEq)]
 pub enum FunctionError {
     /// An error occurred while converting an argument.
@@ -12,4 +14,13 @@ pub enum FunctionError {
     /// The number of arguments provided does not match the expected number.
     #[error("expected {expected} arguments but received {received}")]
     InvalidArgCount { expected: usize, received: usize },
+    /// No function is registered under the given name.
+    #[error("no function named `{name}` is registered")]
+    NotFound { name: Cow<'static, str> },
+    /// None of the overloads of the function accept the provided argument types.
+    #[error("no overload of `{name}` accepts the arguments {received}")]
+    NoMatchingOverload {
+        name: Cow<'static, str>,
+        received: Cow<'static, str>,
+    },
 }
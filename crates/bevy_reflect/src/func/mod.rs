@@ -12,6 +12,9 @@
 //! This returns a [`FunctionResult`] containing the [`Return`] value,
 //! which can be used to extract a [`Reflect`] trait object.
 //!
+//! Functions and methods can also be registered by name in a [`FunctionRegistry`]
+//! (or in the [`ReflectMethods`] of their type), and later looked up and called with a string,
+//! choosing between overloads based on the types of the arguments.
 //!
 //! # Example
 //!
@@ -40,6 +43,7 @@ pub use error::*;
 pub use function::*;
 pub use info::*;
 pub use into_function::*;
+pub use registry::*;
 pub use return_type::*;
 
 pub use args::{Arg, ArgError, ArgList};
@@ -50,6 +54,7 @@ mod function;
 mod info;
 mod into_function;
 pub(crate) mod macros;
+mod registry;
 mod return_type;
 
 #[cfg(test)]
@@ -0,0 +1,513 @@
+use crate::func::args::{Arg, ArgInfo, ArgList, Ownership};
+use crate::func::{DynamicFunction, FunctionError, FunctionInfo, FunctionResult, IntoFunction};
+use crate::Reflect;
+use alloc::borrow::Cow;
+use bevy_utils::HashMap;
+use core::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// A function that can be stored in a [`FunctionRegistry`] and called any number of times.
+///
+/// Unlike a [`DynamicFunction`], which may capture a mutable environment,
+/// a registration only holds onto a function that can be cheaply cloned and shared across threads
+/// (such as a function item, a method, or a non-capturing closure),
+/// and creates a fresh [`DynamicFunction`] for every call.
+#[derive(Clone)]
+pub struct FunctionRegistration {
+    info: FunctionInfo,
+    constructor: Arc<dyn Fn() -> DynamicFunction<'static> + Send + Sync>,
+}
+
+impl FunctionRegistration {
+    /// Create a new [`FunctionRegistration`] for the given function, under the given name.
+    ///
+    /// The name replaces the default name of the [`DynamicFunction`], which is the full path to the function.
+    pub fn new<F, Marker>(name: impl Into<Cow<'static, str>>, func: F) -> Self
+    where
+        F: IntoFunction<'static, Marker> + Clone + Send + Sync + 'static,
+    {
+        let name = name.into();
+        let info = func
+            .clone()
+            .into_function()
+            .info()
+            .clone()
+            .with_name(name.clone());
+        Self {
+            info,
+            constructor: Arc::new(move || func.clone().into_function().with_name(name.clone())),
+        }
+    }
+
+    /// The name the function was registered under.
+    pub fn name(&self) -> &str {
+        self.info.name().unwrap_or_default()
+    }
+
+    /// The function info.
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+
+    /// Returns `true` if the given arguments have the exact types and ownership this function expects.
+    pub fn matches(&self, args: &ArgList) -> bool {
+        args.len() == self.info.arg_count()
+            && args
+                .iter()
+                .zip(self.info.args())
+                .all(|(arg, info)| arg_matches(arg, info))
+    }
+
+    /// Create a new [`DynamicFunction`] from this registration.
+    pub fn to_function(&self) -> DynamicFunction<'static> {
+        (self.constructor)()
+    }
+
+    /// Call the function with the given arguments.
+    pub fn call<'a>(&self, args: ArgList<'a>) -> FunctionResult<'a> {
+        self.to_function().call_once(args)
+    }
+}
+
+impl Debug for FunctionRegistration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.to_function(), f)
+    }
+}
+
+/// All the overloads of a function registered under a single name in a [`FunctionRegistry`].
+///
+/// Overloads are distinguished by the types and ownership of their arguments.
+#[derive(Debug, Clone)]
+pub struct FunctionOverloads {
+    name: Cow<'static, str>,
+    overloads: Vec<FunctionRegistration>,
+}
+
+impl FunctionOverloads {
+    /// The name the overloads were registered under.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the overload whose arguments match the given arguments, if any.
+    pub fn get(&self, args: &ArgList) -> Option<&FunctionRegistration> {
+        self.overloads
+            .iter()
+            .find(|overload| overload.matches(args))
+    }
+
+    /// Call the overload matching the given arguments.
+    ///
+    /// If there is only a single overload, it is called directly,
+    /// so that mismatched arguments are reported with a precise [`ArgError`].
+    ///
+    /// [`ArgError`]: crate::func::ArgError
+    pub fn call<'a>(&self, args: ArgList<'a>) -> FunctionResult<'a> {
+        if let [overload] = self.overloads.as_slice() {
+            return overload.call(args);
+        }
+
+        match self.get(&args) {
+            Some(overload) => overload.call(args),
+            None => Err(FunctionError::NoMatchingOverload {
+                name: self.name.clone(),
+                received: Cow::Owned(format_args(&args)),
+            }),
+        }
+    }
+
+    /// Returns an iterator over the overloads.
+    pub fn iter(&self) -> impl Iterator<Item = &FunctionRegistration> {
+        self.overloads.iter()
+    }
+
+    /// The number of overloads.
+    pub fn len(&self) -> usize {
+        self.overloads.len()
+    }
+
+    /// Returns `true` if there are no overloads.
+    pub fn is_empty(&self) -> bool {
+        self.overloads.is_empty()
+    }
+
+    /// Adds the given overload, replacing any existing overload with the same argument types
+    /// and ownership.
+    fn insert(&mut self, registration: FunctionRegistration) {
+        let existing = self.overloads.iter_mut().find(|overload| {
+            overload.info.arg_count() == registration.info.arg_count()
+                && overload
+                    .info
+                    .args()
+                    .iter()
+                    .zip(registration.info.args())
+                    .all(|(a, b)| a.type_path() == b.type_path() && a.ownership() == b.ownership())
+        });
+
+        match existing {
+            Some(existing) => *existing = registration,
+            None => self.overloads.push(registration),
+        }
+    }
+}
+
+/// A registry of [reflected functions], looked up by name.
+///
+/// Each name may be registered multiple times with different argument types.
+/// Calling a function by name will then pick the overload matching the provided arguments.
+///
+/// The functions of an app are registered in the [`TypeRegistry`],
+/// and can be accessed with [`TypeRegistry::functions`].
+/// Methods of a specific type are stored in its [`ReflectMethods`] type data instead.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, FunctionRegistry};
+/// fn add(a: i32, b: i32) -> i32 {
+///   a + b
+/// }
+///
+/// fn add_floats(a: f32, b: f32) -> f32 {
+///   a + b
+/// }
+///
+/// let mut registry = FunctionRegistry::default();
+/// registry.register("add", add);
+/// registry.register("add", add_floats);
+///
+/// let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+/// let value = registry.call("add", args).unwrap().unwrap_owned();
+/// assert_eq!(value.take::<i32>().unwrap(), 100);
+///
+/// let args = ArgList::new().push_owned(0.5_f32).push_owned(0.25_f32);
+/// let value = registry.call("add", args).unwrap().unwrap_owned();
+/// assert_eq!(value.take::<f32>().unwrap(), 0.75);
+/// ```
+///
+/// [reflected functions]: crate::func
+/// [`TypeRegistry`]: crate::TypeRegistry
+/// [`TypeRegistry::functions`]: crate::TypeRegistry::functions
+#[derive(Debug, Clone, Default)]
+pub struct FunctionRegistry {
+    functions: HashMap<Cow<'static, str>, FunctionOverloads>,
+}
+
+impl FunctionRegistry {
+    /// Registers the given function under the given name.
+    ///
+    /// If a function with the same name and argument types was already registered, it is replaced.
+    /// Otherwise, the function is added as a new overload.
+    pub fn register<F, Marker>(&mut self, name: impl Into<Cow<'static, str>>, func: F)
+    where
+        F: IntoFunction<'static, Marker> + Clone + Send + Sync + 'static,
+    {
+        self.add_registration(FunctionRegistration::new(name, func));
+    }
+
+    /// Adds the given registration under its name.
+    ///
+    /// See [`FunctionRegistry::register`].
+    pub fn add_registration(&mut self, registration: FunctionRegistration) {
+        let name: Cow<'static, str> = Cow::Owned(registration.name().to_string());
+        self.functions
+            .entry(name.clone())
+            .or_insert_with(|| FunctionOverloads {
+                name,
+                overloads: Vec::new(),
+            })
+            .insert(registration);
+    }
+
+    /// Removes all overloads of the function with the given name, returning them if there were any.
+    pub fn remove(&mut self, name: &str) -> Option<FunctionOverloads> {
+        self.functions.remove(name)
+    }
+
+    /// Returns `true` if a function is registered under the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// Returns the overloads of the function with the given name.
+    pub fn get(&self, name: &str) -> Option<&FunctionOverloads> {
+        self.functions.get(name)
+    }
+
+    /// Call the function with the given name, using the overload matching the given arguments.
+    pub fn call<'a>(&self, name: &str, args: ArgList<'a>) -> FunctionResult<'a> {
+        self.get(name)
+            .ok_or_else(|| FunctionError::NotFound {
+                name: Cow::Owned(name.to_string()),
+            })?
+            .call(args)
+    }
+
+    /// Returns an iterator over the registered functions.
+    pub fn iter(&self) -> impl Iterator<Item = &FunctionOverloads> {
+        self.functions.values()
+    }
+
+    /// The number of registered function names.
+    pub fn len(&self) -> usize {
+        self.functions.len()
+    }
+
+    /// Returns `true` if no functions are registered.
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+}
+
+/// Type data holding the reflected methods of a type.
+///
+/// This is inserted by [`TypeRegistry::register_method`], and can be looked up by path
+/// (e.g. `"Transform::looking_at"`) with [`TypeRegistry::get_method`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_reflect::func::{ArgList, ReflectMethods};
+/// #[derive(Reflect)]
+/// struct Counter(u32);
+///
+/// impl Counter {
+///   fn increment(&mut self, amount: u32) {
+///     self.0 += amount;
+///   }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Counter>();
+/// registry.register_method::<Counter, _, _>("increment", Counter::increment);
+///
+/// let mut counter = Counter(1);
+/// let methods = registry.get_type_data::<ReflectMethods>(std::any::TypeId::of::<Counter>()).unwrap();
+/// let args = ArgList::new().push_mut(&mut counter).push_owned(2_u32);
+/// methods.call("increment", args).unwrap();
+/// assert_eq!(counter.0, 3);
+/// ```
+///
+/// [`TypeRegistry::register_method`]: crate::TypeRegistry::register_method
+/// [`TypeRegistry::get_method`]: crate::TypeRegistry::get_method
+#[derive(Debug, Clone, Default)]
+pub struct ReflectMethods {
+    methods: FunctionRegistry,
+}
+
+impl ReflectMethods {
+    /// Registers the given method under the given name.
+    ///
+    /// See [`FunctionRegistry::register`].
+    pub fn register<F, Marker>(&mut self, name: impl Into<Cow<'static, str>>, method: F)
+    where
+        F: IntoFunction<'static, Marker> + Clone + Send + Sync + 'static,
+    {
+        self.methods.register(name, method);
+    }
+
+    /// Returns the overloads of the method with the given name.
+    pub fn get(&self, name: &str) -> Option<&FunctionOverloads> {
+        self.methods.get(name)
+    }
+
+    /// Call the method with the given name, using the overload matching the given arguments.
+    ///
+    /// The receiver, if any, is passed as the first argument.
+    pub fn call<'a>(&self, name: &str, args: ArgList<'a>) -> FunctionResult<'a> {
+        self.methods.call(name, args)
+    }
+
+    /// Returns an iterator over the registered methods.
+    pub fn iter(&self) -> impl Iterator<Item = &FunctionOverloads> {
+        self.methods.iter()
+    }
+}
+
+/// Returns `true` if the argument has the type and ownership described by `info`.
+fn arg_matches(arg: &Arg, info: &ArgInfo) -> bool {
+    let (ownership, value): (Ownership, &dyn Reflect) = match arg {
+        Arg::Owned(value) => (Ownership::Owned, value.as_ref()),
+        Arg::Ref(value) => (Ownership::Ref, *value),
+        Arg::Mut(value) => (Ownership::Mut, &**value),
+    };
+
+    // Reference types are given a `&` or `&mut ` prefix by their `TypePath` implementation.
+    let type_path = match ownership {
+        Ownership::Owned => Some(info.type_path()),
+        Ownership::Ref => info.type_path().strip_prefix('&'),
+        Ownership::Mut => info.type_path().strip_prefix("&mut "),
+    };
+
+    ownership == info.ownership() && type_path == Some(value.reflect_type_path())
+}
+
+/// Formats the types of the given arguments as `(T1, &T2, &mut T3)`.
+fn format_args(args: &ArgList) -> String {
+    let types = args
+        .iter()
+        .map(|arg| match arg {
+            Arg::Owned(value) => value.reflect_type_path().to_string(),
+            Arg::Ref(value) => format!("&{}", value.reflect_type_path()),
+            Arg::Mut(value) => format!("&mut {}", value.reflect_type_path()),
+        })
+        .collect::<Vec<_>>();
+    format!("({})", types.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{TypePath, TypeRegistry};
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Foo(i32);
+
+    impl Foo {
+        fn get(&self) -> &i32 {
+            &self.0
+        }
+
+        fn set(&mut self, value: i32) {
+            self.0 = value;
+        }
+
+        fn set_from(&mut self, other: &Foo) {
+            self.0 = other.0;
+        }
+    }
+
+    #[test]
+    fn should_call_registered_function() {
+        fn double(value: i32) -> i32 {
+            value * 2
+        }
+
+        let mut registry = FunctionRegistry::default();
+        registry.register("double", double);
+
+        let args = ArgList::new().push_owned(21_i32);
+        let value = registry.call("double", args).unwrap().unwrap_owned();
+        assert_eq!(value.take::<i32>().unwrap(), 42);
+
+        let result = registry.call("triple", ArgList::new().push_owned(21_i32));
+        assert_eq!(
+            result.unwrap_err(),
+            FunctionError::NotFound {
+                name: Cow::Borrowed("triple")
+            }
+        );
+    }
+
+    #[test]
+    fn should_resolve_overloads_by_argument_types() {
+        let mut registry = FunctionRegistry::default();
+        registry.register("set", Foo::set);
+        registry.register("set", Foo::set_from);
+        assert_eq!(registry.get("set").unwrap().len(), 2);
+
+        let mut foo = Foo(1);
+        let args = ArgList::new().push_mut(&mut foo).push_owned(2_i32);
+        registry.call("set", args).unwrap();
+        assert_eq!(foo, Foo(2));
+
+        let other = Foo(3);
+        let args = ArgList::new().push_mut(&mut foo).push_ref(&other);
+        registry.call("set", args).unwrap();
+        assert_eq!(foo, Foo(3));
+
+        let args = ArgList::new()
+            .push_mut(&mut foo)
+            .push_owned(String::from("4"));
+        assert_eq!(
+            registry.call("set", args).unwrap_err(),
+            FunctionError::NoMatchingOverload {
+                name: Cow::Borrowed("set"),
+                received: Cow::Owned(format!(
+                    "(&mut {}, {})",
+                    Foo::type_path(),
+                    String::type_path()
+                )),
+            }
+        );
+    }
+
+    #[test]
+    fn should_resolve_overloads_by_ownership() {
+        fn by_ref(_: &Foo) -> &'static str {
+            "ref"
+        }
+
+        fn by_value(_: Foo) -> &'static str {
+            "owned"
+        }
+
+        let mut registry = FunctionRegistry::default();
+        registry.register("describe", by_ref);
+        registry.register("describe", by_value);
+        assert_eq!(registry.get("describe").unwrap().len(), 2);
+
+        let foo = Foo(1);
+        let value = registry
+            .call("describe", ArgList::new().push_ref(&foo))
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(value.take::<&'static str>().unwrap(), "ref");
+
+        let value = registry
+            .call("describe", ArgList::new().push_owned(foo))
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(value.take::<&'static str>().unwrap(), "owned");
+    }
+
+    #[test]
+    fn should_replace_overload_with_same_signature() {
+        fn one() -> i32 {
+            1
+        }
+
+        fn two() -> i32 {
+            2
+        }
+
+        let mut registry = FunctionRegistry::default();
+        registry.register("number", one);
+        registry.register("number", two);
+        assert_eq!(registry.get("number").unwrap().len(), 1);
+
+        let value = registry
+            .call("number", ArgList::new())
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(value.take::<i32>().unwrap(), 2);
+    }
+
+    #[test]
+    fn should_call_method_by_path() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+        registry.register_method::<Foo, _, _>("get", Foo::get);
+        registry.register_method::<Foo, _, _>("set", Foo::set);
+
+        let mut foo = Foo(1);
+        let set = registry.get_method("Foo::set").unwrap();
+        set.call(ArgList::new().push_mut(&mut foo).push_owned(5_i32))
+            .unwrap();
+        assert_eq!(foo, Foo(5));
+
+        let get = registry.get_method(&format!("{}::get", Foo::type_path()));
+        let value = get
+            .unwrap()
+            .call(ArgList::new().push_ref(&foo))
+            .unwrap()
+            .unwrap_ref();
+        assert_eq!(value.downcast_ref::<i32>(), Some(&5));
+
+        assert!(registry.get_method("Foo::missing").is_none());
+        assert!(registry.get_method("Bar::get").is_none());
+    }
+}
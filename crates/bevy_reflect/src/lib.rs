@@ -73,6 +73,7 @@
 //! * [`Array`]
 //! * [`List`]
 //! * [`Map`]
+//! * [`Set`]
 //! * [`Struct`]
 //! * [`TupleStruct`]
 //! * [`Enum`]
@@ -133,6 +134,7 @@
 //! * [`DynamicArray`]
 //! * [`DynamicList`]
 //! * [`DynamicMap`]
+//! * [`DynamicSet`]
 //! * [`DynamicStruct`]
 //! * [`DynamicTupleStruct`]
 //! * [`DynamicEnum`]
@@ -482,6 +484,7 @@ mod list;
 mod map;
 mod path;
 mod reflect;
+mod set;
 mod struct_trait;
 mod tuple;
 mod tuple_struct;
@@ -531,6 +534,7 @@ pub use list::*;
 pub use map::*;
 pub use path::*;
 pub use reflect::*;
+pub use set::*;
 pub use struct_trait::*;
 pub use tuple::*;
 pub use tuple_struct::*;
@@ -549,8 +553,8 @@ extern crate alloc;
 #[doc(hidden)]
 pub mod __macro_exports {
     use crate::{
-        DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-        DynamicTupleStruct, GetTypeRegistration, TypeRegistry,
+        DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct,
+        DynamicTuple, DynamicTupleStruct, GetTypeRegistration, TypeRegistry,
     };
 
     /// A wrapper trait around [`GetTypeRegistration`].
@@ -580,6 +584,8 @@ pub mod __macro_exports {
 
     impl RegisterForReflection for DynamicMap {}
 
+    impl RegisterForReflection for DynamicSet {}
+
     impl RegisterForReflection for DynamicList {}
 
     impl RegisterForReflection for DynamicArray {}
@@ -1,9 +1,9 @@
 use crate::serde::SerializationData;
 use crate::{
-    ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-    DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField, Reflect,
-    ReflectDeserialize, StructInfo, StructVariantInfo, TupleInfo, TupleStructInfo,
-    TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
+    ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct,
+    DynamicTuple, DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField,
+    Reflect, ReflectDeserialize, Set, SetInfo, StructInfo, StructVariantInfo, TupleInfo,
+    TupleStructInfo, TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
 };
 use erased_serde::Deserializer;
 use serde::de::{
@@ -288,6 +288,102 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
     }
 }
 
+/// Allows overriding how [`ReflectDeserializer`] and [`TypedReflectDeserializer`] deserialize specific values.
+///
+/// When given a processor, the deserializers offer it every value before deserializing it,
+/// including the values nested in structs, enums, lists, maps and other containers.
+/// The processor can then deserialize the value itself, for example to load an asset handle from
+/// its path or to map a stable identifier to an entity, or hand the deserializer back to fall back to
+/// the default behavior.
+///
+/// Processors take `&mut self`, so they can keep state across values.
+///
+/// This is the deserializer counterpart to [`ReflectSerializerProcessor`].
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistration, TypeRegistry, serde::{ReflectDeserializerProcessor, TypedReflectDeserializer}};
+/// # use serde::de::{Deserialize, DeserializeSeed, Deserializer, Error};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Inventory {
+///   gold: u32,
+///   items: Vec<u32>,
+/// }
+///
+/// /// Deserializes every `u32` from a hexadecimal string, counting them.
+/// #[derive(Default)]
+/// struct HexProcessor {
+///   count: usize,
+/// }
+///
+/// impl ReflectDeserializerProcessor for HexProcessor {
+///   fn try_deserialize<'de, D>(
+///     &mut self,
+///     registration: &TypeRegistration,
+///     _registry: &TypeRegistry,
+///     deserializer: D,
+///   ) -> Result<Result<Box<dyn Reflect>, D>, D::Error>
+///   where
+///     D: Deserializer<'de>,
+///   {
+///     if registration.type_id() != TypeId::of::<u32>() {
+///       return Ok(Err(deserializer));
+///     }
+///     self.count += 1;
+///     let hex = String::deserialize(deserializer)?;
+///     let value = u32::from_str_radix(hex.trim_start_matches("0x"), 16).map_err(D::Error::custom)?;
+///     Ok(Ok(Box::new(value)))
+///   }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Inventory>();
+/// let registration = registry.get(TypeId::of::<Inventory>()).unwrap();
+///
+/// let input = r#"(gold: "0xff", items: ["0x10"])"#;
+/// let mut processor = HexProcessor::default();
+/// let reflect_deserializer = TypedReflectDeserializer::with_processor(registration, &registry, &mut processor);
+/// let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+/// let output = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+///
+/// let output = <Inventory as FromReflect>::from_reflect(&*output).unwrap();
+/// assert_eq!(output, Inventory { gold: 255, items: vec![16] });
+/// assert_eq!(processor.count, 2);
+/// ```
+///
+/// [`ReflectSerializerProcessor`]: crate::serde::ReflectSerializerProcessor
+pub trait ReflectDeserializerProcessor {
+    /// Attempts to deserialize the value of the type described by the given registration.
+    ///
+    /// Returns `Ok(Ok(_))` if the value was deserialized by the processor,
+    /// and `Ok(Err(deserializer))` to let the reflection deserializer handle the value as usual.
+    fn try_deserialize<'de, D>(
+        &mut self,
+        registration: &TypeRegistration,
+        registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Result<Box<dyn Reflect>, D>, D::Error>
+    where
+        D: serde::Deserializer<'de>;
+}
+
+impl ReflectDeserializerProcessor for () {
+    fn try_deserialize<'de, D>(
+        &mut self,
+        _registration: &TypeRegistration,
+        _registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Result<Box<dyn Reflect>, D>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Err(deserializer))
+    }
+}
+
 /// A general purpose deserializer for reflected types.
 ///
 /// This is the deserializer counterpart to [`ReflectSerializer`].
@@ -367,28 +463,48 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
 /// [`Box<DynamicList>`]: crate::DynamicList
 /// [`FromReflect`]: crate::FromReflect
 /// [`ReflectFromReflect`]: crate::ReflectFromReflect
-pub struct ReflectDeserializer<'a> {
+pub struct ReflectDeserializer<'a, P = ()> {
     registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
 }
 
 impl<'a> ReflectDeserializer<'a> {
     pub fn new(registry: &'a TypeRegistry) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            processor: None,
+        }
     }
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
+impl<'a, P: ReflectDeserializerProcessor> ReflectDeserializer<'a, P> {
+    /// Creates a deserializer that lets the given [processor] deserialize any value first,
+    /// including nested ones.
+    ///
+    /// [processor]: ReflectDeserializerProcessor
+    pub fn with_processor(registry: &'a TypeRegistry, processor: &'a mut P) -> Self {
+        Self {
+            registry,
+            processor: Some(processor),
+        }
+    }
+}
+
+impl<'a, 'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeserializer<'a, P> {
     type Value = Box<dyn Reflect>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        struct UntypedReflectDeserializerVisitor<'a> {
+        struct UntypedReflectDeserializerVisitor<'a, P> {
             registry: &'a TypeRegistry,
+            processor: Option<&'a mut P>,
         }
 
-        impl<'a, 'de> Visitor<'de> for UntypedReflectDeserializerVisitor<'a> {
+        impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de>
+            for UntypedReflectDeserializerVisitor<'a, P>
+        {
             type Value = Box<dyn Reflect>;
 
             fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -407,6 +523,7 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
                 let value = map.next_value_seed(TypedReflectDeserializer {
                     registration,
                     registry: self.registry,
+                    processor: self.processor,
                 })?;
 
                 if map.next_key::<IgnoredAny>()?.is_some() {
@@ -419,6 +536,7 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
 
         deserializer.deserialize_map(UntypedReflectDeserializerVisitor {
             registry: self.registry,
+            processor: self.processor,
         })
     }
 }
@@ -499,9 +617,10 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
 /// [`Box<DynamicList>`]: crate::DynamicList
 /// [`FromReflect`]: crate::FromReflect
 /// [`ReflectFromReflect`]: crate::ReflectFromReflect
-pub struct TypedReflectDeserializer<'a> {
+pub struct TypedReflectDeserializer<'a, P = ()> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
 }
 
 impl<'a> TypedReflectDeserializer<'a> {
@@ -509,17 +628,48 @@ impl<'a> TypedReflectDeserializer<'a> {
         Self {
             registration,
             registry,
+            processor: None,
+        }
+    }
+}
+
+impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
+    /// Creates a deserializer that lets the given [processor] deserialize any value first,
+    /// including nested ones.
+    ///
+    /// [processor]: ReflectDeserializerProcessor
+    pub fn with_processor(
+        registration: &'a TypeRegistration,
+        registry: &'a TypeRegistry,
+        processor: &'a mut P,
+    ) -> Self {
+        Self {
+            registration,
+            registry,
+            processor: Some(processor),
         }
     }
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
+    for TypedReflectDeserializer<'a, P>
+{
     type Value = Box<dyn Reflect>;
 
-    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    fn deserialize<D>(mut self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
+        let deserializer = match self.processor.as_deref_mut() {
+            Some(processor) => {
+                match processor.try_deserialize(self.registration, self.registry, deserializer)? {
+                    Ok(value) => return Ok(value),
+                    Err(deserializer) => deserializer,
+                }
+            }
+            None => deserializer,
+        };
+
         let type_path = self.registration.type_info().type_path();
 
         // Handle both Value case and types that have a custom `ReflectDeserialize`
@@ -537,6 +687,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                         struct_info,
                         registration: self.registration,
                         registry: self.registry,
+                        processor: self.processor,
                     },
                 )?;
                 dynamic_struct.set_represented_type(Some(self.registration.type_info()));
@@ -550,6 +701,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                         tuple_struct_info,
                         registry: self.registry,
                         registration: self.registration,
+                        processor: self.processor,
                     },
                 )?;
                 dynamic_tuple_struct.set_represented_type(Some(self.registration.type_info()));
@@ -559,6 +711,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                 let mut dynamic_list = deserializer.deserialize_seq(ListVisitor {
                     list_info,
                     registry: self.registry,
+                    processor: self.processor,
                 })?;
                 dynamic_list.set_represented_type(Some(self.registration.type_info()));
                 Ok(Box::new(dynamic_list))
@@ -569,6 +722,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                     ArrayVisitor {
                         array_info,
                         registry: self.registry,
+                        processor: self.processor,
                     },
                 )?;
                 dynamic_array.set_represented_type(Some(self.registration.type_info()));
@@ -578,10 +732,20 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                 let mut dynamic_map = deserializer.deserialize_map(MapVisitor {
                     map_info,
                     registry: self.registry,
+                    processor: self.processor,
                 })?;
                 dynamic_map.set_represented_type(Some(self.registration.type_info()));
                 Ok(Box::new(dynamic_map))
             }
+            TypeInfo::Set(set_info) => {
+                let mut dynamic_set = deserializer.deserialize_seq(SetVisitor {
+                    set_info,
+                    registry: self.registry,
+                    processor: self.processor,
+                })?;
+                dynamic_set.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_set))
+            }
             TypeInfo::Tuple(tuple_info) => {
                 let mut dynamic_tuple = deserializer.deserialize_tuple(
                     tuple_info.field_len(),
@@ -589,6 +753,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                         tuple_info,
                         registration: self.registration,
                         registry: self.registry,
+                        processor: self.processor,
                     },
                 )?;
                 dynamic_tuple.set_represented_type(Some(self.registration.type_info()));
@@ -602,6 +767,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                     deserializer.deserialize_option(OptionVisitor {
                         enum_info,
                         registry: self.registry,
+                        processor: self.processor,
                     })?
                 } else {
                     deserializer.deserialize_enum(
@@ -611,6 +777,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                             enum_info,
                             registration: self.registration,
                             registry: self.registry,
+                            processor: self.processor,
                         },
                     )?
                 };
@@ -627,13 +794,14 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
     }
 }
 
-struct StructVisitor<'a> {
+struct StructVisitor<'a, P> {
     struct_info: &'static StructInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVisitor<'a, P> {
     type Value = DynamicStruct;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -644,24 +812,37 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registration, self.registry)
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.processor,
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registration, self.registry)
+        visit_struct(
+            &mut map,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.processor,
+        )
     }
 }
 
-struct TupleStructVisitor<'a> {
+struct TupleStructVisitor<'a, P> {
     tuple_struct_info: &'static TupleStructInfo,
     registry: &'a TypeRegistry,
     registration: &'a TypeRegistration,
+    processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for TupleStructVisitor<'a, P> {
     type Value = DynamicTupleStruct;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -677,18 +858,20 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
             self.tuple_struct_info,
             self.registration,
             self.registry,
+            self.processor,
         )
         .map(DynamicTupleStruct::from)
     }
 }
 
-struct TupleVisitor<'a> {
+struct TupleVisitor<'a, P> {
     tuple_info: &'static TupleInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for TupleVisitor<'a, P> {
     type Value = DynamicTuple;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -699,23 +882,30 @@ impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
     where
         V: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registration, self.registry)
+        visit_tuple(
+            &mut seq,
+            self.tuple_info,
+            self.registration,
+            self.registry,
+            self.processor,
+        )
     }
 }
 
-struct ArrayVisitor<'a> {
+struct ArrayVisitor<'a, P> {
     array_info: &'static ArrayInfo,
     registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for ArrayVisitor<'a, P> {
     type Value = DynamicArray;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
         formatter.write_str("reflected array value")
     }
 
-    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    fn visit_seq<V>(mut self, mut seq: V) -> Result<Self::Value, V::Error>
     where
         V: SeqAccess<'de>,
     {
@@ -728,6 +918,7 @@ impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
         while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
             registration,
             registry: self.registry,
+            processor: self.processor.as_deref_mut(),
         })? {
             vec.push(value);
         }
@@ -743,19 +934,20 @@ impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
     }
 }
 
-struct ListVisitor<'a> {
+struct ListVisitor<'a, P> {
     list_info: &'static ListInfo,
     registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for ListVisitor<'a, P> {
     type Value = DynamicList;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
         formatter.write_str("reflected list value")
     }
 
-    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    fn visit_seq<V>(mut self, mut seq: V) -> Result<Self::Value, V::Error>
     where
         V: SeqAccess<'de>,
     {
@@ -768,6 +960,7 @@ impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
         while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
             registration,
             registry: self.registry,
+            processor: self.processor.as_deref_mut(),
         })? {
             list.push_box(value);
         }
@@ -775,19 +968,20 @@ impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
     }
 }
 
-struct MapVisitor<'a> {
+struct MapVisitor<'a, P> {
     map_info: &'static MapInfo,
     registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for MapVisitor<'a, P> {
     type Value = DynamicMap;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
         formatter.write_str("reflected map value")
     }
 
-    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    fn visit_map<V>(mut self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
     {
@@ -805,10 +999,12 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
         while let Some(key) = map.next_key_seed(TypedReflectDeserializer {
             registration: key_registration,
             registry: self.registry,
+            processor: self.processor.as_deref_mut(),
         })? {
             let value = map.next_value_seed(TypedReflectDeserializer {
                 registration: value_registration,
                 registry: self.registry,
+                processor: self.processor.as_deref_mut(),
             })?;
             dynamic_map.insert_boxed(key, value);
         }
@@ -817,13 +1013,49 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
     }
 }
 
-struct EnumVisitor<'a> {
+struct SetVisitor<'a, P> {
+    set_info: &'static SetInfo,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for SetVisitor<'a, P> {
+    type Value = DynamicSet;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected set value")
+    }
+
+    fn visit_seq<V>(mut self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut dynamic_set = DynamicSet::default();
+        let registration = get_registration(
+            self.set_info.value_type_id(),
+            self.set_info.value_type_path_table().path(),
+            self.registry,
+        )?;
+        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
+            registration,
+            registry: self.registry,
+            processor: self.processor.as_deref_mut(),
+        })? {
+            dynamic_set.insert_boxed(value);
+        }
+
+        Ok(dynamic_set)
+    }
+}
+
+struct EnumVisitor<'a, P> {
     enum_info: &'static EnumInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for EnumVisitor<'a, P> {
     type Value = DynamicEnum;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -848,6 +1080,7 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
                         struct_info,
                         registration: self.registration,
                         registry: self.registry,
+                        processor: self.processor,
                     },
                 )?
                 .into(),
@@ -856,6 +1089,7 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
                 let value = variant.newtype_variant_seed(TypedReflectDeserializer {
                     registration,
                     registry: self.registry,
+                    processor: self.processor,
                 })?;
                 let mut dynamic_tuple = DynamicTuple::default();
                 dynamic_tuple.insert_boxed(value);
@@ -868,6 +1102,7 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
                         tuple_info,
                         registration: self.registration,
                         registry: self.registry,
+                        processor: self.processor,
                     },
                 )?
                 .into(),
@@ -934,13 +1169,14 @@ impl<'de> DeserializeSeed<'de> for VariantDeserializer {
     }
 }
 
-struct StructVariantVisitor<'a> {
+struct StructVariantVisitor<'a, P> {
     struct_info: &'static StructVariantInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVariantVisitor<'a, P> {
     type Value = DynamicStruct;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -951,24 +1187,37 @@ impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registration, self.registry)
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.processor,
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registration, self.registry)
+        visit_struct(
+            &mut map,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.processor,
+        )
     }
 }
 
-struct TupleVariantVisitor<'a> {
+struct TupleVariantVisitor<'a, P> {
     tuple_info: &'static TupleVariantInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for TupleVariantVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for TupleVariantVisitor<'a, P> {
     type Value = DynamicTuple;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -979,16 +1228,23 @@ impl<'a, 'de> Visitor<'de> for TupleVariantVisitor<'a> {
     where
         V: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registration, self.registry)
+        visit_tuple(
+            &mut seq,
+            self.tuple_info,
+            self.registration,
+            self.registry,
+            self.processor,
+        )
     }
 }
 
-struct OptionVisitor<'a> {
+struct OptionVisitor<'a, P> {
     enum_info: &'static EnumInfo,
     registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for OptionVisitor<'a, P> {
     type Value = DynamicEnum;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -1018,6 +1274,7 @@ impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
                 let de = TypedReflectDeserializer {
                     registration,
                     registry: self.registry,
+                    processor: self.processor,
                 };
                 let mut value = DynamicTuple::default();
                 value.insert_boxed(de.deserialize(deserializer)?);
@@ -1033,15 +1290,17 @@ impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
     }
 }
 
-fn visit_struct<'de, T, V>(
+fn visit_struct<'de, T, V, P>(
     map: &mut V,
     info: &'static T,
     registration: &TypeRegistration,
     registry: &TypeRegistry,
+    mut processor: Option<&mut P>,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo,
     V: MapAccess<'de>,
+    P: ReflectDeserializerProcessor,
 {
     let mut dynamic_struct = DynamicStruct::default();
     while let Some(Ident(key)) = map.next_key::<Ident>()? {
@@ -1057,6 +1316,7 @@ where
         let value = map.next_value_seed(TypedReflectDeserializer {
             registration,
             registry,
+            processor: processor.as_deref_mut(),
         })?;
         dynamic_struct.insert_boxed(&key, value);
     }
@@ -1073,15 +1333,17 @@ where
     Ok(dynamic_struct)
 }
 
-fn visit_tuple<'de, T, V>(
+fn visit_tuple<'de, T, V, P>(
     seq: &mut V,
     info: &T,
     registration: &TypeRegistration,
     registry: &TypeRegistry,
+    mut processor: Option<&mut P>,
 ) -> Result<DynamicTuple, V::Error>
 where
     T: TupleLikeInfo + Container,
     V: SeqAccess<'de>,
+    P: ReflectDeserializerProcessor,
 {
     let mut tuple = DynamicTuple::default();
 
@@ -1104,6 +1366,7 @@ where
             .next_element_seed(TypedReflectDeserializer {
                 registration: info.get_field_registration(index, registry)?,
                 registry,
+                processor: processor.as_deref_mut(),
             })?
             .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
         tuple.insert_boxed(value);
@@ -1112,15 +1375,17 @@ where
     Ok(tuple)
 }
 
-fn visit_struct_seq<'de, T, V>(
+fn visit_struct_seq<'de, T, V, P>(
     seq: &mut V,
     info: &T,
     registration: &TypeRegistration,
     registry: &TypeRegistry,
+    mut processor: Option<&mut P>,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo + Container,
     V: SeqAccess<'de>,
+    P: ReflectDeserializerProcessor,
 {
     let mut dynamic_struct = DynamicStruct::default();
 
@@ -1150,6 +1415,7 @@ where
             .next_element_seed(TypedReflectDeserializer {
                 registration: info.get_field_registration(index, registry)?,
                 registry,
+                processor: processor.as_deref_mut(),
             })?
             .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
         dynamic_struct.insert_boxed(name, value);
@@ -1426,6 +1692,131 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_deserialize_with_processor() {
+        use crate::serde::ReflectDeserializerProcessor;
+        use crate::TypeRegistration;
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct AssetHandle(u32);
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Level {
+            skybox: AssetHandle,
+            props: Vec<AssetHandle>,
+            named: HashMap<String, AssetHandle>,
+            id: u32,
+        }
+
+        /// Deserializes asset handles from their path, collecting the loaded paths.
+        #[derive(Default)]
+        struct AssetPathProcessor {
+            loaded: Vec<String>,
+        }
+
+        impl ReflectDeserializerProcessor for AssetPathProcessor {
+            fn try_deserialize<'de, D>(
+                &mut self,
+                registration: &TypeRegistration,
+                _registry: &TypeRegistry,
+                deserializer: D,
+            ) -> Result<Result<Box<dyn Reflect>, D>, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                if registration.type_id() != TypeId::of::<AssetHandle>() {
+                    return Ok(Err(deserializer));
+                }
+                let path = String::deserialize(deserializer)?;
+                let id = path
+                    .trim_start_matches("assets/")
+                    .trim_end_matches(".png")
+                    .parse()
+                    .map_err(serde::de::Error::custom)?;
+                self.loaded.push(path);
+                Ok(Ok(Box::new(AssetHandle(id))))
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+
+        let expected = Level {
+            skybox: AssetHandle(0),
+            props: vec![AssetHandle(1), AssetHandle(2)],
+            named: HashMap::from_iter([(String::from("door"), AssetHandle(3))]),
+            id: 4,
+        };
+
+        let input = r#"(
+            skybox: "assets/0.png",
+            props: ["assets/1.png", "assets/2.png"],
+            named: { "door": "assets/3.png" },
+            id: 4,
+        )"#;
+        let registration = registry.get(TypeId::of::<Level>()).unwrap();
+        let mut processor = AssetPathProcessor::default();
+        let reflect_deserializer =
+            TypedReflectDeserializer::with_processor(registration, &registry, &mut processor);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+        let output = <Level as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(expected, output);
+        assert_eq!(
+            processor.loaded,
+            [
+                "assets/0.png",
+                "assets/1.png",
+                "assets/2.png",
+                "assets/3.png"
+            ]
+        );
+
+        let input = format!(r#"{{"{}": {input}}}"#, registration.type_info().type_path());
+        let mut processor = AssetPathProcessor::default();
+        let reflect_deserializer = ReflectDeserializer::with_processor(&registry, &mut processor);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(&input).unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+        let output = <Level as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(expected, output);
+        assert_eq!(processor.loaded.len(), 4);
+    }
+
+    #[test]
+    fn should_deserialize_set() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct SetStruct {
+            ids: std::collections::BTreeSet<u32>,
+            names: bevy_utils::HashSet<String>,
+        }
+
+        let expected = SetStruct {
+            ids: [1, 2, 3].into_iter().collect(),
+            names: ["foo".to_string()].into_iter().collect(),
+        };
+
+        let input = r#"(
+            ids: [3, 1, 2],
+            names: ["foo"],
+        )"#;
+
+        let mut registry = get_registry();
+        registry.register::<SetStruct>();
+        let registration = registry.get(TypeId::of::<SetStruct>()).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        let output = <SetStruct as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(expected, output);
+    }
+
     #[test]
     fn should_deserialize_option() {
         #[derive(Reflect, Debug, PartialEq)]
@@ -1,8 +1,12 @@
 mod de;
+#[cfg(feature = "json_schema")]
+mod schema;
 mod ser;
 mod type_data;
 
 pub use de::*;
+#[cfg(feature = "json_schema")]
+pub use schema::*;
 pub use ser::*;
 pub use type_data::*;
 
@@ -0,0 +1,730 @@
+use crate::serde::SerializationData;
+use crate::{TypeInfo, TypeRegistry, VariantInfo};
+use bevy_utils::TypeIdMap;
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+use std::any::TypeId;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// The JSON Schema dialect used by the exported schemas.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Exports [JSON Schemas] describing the JSON produced by the reflection serializers
+/// for the types of a [`TypeRegistry`].
+///
+/// - [`type_schema`] describes the output of [`TypedReflectSerializer`] for a single type.
+/// - [`registry_schema`] describes the output of [`ReflectSerializer`] for any registered type,
+///   that is, a map with a single entry from the type path to the serialized value.
+///
+/// Every non-primitive type is described once in the `$defs` of the schema, keyed by its [type path],
+/// and referenced from wherever it is used.
+/// When the `documentation` feature is enabled, doc comments on types, fields and variants
+/// are exported as descriptions.
+///
+/// # Limitations
+///
+/// [Value types] are serialized with their own [`Serialize`] implementation,
+/// whose format cannot be known through reflection.
+/// Apart from primitives and strings, they are described by an empty schema accepting any value,
+/// unless a schema is provided with [`with_type_schema`].
+///
+/// Other types registering [`ReflectSerialize`] are assumed to serialize with the same structure
+/// as their reflected kind, which holds for a `#[derive(Serialize)]` without serde attributes.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry, serde::JsonSchemaExporter};
+/// # use std::any::TypeId;
+/// #[derive(Reflect)]
+/// #[type_path = "my_crate"]
+/// struct Player {
+///   name: String,
+///   health: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Player>();
+///
+/// let exporter = JsonSchemaExporter::new(&registry);
+/// let schema = exporter.type_schema(TypeId::of::<Player>()).unwrap();
+/// let schema = serde_json::to_value(&schema).unwrap();
+///
+/// assert_eq!(schema["$ref"], "#/$defs/my_crate::Player");
+/// let player = &schema["$defs"]["my_crate::Player"];
+/// assert_eq!(player["type"], "object");
+/// assert_eq!(player["properties"]["health"]["type"], "number");
+/// assert_eq!(player["required"], serde_json::json!(["name", "health"]));
+/// ```
+///
+/// [JSON Schemas]: https://json-schema.org/
+/// [`type_schema`]: Self::type_schema
+/// [`registry_schema`]: Self::registry_schema
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [type path]: crate::TypePath::type_path
+/// [Value types]: crate::ReflectKind::Value
+/// [`Serialize`]: ::serde::Serialize
+/// [`with_type_schema`]: Self::with_type_schema
+/// [`ReflectSerialize`]: crate::ReflectSerialize
+pub struct JsonSchemaExporter<'a> {
+    registry: &'a TypeRegistry,
+    type_schemas: TypeIdMap<SchemaValue>,
+}
+
+impl<'a> JsonSchemaExporter<'a> {
+    /// Create a new exporter for the types of the given registry.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            type_schemas: TypeIdMap::default(),
+        }
+    }
+
+    /// Use the given schema for the type with the given [`TypeId`], instead of deriving it from its type info.
+    ///
+    /// This is mostly useful for [value types] with a custom [`Serialize`] implementation.
+    /// The schema can be any serializable value, for example a `serde_json::Value`.
+    ///
+    /// [value types]: crate::ReflectKind::Value
+    pub fn with_type_schema(
+        mut self,
+        type_id: TypeId,
+        schema: impl Serialize + Send + Sync + 'static,
+    ) -> Self {
+        self.type_schemas
+            .insert(type_id, SchemaValue::Custom(Arc::new(schema)));
+        self
+    }
+
+    /// Returns the schema of the output of [`TypedReflectSerializer`] for the type with the given [`TypeId`].
+    ///
+    /// Returns `None` if the type is not registered.
+    ///
+    /// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+    pub fn type_schema(&self, type_id: TypeId) -> Option<JsonSchema> {
+        let info = self.registry.get_type_info(type_id)?;
+        let mut builder = SchemaBuilder {
+            exporter: self,
+            defs: BTreeMap::new(),
+        };
+        let schema = builder.reference(type_id, Some(info));
+        // Custom schemas can't be extended, so they are wrapped instead.
+        let mut schema = match schema {
+            SchemaValue::Object(schema) => schema,
+            schema => BTreeMap::from([("allOf".to_string(), SchemaValue::Array(vec![schema]))]),
+        };
+        schema.insert("$schema".to_string(), JSON_SCHEMA_DIALECT.into());
+        if !builder.defs.is_empty() {
+            schema.insert("$defs".to_string(), SchemaValue::Object(builder.defs));
+        }
+        Some(JsonSchema(SchemaValue::Object(schema)))
+    }
+
+    /// Returns the schema of the output of [`ReflectSerializer`] for any registered type.
+    ///
+    /// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+    pub fn registry_schema(&self) -> JsonSchema {
+        let mut builder = SchemaBuilder {
+            exporter: self,
+            defs: BTreeMap::new(),
+        };
+        let properties = self
+            .registry
+            .iter()
+            .map(|registration| {
+                let info = registration.type_info();
+                (
+                    info.type_path().to_string(),
+                    builder.reference(registration.type_id(), Some(info)),
+                )
+            })
+            .collect();
+
+        JsonSchema(object([
+            ("$schema", JSON_SCHEMA_DIALECT.into()),
+            ("type", "object".into()),
+            ("properties", SchemaValue::Object(properties)),
+            ("additionalProperties", false.into()),
+            ("minProperties", 1.into()),
+            ("maxProperties", 1.into()),
+            ("$defs", SchemaValue::Object(builder.defs)),
+        ]))
+    }
+}
+
+/// A [JSON Schema] exported by a [`JsonSchemaExporter`].
+///
+/// The schema is written through its [`Serialize`] implementation,
+/// for example with `serde_json::to_string`.
+///
+/// [JSON Schema]: https://json-schema.org/
+#[derive(Clone, Debug)]
+pub struct JsonSchema(SchemaValue);
+
+impl Serialize for JsonSchema {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// A JSON value making up a [`JsonSchema`].
+#[derive(Clone)]
+enum SchemaValue {
+    Bool(bool),
+    Integer(i128),
+    String(String),
+    Array(Vec<SchemaValue>),
+    Object(BTreeMap<String, SchemaValue>),
+    /// A schema given to [`JsonSchemaExporter::with_type_schema`].
+    Custom(Arc<dyn erased_serde::Serialize + Send + Sync>),
+}
+
+impl SchemaValue {
+    /// The empty schema, accepting any value.
+    fn any() -> Self {
+        Self::Object(BTreeMap::new())
+    }
+}
+
+impl From<bool> for SchemaValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i128> for SchemaValue {
+    fn from(value: i128) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<&str> for SchemaValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for SchemaValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl fmt::Debug for SchemaValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => value.fmt(f),
+            Self::Integer(value) => value.fmt(f),
+            Self::String(value) => value.fmt(f),
+            Self::Array(values) => f.debug_list().entries(values).finish(),
+            Self::Object(entries) => f.debug_map().entries(entries).finish(),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl Serialize for SchemaValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            // Integers in schemas are bounds of at most 64-bit integers.
+            Self::Integer(value) => match i64::try_from(*value) {
+                Ok(value) => serializer.serialize_i64(value),
+                Err(_) => serializer.serialize_u64(*value as u64),
+            },
+            Self::String(value) => serializer.serialize_str(value),
+            Self::Array(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(value)?;
+                }
+                seq.end()
+            }
+            Self::Object(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+            Self::Custom(schema) => schema.serialize(serializer),
+        }
+    }
+}
+
+/// Returns an object with the given entries.
+fn object<const N: usize>(entries: [(&str, SchemaValue); N]) -> SchemaValue {
+    SchemaValue::Object(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+struct SchemaBuilder<'a> {
+    exporter: &'a JsonSchemaExporter<'a>,
+    defs: BTreeMap<String, SchemaValue>,
+}
+
+impl<'a> SchemaBuilder<'a> {
+    /// Returns the schema to use wherever the given type appears.
+    ///
+    /// Primitives are described inline, other types are added to the definitions and referenced.
+    fn reference(&mut self, type_id: TypeId, info: Option<&'static TypeInfo>) -> SchemaValue {
+        if let Some(schema) = self.exporter.type_schemas.get(&type_id) {
+            return schema.clone();
+        }
+        if let Some(schema) = primitive_schema(type_id) {
+            return schema;
+        }
+        let Some(info) = self.exporter.registry.get_type_info(type_id).or(info) else {
+            return SchemaValue::any();
+        };
+
+        let type_path = info.type_path();
+        if !self.defs.contains_key(type_path) {
+            // Insert a placeholder first so that recursive types don't recurse forever.
+            self.defs.insert(type_path.to_string(), SchemaValue::any());
+            let definition = self.definition(info);
+            self.defs.insert(type_path.to_string(), definition);
+        }
+        object([("$ref", definition_ref(type_path).into())])
+    }
+
+    fn definition(&mut self, info: &'static TypeInfo) -> SchemaValue {
+        let mut schema = match info {
+            TypeInfo::Struct(info) => {
+                let serialization_data = self.serialization_data(info.type_id());
+                let mut properties = Vec::new();
+                for (index, field) in info.iter().enumerate() {
+                    if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
+                        continue;
+                    }
+                    #[allow(unused_mut)]
+                    let mut schema = self.reference(field.type_id(), field.type_info());
+                    #[cfg(feature = "documentation")]
+                    with_description(&mut schema, field.docs());
+                    properties.push((field.name().to_string(), schema));
+                }
+                object_schema(properties)
+            }
+            TypeInfo::TupleStruct(info) => {
+                let serialization_data = self.serialization_data(info.type_id());
+                let items = info
+                    .iter()
+                    .filter(|field| {
+                        !serialization_data.is_some_and(|data| data.is_field_skipped(field.index()))
+                    })
+                    .map(|field| {
+                        #[allow(unused_mut)]
+                        let mut schema = self.reference(field.type_id(), field.type_info());
+                        #[cfg(feature = "documentation")]
+                        with_description(&mut schema, field.docs());
+                        schema
+                    })
+                    .collect();
+                tuple_schema(items)
+            }
+            TypeInfo::Tuple(info) => {
+                let items = info
+                    .iter()
+                    .map(|field| self.reference(field.type_id(), field.type_info()))
+                    .collect();
+                tuple_schema(items)
+            }
+            TypeInfo::List(info) => object([
+                ("type", "array".into()),
+                (
+                    "items",
+                    self.reference(info.item_type_id(), info.item_info()),
+                ),
+            ]),
+            TypeInfo::Array(info) => object([
+                ("type", "array".into()),
+                (
+                    "items",
+                    self.reference(info.item_type_id(), info.item_info()),
+                ),
+                ("minItems", (info.capacity() as i128).into()),
+                ("maxItems", (info.capacity() as i128).into()),
+            ]),
+            TypeInfo::Map(info) => object([
+                ("type", "object".into()),
+                (
+                    "additionalProperties",
+                    self.reference(info.value_type_id(), None),
+                ),
+            ]),
+            TypeInfo::Set(info) => object([
+                ("type", "array".into()),
+                ("items", self.reference(info.value_type_id(), None)),
+                ("uniqueItems", true.into()),
+            ]),
+            TypeInfo::Enum(info) => {
+                let path = info.type_path_table();
+                if path.module_path() == Some("core::option") && path.ident() == Some("Option") {
+                    // `None` is serialized as `null` and `Some(value)` as the value itself.
+                    let some = match info.variant("Some") {
+                        Some(VariantInfo::Tuple(variant)) => variant
+                            .field_at(0)
+                            .map(|field| self.reference(field.type_id(), field.type_info())),
+                        _ => None,
+                    };
+                    let null = object([("type", "null".into())]);
+                    let some = some.unwrap_or_else(SchemaValue::any);
+                    object([("oneOf", SchemaValue::Array(vec![null, some]))])
+                } else {
+                    let variants = info
+                        .iter()
+                        .map(|variant| self.variant_schema(variant))
+                        .collect();
+                    object([("oneOf", SchemaValue::Array(variants))])
+                }
+            }
+            TypeInfo::Value(_) => SchemaValue::any(),
+        };
+
+        if let SchemaValue::Object(schema) = &mut schema {
+            schema.insert(
+                "title".to_string(),
+                info.type_path_table().short_path().into(),
+            );
+        }
+        #[cfg(feature = "documentation")]
+        with_description(&mut schema, info.docs());
+        schema
+    }
+
+    /// Returns the schema of an externally tagged enum variant.
+    fn variant_schema(&mut self, variant: &VariantInfo) -> SchemaValue {
+        let name = variant.name();
+        #[allow(unused_mut)]
+        let mut schema = match variant {
+            VariantInfo::Unit(_) => object([("const", name.into())]),
+            VariantInfo::Tuple(info) => {
+                let mut fields = info
+                    .iter()
+                    .map(|field| self.reference(field.type_id(), field.type_info()))
+                    .collect::<Vec<_>>();
+                let content = if fields.len() == 1 {
+                    fields.remove(0)
+                } else {
+                    tuple_schema(fields)
+                };
+                object_schema(vec![(name.to_string(), content)])
+            }
+            VariantInfo::Struct(info) => {
+                let mut properties = Vec::new();
+                for field in info.iter() {
+                    #[allow(unused_mut)]
+                    let mut schema = self.reference(field.type_id(), field.type_info());
+                    #[cfg(feature = "documentation")]
+                    with_description(&mut schema, field.docs());
+                    properties.push((field.name().to_string(), schema));
+                }
+                object_schema(vec![(name.to_string(), object_schema(properties))])
+            }
+        };
+        #[cfg(feature = "documentation")]
+        with_description(&mut schema, variant.docs());
+        schema
+    }
+
+    fn serialization_data(&self, type_id: TypeId) -> Option<&'a SerializationData> {
+        self.exporter
+            .registry
+            .get(type_id)
+            .and_then(|registration| registration.data::<SerializationData>())
+    }
+}
+
+/// Returns the schema of a primitive type, if the type is one.
+fn primitive_schema(type_id: TypeId) -> Option<SchemaValue> {
+    macro_rules! integer {
+        ($($ty:ty),*) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    return Some(object([
+                        ("type", "integer".into()),
+                        ("minimum", (<$ty>::MIN as i128).into()),
+                        ("maximum", (<$ty>::MAX as i128).into()),
+                    ]));
+                }
+            )*
+        };
+    }
+
+    integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+    let schema = if type_id == TypeId::of::<bool>() {
+        object([("type", "boolean".into())])
+    } else if type_id == TypeId::of::<u128>() {
+        object([("type", "integer".into()), ("minimum", 0.into())])
+    } else if type_id == TypeId::of::<i128>() {
+        object([("type", "integer".into())])
+    } else if type_id == TypeId::of::<f32>() || type_id == TypeId::of::<f64>() {
+        object([("type", "number".into())])
+    } else if type_id == TypeId::of::<char>() {
+        object([
+            ("type", "string".into()),
+            ("minLength", 1.into()),
+            ("maxLength", 1.into()),
+        ])
+    } else if type_id == TypeId::of::<String>()
+        || type_id == TypeId::of::<&'static str>()
+        || type_id == TypeId::of::<std::borrow::Cow<'static, str>>()
+        || type_id == TypeId::of::<std::path::PathBuf>()
+    {
+        object([("type", "string".into())])
+    } else {
+        return None;
+    };
+    Some(schema)
+}
+
+/// Returns the schema of an object with exactly the given properties, in order.
+fn object_schema(properties: Vec<(String, SchemaValue)>) -> SchemaValue {
+    let required = properties
+        .iter()
+        .map(|(name, _)| name.clone().into())
+        .collect();
+    let properties = properties.into_iter().collect();
+    object([
+        ("type", "object".into()),
+        ("properties", SchemaValue::Object(properties)),
+        ("required", SchemaValue::Array(required)),
+        ("additionalProperties", false.into()),
+    ])
+}
+
+/// Returns the schema of an array with exactly the given items, in order.
+fn tuple_schema(items: Vec<SchemaValue>) -> SchemaValue {
+    let len = items.len() as i128;
+    object([
+        ("type", "array".into()),
+        ("prefixItems", SchemaValue::Array(items)),
+        ("minItems", len.into()),
+        ("maxItems", len.into()),
+    ])
+}
+
+#[cfg(feature = "documentation")]
+fn with_description(schema: &mut SchemaValue, docs: Option<&str>) {
+    if let (SchemaValue::Object(schema), Some(docs)) = (schema, docs) {
+        schema.insert("description".to_string(), docs.trim().into());
+    }
+}
+
+/// Returns the URI fragment referencing the definition of the given type path.
+fn definition_ref(type_path: &str) -> String {
+    let mut fragment = String::from("#/$defs/");
+    for char in type_path.chars() {
+        match char {
+            // JSON Pointer escapes.
+            '~' => fragment.push_str("~0"),
+            '/' => fragment.push_str("~1"),
+            // Characters allowed as is in URI fragments.
+            'a'..='z'
+            | 'A'..='Z'
+            | '0'..='9'
+            | '-'
+            | '.'
+            | '_'
+            | ':'
+            | '@'
+            | '!'
+            | '$'
+            | '&'
+            | '\''
+            | '('
+            | ')'
+            | '*'
+            | '+'
+            | ','
+            | ';'
+            | '=' => fragment.push(char),
+            _ => {
+                let mut buffer = [0; 4];
+                for byte in char.encode_utf8(&mut buffer).bytes() {
+                    fragment.push_str(&format!("%{byte:02X}"));
+                }
+            }
+        }
+    }
+    fragment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::ReflectSerializer;
+    use crate::Reflect;
+    use bevy_utils::HashMap;
+    use serde_json::{json, Value};
+
+    #[derive(Reflect)]
+    #[type_path = "test"]
+    struct MyStruct {
+        id: u8,
+        name: Option<String>,
+        tags: Vec<String>,
+        #[reflect(skip_serializing)]
+        cache: f32,
+        position: MyTupleStruct,
+        items: HashMap<String, MyEnum>,
+    }
+
+    #[derive(Reflect)]
+    #[type_path = "test"]
+    struct MyTupleStruct(f32, f32);
+
+    #[derive(Reflect)]
+    #[type_path = "test"]
+    enum MyEnum {
+        Unit,
+        Newtype(u32),
+        Tuple(bool, bool),
+        Struct { value: String },
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<MyStruct>();
+        registry
+    }
+
+    #[test]
+    fn should_export_type_schema() {
+        let registry = get_registry();
+        let schema = JsonSchemaExporter::new(&registry)
+            .type_schema(TypeId::of::<MyStruct>())
+            .unwrap();
+        let schema = serde_json::to_value(schema).unwrap();
+
+        assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT);
+        assert_eq!(schema["$ref"], "#/$defs/test::MyStruct");
+
+        let defs = &schema["$defs"];
+        assert_eq!(
+            defs["test::MyStruct"],
+            json!({
+                "title": "MyStruct",
+                "type": "object",
+                "properties": {
+                    "id": { "type": "integer", "minimum": 0, "maximum": 255 },
+                    "name": { "$ref": "#/$defs/core::option::Option%3Calloc::string::String%3E" },
+                    "tags": { "$ref": "#/$defs/alloc::vec::Vec%3Calloc::string::String%3E" },
+                    "position": { "$ref": "#/$defs/test::MyTupleStruct" },
+                    "items": { "$ref": "#/$defs/bevy_utils::hashbrown::HashMap%3Calloc::string::String,%20test::MyEnum,%20bevy_utils::hashbrown::hash_map::DefaultHashBuilder%3E" },
+                },
+                "required": ["id", "name", "tags", "position", "items"],
+                "additionalProperties": false,
+            })
+        );
+        assert_eq!(
+            defs["core::option::Option<alloc::string::String>"]["oneOf"],
+            json!([{ "type": "null" }, { "type": "string" }])
+        );
+        assert_eq!(
+            defs["test::MyTupleStruct"]["prefixItems"],
+            json!([{ "type": "number" }, { "type": "number" }])
+        );
+        assert_eq!(
+            defs["test::MyEnum"]["oneOf"],
+            json!([
+                { "const": "Unit" },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Newtype": { "type": "integer", "minimum": 0, "maximum": u32::MAX },
+                    },
+                    "required": ["Newtype"],
+                    "additionalProperties": false,
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Tuple": {
+                            "type": "array",
+                            "prefixItems": [{ "type": "boolean" }, { "type": "boolean" }],
+                            "minItems": 2,
+                            "maxItems": 2,
+                        },
+                    },
+                    "required": ["Tuple"],
+                    "additionalProperties": false,
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Struct": {
+                            "type": "object",
+                            "properties": { "value": { "type": "string" } },
+                            "required": ["value"],
+                            "additionalProperties": false,
+                        },
+                    },
+                    "required": ["Struct"],
+                    "additionalProperties": false,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn should_export_registry_schema() {
+        let registry = get_registry();
+        let schema = JsonSchemaExporter::new(&registry)
+            .with_type_schema(TypeId::of::<MyTupleStruct>(), json!({ "type": "array" }))
+            .registry_schema();
+        let schema = serde_json::to_value(schema).unwrap();
+
+        assert_eq!(schema["maxProperties"], 1);
+        assert_eq!(
+            schema["properties"]["test::MyStruct"],
+            json!({ "$ref": "#/$defs/test::MyStruct" })
+        );
+        assert_eq!(schema["properties"]["f32"], json!({ "type": "number" }));
+        assert_eq!(
+            schema["properties"]["test::MyTupleStruct"],
+            json!({ "type": "array" })
+        );
+        assert!(schema["$defs"].get("test::MyTupleStruct").is_none());
+
+        // Check that the schema describes what the serializer actually outputs.
+        let value = MyStruct {
+            id: 1,
+            name: None,
+            tags: vec![String::from("a")],
+            cache: 0.0,
+            position: MyTupleStruct(1.0, 2.0),
+            items: HashMap::from_iter([(String::from("b"), MyEnum::Newtype(3))]),
+        };
+        let output = serde_json::to_value(ReflectSerializer::new(&value, &registry)).unwrap();
+        let data = &output["test::MyStruct"];
+        let definition = &schema["$defs"]["test::MyStruct"];
+        let mut keys = data
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        let mut required = definition["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>();
+        keys.sort_unstable();
+        required.sort_unstable();
+        assert_eq!(keys, required);
+        assert_eq!(data["name"], Value::Null);
+        assert_eq!(data["position"], json!([1.0, 2.0]));
+        assert_eq!(data["items"]["b"], json!({ "Newtype": 3 }));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Struct, Tuple, TupleStruct,
+    Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Set, Struct, Tuple, TupleStruct,
     TypeInfo, TypeRegistry, VariantInfo, VariantType,
 };
 use serde::ser::{
@@ -56,6 +56,88 @@ fn get_serializable<'a, E: Error>(
     Ok(reflect_serialize.get_serializable(reflect_value))
 }
 
+/// Allows overriding how [`ReflectSerializer`] and [`TypedReflectSerializer`] serialize specific values.
+///
+/// When given a processor, the serializers offer it every value before serializing it,
+/// including the values nested in structs, enums, lists, maps and other containers.
+/// The processor can then serialize the value itself, for example to write an asset handle as
+/// its path or an entity as a stable identifier, or hand the serializer back to fall back to
+/// the default behavior.
+///
+/// This is the serializer counterpart to [`ReflectDeserializerProcessor`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{ReflectSerializerProcessor, TypedReflectSerializer}};
+/// # use serde::Serializer;
+/// #[derive(Reflect)]
+/// struct Inventory {
+///   gold: u32,
+///   items: Vec<u32>,
+/// }
+///
+/// /// Serializes every `u32` as a hexadecimal string.
+/// struct HexProcessor;
+///
+/// impl ReflectSerializerProcessor for HexProcessor {
+///   fn try_serialize<S>(
+///     &self,
+///     value: &dyn Reflect,
+///     _registry: &TypeRegistry,
+///     serializer: S,
+///   ) -> Result<Result<S::Ok, S>, S::Error>
+///   where
+///     S: Serializer,
+///   {
+///     match value.downcast_ref::<u32>() {
+///       Some(value) => serializer.serialize_str(&format!("{value:#x}")).map(Ok),
+///       None => Ok(Err(serializer)),
+///     }
+///   }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Inventory>();
+///
+/// let input = Inventory { gold: 255, items: vec![16] };
+/// let serializer = TypedReflectSerializer::with_processor(&input, &registry, &HexProcessor);
+/// let output = ron::to_string(&serializer).unwrap();
+///
+/// assert_eq!(output, r#"(gold:"0xff",items:["0x10"])"#);
+/// ```
+///
+/// [`ReflectDeserializerProcessor`]: crate::serde::ReflectDeserializerProcessor
+pub trait ReflectSerializerProcessor {
+    /// Attempts to serialize the given value.
+    ///
+    /// Returns `Ok(Ok(_))` if the value was serialized by the processor,
+    /// and `Ok(Err(serializer))` to let the reflection serializer handle the value as usual.
+    fn try_serialize<S>(
+        &self,
+        value: &dyn Reflect,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S>, S::Error>
+    where
+        S: serde::Serializer;
+}
+
+impl ReflectSerializerProcessor for () {
+    fn try_serialize<S>(
+        &self,
+        _value: &dyn Reflect,
+        _registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S>, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Ok(Err(serializer))
+    }
+}
+
 /// A general purpose serializer for reflected types.
 ///
 /// This is the serializer counterpart to [`ReflectDeserializer`].
@@ -92,18 +174,41 @@ fn get_serializable<'a, E: Error>(
 ///
 /// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
 /// [type path]: crate::TypePath::type_path
-pub struct ReflectSerializer<'a> {
+pub struct ReflectSerializer<'a, P = ()> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a P>,
 }
 
 impl<'a> ReflectSerializer<'a> {
     pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
-        ReflectSerializer { value, registry }
+        ReflectSerializer {
+            value,
+            registry,
+            processor: None,
+        }
+    }
+}
+
+impl<'a, P: ReflectSerializerProcessor> ReflectSerializer<'a, P> {
+    /// Creates a serializer that lets the given [processor] serialize any value first,
+    /// including nested ones.
+    ///
+    /// [processor]: ReflectSerializerProcessor
+    pub fn with_processor(
+        value: &'a dyn Reflect,
+        registry: &'a TypeRegistry,
+        processor: &'a P,
+    ) -> Self {
+        ReflectSerializer {
+            value,
+            registry,
+            processor: Some(processor),
+        }
     }
 }
 
-impl<'a> Serialize for ReflectSerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -126,7 +231,7 @@ impl<'a> Serialize for ReflectSerializer<'a> {
                     }
                 })?
                 .type_path(),
-            &TypedReflectSerializer::new(self.value, self.registry),
+            &TypedReflectSerializer::new_internal(self.value, self.registry, self.processor),
         )?;
         state.end()
     }
@@ -170,22 +275,63 @@ impl<'a> Serialize for ReflectSerializer<'a> {
 ///
 /// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
 /// [type path]: crate::TypePath::type_path
-pub struct TypedReflectSerializer<'a> {
+pub struct TypedReflectSerializer<'a, P = ()> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a P>,
 }
 
 impl<'a> TypedReflectSerializer<'a> {
     pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
-        TypedReflectSerializer { value, registry }
+        TypedReflectSerializer {
+            value,
+            registry,
+            processor: None,
+        }
     }
 }
 
-impl<'a> Serialize for TypedReflectSerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> TypedReflectSerializer<'a, P> {
+    /// Creates a serializer that lets the given [processor] serialize any value first,
+    /// including nested ones.
+    ///
+    /// [processor]: ReflectSerializerProcessor
+    pub fn with_processor(
+        value: &'a dyn Reflect,
+        registry: &'a TypeRegistry,
+        processor: &'a P,
+    ) -> Self {
+        Self::new_internal(value, registry, Some(processor))
+    }
+
+    fn new_internal(
+        value: &'a dyn Reflect,
+        registry: &'a TypeRegistry,
+        processor: Option<&'a P>,
+    ) -> Self {
+        TypedReflectSerializer {
+            value,
+            registry,
+            processor,
+        }
+    }
+}
+
+impl<'a, P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
+        let serializer = match self.processor {
+            Some(processor) => {
+                match processor.try_serialize(self.value, self.registry, serializer)? {
+                    Ok(value) => return Ok(value),
+                    Err(serializer) => serializer,
+                }
+            }
+            None => serializer,
+        };
+
         // Handle both Value case and types that have a custom `Serialize`
         let serializable = get_serializable::<S::Error>(self.value, self.registry);
         if let Ok(serializable) = serializable {
@@ -196,36 +342,49 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
             ReflectRef::Struct(value) => StructSerializer {
                 struct_value: value,
                 registry: self.registry,
+                processor: self.processor,
             }
             .serialize(serializer),
             ReflectRef::TupleStruct(value) => TupleStructSerializer {
                 tuple_struct: value,
                 registry: self.registry,
+                processor: self.processor,
             }
             .serialize(serializer),
             ReflectRef::Tuple(value) => TupleSerializer {
                 tuple: value,
                 registry: self.registry,
+                processor: self.processor,
             }
             .serialize(serializer),
             ReflectRef::List(value) => ListSerializer {
                 list: value,
                 registry: self.registry,
+                processor: self.processor,
             }
             .serialize(serializer),
             ReflectRef::Array(value) => ArraySerializer {
                 array: value,
                 registry: self.registry,
+                processor: self.processor,
             }
             .serialize(serializer),
             ReflectRef::Map(value) => MapSerializer {
                 map: value,
                 registry: self.registry,
+                processor: self.processor,
+            }
+            .serialize(serializer),
+            ReflectRef::Set(value) => SetSerializer {
+                set: value,
+                registry: self.registry,
+                processor: self.processor,
             }
             .serialize(serializer),
             ReflectRef::Enum(value) => EnumSerializer {
                 enum_value: value,
                 registry: self.registry,
+                processor: self.processor,
             }
             .serialize(serializer),
             ReflectRef::Value(_) => Err(serializable.err().unwrap()),
@@ -249,12 +408,13 @@ impl<'a> Serialize for ReflectValueSerializer<'a> {
     }
 }
 
-pub struct StructSerializer<'a> {
+pub struct StructSerializer<'a, P = ()> {
     pub struct_value: &'a dyn Struct,
     pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a P>,
 }
 
-impl<'a> Serialize for StructSerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> Serialize for StructSerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -296,18 +456,22 @@ impl<'a> Serialize for StructSerializer<'a> {
                 continue;
             }
             let key = struct_info.field_at(index).unwrap().name();
-            state.serialize_field(key, &TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_field(
+                key,
+                &TypedReflectSerializer::new_internal(value, self.registry, self.processor),
+            )?;
         }
         state.end()
     }
 }
 
-pub struct TupleStructSerializer<'a> {
+pub struct TupleStructSerializer<'a, P = ()> {
     pub tuple_struct: &'a dyn TupleStruct,
     pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a P>,
 }
 
-impl<'a> Serialize for TupleStructSerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> Serialize for TupleStructSerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -348,18 +512,23 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
             {
                 continue;
             }
-            state.serialize_field(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_field(&TypedReflectSerializer::new_internal(
+                value,
+                self.registry,
+                self.processor,
+            ))?;
         }
         state.end()
     }
 }
 
-pub struct EnumSerializer<'a> {
+pub struct EnumSerializer<'a, P = ()> {
     pub enum_value: &'a dyn Enum,
     pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a P>,
 }
 
-impl<'a> Serialize for EnumSerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> Serialize for EnumSerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -423,7 +592,11 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     let field_info = struct_info.field_at(index).unwrap();
                     state.serialize_field(
                         field_info.name(),
-                        &TypedReflectSerializer::new(field.value(), self.registry),
+                        &TypedReflectSerializer::new_internal(
+                            field.value(),
+                            self.registry,
+                            self.processor,
+                        ),
                     )?;
                 }
                 state.end()
@@ -434,13 +607,17 @@ impl<'a> Serialize for EnumSerializer<'a> {
                 if type_info.type_path_table().module_path() == Some("core::option")
                     && type_info.type_path_table().ident() == Some("Option")
                 {
-                    serializer.serialize_some(&TypedReflectSerializer::new(field, self.registry))
+                    serializer.serialize_some(&TypedReflectSerializer::new_internal(
+                        field,
+                        self.registry,
+                        self.processor,
+                    ))
                 } else {
                     serializer.serialize_newtype_variant(
                         enum_name,
                         variant_index,
                         variant_name,
-                        &TypedReflectSerializer::new(field, self.registry),
+                        &TypedReflectSerializer::new_internal(field, self.registry, self.processor),
                     )
                 }
             }
@@ -452,9 +629,10 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     field_len,
                 )?;
                 for field in self.enum_value.iter_fields() {
-                    state.serialize_field(&TypedReflectSerializer::new(
+                    state.serialize_field(&TypedReflectSerializer::new_internal(
                         field.value(),
                         self.registry,
+                        self.processor,
                     ))?;
                 }
                 state.end()
@@ -463,12 +641,13 @@ impl<'a> Serialize for EnumSerializer<'a> {
     }
 }
 
-pub struct TupleSerializer<'a> {
+pub struct TupleSerializer<'a, P = ()> {
     pub tuple: &'a dyn Tuple,
     pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a P>,
 }
 
-impl<'a> Serialize for TupleSerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> Serialize for TupleSerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -476,18 +655,23 @@ impl<'a> Serialize for TupleSerializer<'a> {
         let mut state = serializer.serialize_tuple(self.tuple.field_len())?;
 
         for value in self.tuple.iter_fields() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(&TypedReflectSerializer::new_internal(
+                value,
+                self.registry,
+                self.processor,
+            ))?;
         }
         state.end()
     }
 }
 
-pub struct MapSerializer<'a> {
+pub struct MapSerializer<'a, P = ()> {
     pub map: &'a dyn Map,
     pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a P>,
 }
 
-impl<'a> Serialize for MapSerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> Serialize for MapSerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -495,45 +679,78 @@ impl<'a> Serialize for MapSerializer<'a> {
         let mut state = serializer.serialize_map(Some(self.map.len()))?;
         for (key, value) in self.map.iter() {
             state.serialize_entry(
-                &TypedReflectSerializer::new(key, self.registry),
-                &TypedReflectSerializer::new(value, self.registry),
+                &TypedReflectSerializer::new_internal(key, self.registry, self.processor),
+                &TypedReflectSerializer::new_internal(value, self.registry, self.processor),
             )?;
         }
         state.end()
     }
 }
 
-pub struct ListSerializer<'a> {
+pub struct SetSerializer<'a, P = ()> {
+    pub set: &'a dyn Set,
+    pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a P>,
+}
+
+impl<'a, P: ReflectSerializerProcessor> Serialize for SetSerializer<'a, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.set.len()))?;
+        for value in self.set.iter() {
+            state.serialize_element(&TypedReflectSerializer::new_internal(
+                value,
+                self.registry,
+                self.processor,
+            ))?;
+        }
+        state.end()
+    }
+}
+
+pub struct ListSerializer<'a, P = ()> {
     pub list: &'a dyn List,
     pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a P>,
 }
 
-impl<'a> Serialize for ListSerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> Serialize for ListSerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         let mut state = serializer.serialize_seq(Some(self.list.len()))?;
         for value in self.list.iter() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(&TypedReflectSerializer::new_internal(
+                value,
+                self.registry,
+                self.processor,
+            ))?;
         }
         state.end()
     }
 }
 
-pub struct ArraySerializer<'a> {
+pub struct ArraySerializer<'a, P = ()> {
     pub array: &'a dyn Array,
     pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a P>,
 }
 
-impl<'a> Serialize for ArraySerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> Serialize for ArraySerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         let mut state = serializer.serialize_tuple(self.array.len())?;
         for value in self.array.iter() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(&TypedReflectSerializer::new_internal(
+                value,
+                self.registry,
+                self.processor,
+            ))?;
         }
         state.end()
     }
@@ -849,6 +1066,83 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_serialize_with_processor() {
+        use crate::serde::{ReflectSerializerProcessor, TypedReflectSerializer};
+
+        #[derive(Reflect)]
+        struct AssetHandle(u32);
+
+        #[derive(Reflect)]
+        struct Level {
+            skybox: AssetHandle,
+            props: Vec<AssetHandle>,
+            named: HashMap<String, AssetHandle>,
+            id: u32,
+        }
+
+        /// Serializes asset handles as their path.
+        struct AssetPathProcessor;
+
+        impl ReflectSerializerProcessor for AssetPathProcessor {
+            fn try_serialize<S>(
+                &self,
+                value: &dyn Reflect,
+                _registry: &TypeRegistry,
+                serializer: S,
+            ) -> Result<Result<S::Ok, S>, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                match value.downcast_ref::<AssetHandle>() {
+                    Some(handle) => serializer
+                        .serialize_str(&format!("assets/{}.png", handle.0))
+                        .map(Ok),
+                    None => Ok(Err(serializer)),
+                }
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+
+        let input = Level {
+            skybox: AssetHandle(0),
+            props: vec![AssetHandle(1), AssetHandle(2)],
+            named: HashMap::from_iter([(String::from("door"), AssetHandle(3))]),
+            id: 4,
+        };
+
+        let serializer =
+            TypedReflectSerializer::with_processor(&input, &registry, &AssetPathProcessor);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let expected = r#"(skybox:"assets/0.png",props:["assets/1.png","assets/2.png"],named:{"door":"assets/3.png"},id:4)"#;
+        assert_eq!(expected, output);
+
+        let serializer = ReflectSerializer::with_processor(&input, &registry, &AssetPathProcessor);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert!(output.ends_with(r#"::Level":(skybox:"assets/0.png",props:["assets/1.png","assets/2.png"],named:{"door":"assets/3.png"},id:4)}"#));
+    }
+
+    #[test]
+    fn should_serialize_set() {
+        #[derive(Reflect)]
+        struct SetStruct {
+            ids: std::collections::BTreeSet<u32>,
+        }
+
+        let mut registry = get_registry();
+        registry.register::<SetStruct>();
+
+        let input = SetStruct {
+            ids: [3, 1, 2].into_iter().collect(),
+        };
+        let serializer = ReflectSerializer::new(&input, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let expected = r#"{"bevy_reflect::serde::ser::tests::SetStruct":(ids:[1,2,3])}"#;
+        assert_eq!(expected, output);
+    }
+
     #[test]
     fn should_serialize_non_self_describing_binary() {
         let input = get_my_struct();
@@ -26,6 +26,8 @@ pub struct TypeRegistry {
     short_path_to_id: HashMap<&'static str, TypeId>,
     type_path_to_id: HashMap<&'static str, TypeId>,
     ambiguous_names: HashSet<&'static str>,
+    #[cfg(feature = "functions")]
+    functions: crate::func::FunctionRegistry,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -85,6 +87,8 @@ impl TypeRegistry {
             short_path_to_id: Default::default(),
             type_path_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            #[cfg(feature = "functions")]
+            functions: Default::default(),
         }
     }
 
@@ -418,6 +422,84 @@ impl TypeRegistry {
             type_data.map(|data| (item, data))
         })
     }
+
+    /// Returns the registry of [reflected functions] that are not tied to a type.
+    ///
+    /// [reflected functions]: crate::func
+    #[cfg(feature = "functions")]
+    pub fn functions(&self) -> &crate::func::FunctionRegistry {
+        &self.functions
+    }
+
+    /// Returns a mutable reference to the registry of [reflected functions] that are not tied to a type.
+    ///
+    /// [reflected functions]: crate::func
+    #[cfg(feature = "functions")]
+    pub fn functions_mut(&mut self) -> &mut crate::func::FunctionRegistry {
+        &mut self.functions
+    }
+
+    /// Registers the given function under the given name in the [function registry](Self::functions).
+    ///
+    /// See [`FunctionRegistry::register`](crate::func::FunctionRegistry::register).
+    #[cfg(feature = "functions")]
+    pub fn register_function<F, Marker>(
+        &mut self,
+        name: impl Into<std::borrow::Cow<'static, str>>,
+        func: F,
+    ) where
+        F: crate::func::IntoFunction<'static, Marker> + Clone + Send + Sync + 'static,
+    {
+        self.functions.register(name, func);
+    }
+
+    /// Registers the given method of type `T` under the given name,
+    /// in the [`ReflectMethods`](crate::func::ReflectMethods) type data of `T`.
+    ///
+    /// The receiver of the method, if any, is passed as its first argument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered.
+    #[cfg(feature = "functions")]
+    pub fn register_method<T, F, Marker>(
+        &mut self,
+        name: impl Into<std::borrow::Cow<'static, str>>,
+        method: F,
+    ) where
+        T: Reflect + TypePath,
+        F: crate::func::IntoFunction<'static, Marker> + Clone + Send + Sync + 'static,
+    {
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_method` for type `{T}` without registering `{T}` first",
+                T = T::type_path(),
+            )
+        });
+        if registration.data::<crate::func::ReflectMethods>().is_none() {
+            registration.insert(crate::func::ReflectMethods::default());
+        }
+        registration
+            .data_mut::<crate::func::ReflectMethods>()
+            .unwrap()
+            .register(name, method);
+    }
+
+    /// Returns the overloads of the method at the given path, of the form `Type::method`.
+    ///
+    /// The type may be given by its [type path] or, if it is not ambiguous, its [short type path].
+    /// Returns `None` if the type is not registered or has no method with that name.
+    ///
+    /// [type path]: TypePath::type_path
+    /// [short type path]: TypePath::short_type_path
+    #[cfg(feature = "functions")]
+    pub fn get_method(&self, path: &str) -> Option<&crate::func::FunctionOverloads> {
+        let (type_path, name) = path.rsplit_once("::")?;
+        self.get_with_type_path(type_path)
+            .or_else(|| self.get_with_short_type_path(type_path))?
+            .data::<crate::func::ReflectMethods>()?
+            .get(name)
+    }
 }
 
 impl TypeRegistryArc {
@@ -2,7 +2,7 @@ use crate::extract_resource::ExtractResource;
 use bevy_color::Color;
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::prelude::*;
-use bevy_reflect::prelude::*;
+use bevy_reflect::{attributes::ColorPicker, prelude::*};
 use serde::{Deserialize, Serialize};
 
 /// For a camera, specifies the color used to clear the viewport before rendering.
@@ -13,7 +13,7 @@ pub enum ClearColorConfig {
     #[default]
     Default,
     /// The given clear color is used, overriding the [`ClearColor`] resource defined in the world.
-    Custom(Color),
+    Custom(#[reflect(@ColorPicker::RGBA)] Color),
     /// No clear color is used: the camera will simply draw on top of anything already in the viewport.
     ///
     /// This can be useful when multiple cameras are rendering to the same viewport.
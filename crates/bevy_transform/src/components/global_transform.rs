@@ -5,7 +5,7 @@ use super::Transform;
 use bevy_ecs::{component::Component, reflect::ReflectComponent};
 use bevy_math::{Affine3A, Dir3, Mat4, Quat, Vec3, Vec3A};
 #[cfg(feature = "bevy-support")]
-use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_reflect::{attributes::ReadOnly, std_traits::ReflectDefault, Reflect};
 
 /// [`GlobalTransform`] is an affine transformation from entity-local coordinates to worldspace coordinates.
 ///
@@ -39,7 +39,8 @@ use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 #[cfg_attr(
     feature = "bevy-support",
     derive(Component, Reflect),
-    reflect(Component, Default, PartialEq)
+    reflect(Component, Default, PartialEq),
+    reflect(@ReadOnly)
 )]
 pub struct GlobalTransform(Affine3A);
 
@@ -3,7 +3,11 @@ use super::GlobalTransform;
 use bevy_ecs::{component::Component, reflect::ReflectComponent};
 use bevy_math::{Affine3A, Dir3, Mat3, Mat4, Quat, Vec3};
 #[cfg(feature = "bevy-support")]
-use bevy_reflect::{prelude::*, Reflect};
+use bevy_reflect::{
+    attributes::{NumericRange, Tooltip},
+    prelude::*,
+    Reflect,
+};
 use std::ops::Mul;
 
 /// Describe the position of an entity. If the entity has a parent, the position is relative
@@ -46,18 +50,30 @@ pub struct Transform {
     /// See the [`translations`] example for usage.
     ///
     /// [`translations`]: https://github.com/bevyengine/bevy/blob/latest/examples/transforms/translation.rs
+    #[cfg_attr(
+        feature = "bevy-support",
+        reflect(@NumericRange::UNBOUNDED.with_step(0.1))
+    )]
     pub translation: Vec3,
     /// Rotation of the entity.
     ///
     /// See the [`3d_rotation`] example for usage.
     ///
     /// [`3d_rotation`]: https://github.com/bevyengine/bevy/blob/latest/examples/transforms/3d_rotation.rs
+    #[cfg_attr(
+        feature = "bevy-support",
+        reflect(@Tooltip::new("Stored as a normalized quaternion"))
+    )]
     pub rotation: Quat,
     /// Scale of the entity.
     ///
     /// See the [`scale`] example for usage.
     ///
     /// [`scale`]: https://github.com/bevyengine/bevy/blob/latest/examples/transforms/scale.rs
+    #[cfg_attr(
+        feature = "bevy-support",
+        reflect(@NumericRange::UNBOUNDED.with_step(0.01))
+    )]
     pub scale: Vec3,
 }
 
@@ -3,7 +3,10 @@ use bevy_asset::Handle;
 use bevy_color::Color;
 use bevy_ecs::{prelude::*, system::SystemParam};
 use bevy_math::{Rect, Vec2};
-use bevy_reflect::prelude::*;
+use bevy_reflect::{
+    attributes::{Hidden, NumericRange, Tooltip},
+    prelude::*,
+};
 use bevy_render::{
     camera::{Camera, RenderTarget},
     texture::{Image, TRANSPARENT_IMAGE_HANDLE},
@@ -43,6 +46,7 @@ pub struct Node {
     /// The unrounded size of the node as width and height in logical pixels.
     ///
     /// Automatically calculated by [`super::layout::ui_layout_system`].
+    #[reflect(@Hidden)]
     pub(crate) unrounded_size: Vec2,
 }
 
@@ -241,6 +245,8 @@ pub struct Style {
     /// The aspect ratio of the node (defined as `width / height`)
     ///
     /// <https://developer.mozilla.org/en-US/docs/Web/CSS/aspect-ratio>
+    #[reflect(@NumericRange::at_least(0.0).with_step(0.01))]
+    #[reflect(@Tooltip::new("Width divided by height"))]
     pub aspect_ratio: Option<f32>,
 
     /// Used to control how each individual item is aligned by default within the space they're given.
@@ -359,11 +365,13 @@ pub struct Style {
     /// Defines how much a flexbox item should grow if there's space available. Defaults to 0 (don't grow at all).
     ///
     /// <https://developer.mozilla.org/en-US/docs/Web/CSS/flex-grow>
+    #[reflect(@NumericRange::at_least(0.0).with_step(0.1))]
     pub flex_grow: f32,
 
     /// Defines how much a flexbox item should shrink if there's not enough space available. Defaults to 1.
     ///
     /// <https://developer.mozilla.org/en-US/docs/Web/CSS/flex-shrink>
+    #[reflect(@NumericRange::at_least(0.0).with_step(0.1))]
     pub flex_shrink: f32,
 
     /// The initial length of a flexbox in the main axis, before flex growing/shrinking properties are applied.